@@ -0,0 +1,293 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Command line tool which generates the C++ code for the bridges in a Rust file,
+//! for build systems other than Cargo, similar to `cxxbridge` for CXX.
+
+use cxx_qt_build::{BridgeOptions, FlagsCfgEvaluator};
+use cxx_qt_gen::{Lint, LintConfig, LintLevel, MetadataPaths};
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+Usage: cxxqtbridge <input.rs> --include-prefix <prefix> --cpp-dir <dir> --header-dir <dir> [options]
+
+Generates the .cxxqt.h/.cxxqt.cpp and .cxx.h/.cxx.cpp files for each bridge in the input file.
+Headers are written to <header-dir>/<prefix>/ so that they can be included as \"<prefix>/<name>.cxxqt.h\".
+
+Options:
+    --include-prefix <prefix>   Prefix of the include paths of the generated headers
+    --cpp-dir <dir>             Directory to write the generated sources to
+    --header-dir <dir>          Directory to write the generated headers to
+    --relative-to <dir>         Name the generated files after the path of the input relative to <dir>
+                                (default: the file name of the input)
+    --depfile <path>            Write a Makefile style depfile listing the generated files
+    --cfg <name>[=\"<value>\"]    Enable a cfg for #[cfg] attributes in the bridges, like rustc --cfg.
+                                Any other cfg except features is an error, use <name>=false to disable it
+    --metadata <crate>=<path>   Path to the metadata exported by a crate, for #[import = \"crate\"]
+    -A, --allow <lint>          Do not check the given lint (default for all lints)
+    -W, --warn <lint>           Report the given lint as a warning
+    -D, --deny <lint>           Report the given lint as an error
+    -h, --help                  Print this help";
+
+#[derive(Default)]
+struct Args {
+    input: Option<PathBuf>,
+    include_prefix: Option<String>,
+    cpp_dir: Option<PathBuf>,
+    header_dir: Option<PathBuf>,
+    relative_to: Option<PathBuf>,
+    depfile: Option<PathBuf>,
+    lints: LintConfig,
+    cfgs: FlagsCfgEvaluator,
+    metadata_paths: MetadataPaths,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut parsed = Self::default();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for {arg}"))
+            };
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(None),
+                "--include-prefix" => parsed.include_prefix = Some(value()?),
+                "--cpp-dir" => parsed.cpp_dir = Some(value()?.into()),
+                "--header-dir" => parsed.header_dir = Some(value()?.into()),
+                "--relative-to" => parsed.relative_to = Some(value()?.into()),
+                "--depfile" => parsed.depfile = Some(value()?.into()),
+                "--cfg" => parsed.add_cfg(value()?)?,
+                "--metadata" => parsed.add_metadata(value()?)?,
+                "-A" | "--allow" => parsed.set_lint(value()?, LintLevel::Allow)?,
+                "-W" | "--warn" => parsed.set_lint(value()?, LintLevel::Warn)?,
+                "-D" | "--deny" => parsed.set_lint(value()?, LintLevel::Deny)?,
+                _ if arg.starts_with('-') => return Err(format!("Unknown option {arg}")),
+                _ if parsed.input.is_some() => return Err(format!("Unexpected argument {arg}")),
+                _ => parsed.input = Some(arg.into()),
+            }
+        }
+        Ok(Some(parsed))
+    }
+
+    fn add_cfg(&mut self, cfg: String) -> Result<(), String> {
+        let (name, value) = match cfg.split_once('=') {
+            Some((name, value)) => {
+                // The value may be quoted like for rustc, e.g. --cfg 'feature="qml"'
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|value| value.strip_suffix('"'))
+                    .unwrap_or(value);
+                (name, Some(value.to_owned()))
+            }
+            None => (cfg.as_str(), None),
+        };
+        if name.is_empty() {
+            return Err(format!("Invalid cfg {cfg}"));
+        }
+        self.cfgs.insert(name, value);
+        Ok(())
+    }
+
+    fn add_metadata(&mut self, metadata: String) -> Result<(), String> {
+        match metadata.split_once('=') {
+            Some((crate_name, path)) if !crate_name.is_empty() && !path.is_empty() => {
+                self.metadata_paths.insert(crate_name, path);
+                Ok(())
+            }
+            _ => Err(format!(
+                "Invalid metadata {metadata}, expected <crate>=<path>"
+            )),
+        }
+    }
+
+    fn set_lint(&mut self, name: String, level: LintLevel) -> Result<(), String> {
+        let lint = Lint::from_name(&name).ok_or_else(|| format!("Unknown lint {name}"))?;
+        self.lints = std::mem::take(&mut self.lints).set(lint, level);
+        Ok(())
+    }
+}
+
+/// Escape a path for use in a Makefile style depfile
+fn escape_depfile_path(path: &Path) -> String {
+    path.display()
+        .to_string()
+        .replace(' ', "\\ ")
+        .replace('#', "\\#")
+        .replace('$', "$$")
+}
+
+/// Write a depfile with the generated files as targets and the given inputs as prerequisites,
+/// which include the metadata of any imported crates
+fn write_depfile<'a>(
+    depfile: &Path,
+    inputs: impl IntoIterator<Item = &'a Path>,
+    outputs: &[PathBuf],
+) -> std::io::Result<()> {
+    let targets = outputs
+        .iter()
+        .map(|output| escape_depfile_path(output))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let prerequisites = inputs
+        .into_iter()
+        .map(escape_depfile_path)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut file = std::fs::File::create(depfile)?;
+    writeln!(file, "{targets}: {prerequisites}")
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let (Some(input), Some(include_prefix), Some(cpp_dir), Some(header_dir)) = (
+        args.input,
+        args.include_prefix,
+        args.cpp_dir,
+        args.header_dir,
+    ) else {
+        eprintln!("error: Missing required arguments\n\n{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let relative_to = args
+        .relative_to
+        .unwrap_or_else(|| input.parent().map(Path::to_path_buf).unwrap_or_default());
+    let Some(outputs) = cxx_qt_build::generate_bridge_files(
+        &input,
+        relative_to,
+        cpp_dir,
+        header_dir,
+        &BridgeOptions {
+            include_prefix: &include_prefix,
+            lints: &args.lints,
+            metadata_paths: &args.metadata_paths,
+            cfgs: Some(&args.cfgs),
+        },
+    ) else {
+        return ExitCode::FAILURE;
+    };
+
+    if let Some(depfile) = args.depfile {
+        let inputs = std::iter::once(input.as_path()).chain(args.metadata_paths.paths());
+        if let Err(err) = write_depfile(&depfile, inputs, &outputs) {
+            eprintln!(
+                "error: Could not write depfile {}: {err}",
+                depfile.display()
+            );
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<Args>, String> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse() {
+        let args = parse(&[
+            "src/lib.rs",
+            "--include-prefix",
+            "my_crate",
+            "--cpp-dir",
+            "cpp",
+            "--header-dir",
+            "include",
+            "--depfile",
+            "lib.d",
+            "-D",
+            "unsafe_invokable",
+            "--cfg",
+            "enabled",
+            "--cfg",
+            "feature=\"qml\"",
+            "--cfg",
+            "disabled=false",
+            "--metadata",
+            "other_crate=metadata/other.json",
+        ])
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(args.input, Some(PathBuf::from("src/lib.rs")));
+        assert_eq!(args.include_prefix.as_deref(), Some("my_crate"));
+        assert_eq!(args.cpp_dir, Some(PathBuf::from("cpp")));
+        assert_eq!(args.header_dir, Some(PathBuf::from("include")));
+        assert_eq!(args.depfile, Some(PathBuf::from("lib.d")));
+        assert_eq!(args.lints.to_string(), "unsafe_invokable=deny");
+
+        let mut cfgs = FlagsCfgEvaluator::default();
+        cfgs.insert("enabled", None);
+        cfgs.insert("feature", Some("qml".to_owned()));
+        cfgs.insert("disabled", Some("false".to_owned()));
+        assert_eq!(args.cfgs, cfgs);
+        assert_eq!(
+            args.metadata_paths.get("other_crate"),
+            Some(PathBuf::from("metadata/other.json"))
+        );
+        assert_eq!(args.metadata_paths.get("unknown_crate"), None);
+    }
+
+    #[test]
+    fn test_parse_help() {
+        assert!(parse(&["--help"]).unwrap().is_none());
+        assert!(parse(&["src/lib.rs", "-h"]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse(&["--unknown"]).is_err());
+        assert!(parse(&["a.rs", "b.rs"]).is_err());
+        assert!(parse(&["a.rs", "--cpp-dir"]).is_err());
+        assert!(parse(&["a.rs", "-D", "unknown_lint"]).is_err());
+        assert!(parse(&["a.rs", "--cfg", "=value"]).is_err());
+        assert!(parse(&["a.rs", "--metadata", "other_crate"]).is_err());
+        assert!(parse(&["a.rs", "--metadata", "other_crate="]).is_err());
+    }
+
+    #[test]
+    fn test_escape_depfile_path() {
+        assert_eq!(escape_depfile_path(Path::new("a/b.h")), "a/b.h");
+        assert_eq!(
+            escape_depfile_path(Path::new("my dir/#1/$file.h")),
+            "my\\ dir/\\#1/$$file.h"
+        );
+    }
+
+    #[test]
+    fn test_write_depfile() {
+        let dir = tempfile::tempdir().unwrap();
+        let depfile = dir.path().join("bridge.d");
+        write_depfile(
+            &depfile,
+            [Path::new("src/lib.rs"), Path::new("dep/metadata.json")],
+            &[PathBuf::from("lib.cxxqt.h"), PathBuf::from("lib.cxxqt.cpp")],
+        )
+        .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&depfile).unwrap(),
+            "lib.cxxqt.h lib.cxxqt.cpp: src/lib.rs dep/metadata.json\n"
+        );
+    }
+}
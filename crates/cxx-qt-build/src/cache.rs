@@ -0,0 +1,333 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Avoids touching or recompiling generated C++ files whose content did not change,
+//! so that incremental builds only rebuild what was actually affected.
+
+use crate::dir;
+
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Write the contents to the given path, unless the file already has exactly these contents.
+///
+/// If the file was part of the crate target directory before it was cleaned and the contents did
+/// not change, the previous file is restored instead.
+/// Either way, the modification time of unchanged files is kept, so C++ build systems and moc
+/// do not consider them out of date.
+pub(crate) fn write_if_changed(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) {
+    let path = path.as_ref();
+    let contents = contents.as_ref();
+    if fs::read(path).is_ok_and(|existing| existing == contents) {
+        return;
+    }
+
+    if let Some(previous) = dir::previous_file(path) {
+        if fs::read(&previous).is_ok_and(|existing| existing == contents)
+            && fs::rename(&previous, path).is_ok()
+        {
+            return;
+        }
+    }
+
+    fs::write(path, contents)
+        .unwrap_or_else(|err| panic!("Could not write {}: {err}", path.display()));
+}
+
+/// Compile the given generated C++ sources with the configuration of the builder, reusing the
+/// objects of previous builds whenever the inputs of a source did not change.
+///
+/// An object is keyed by a hash of the compiler and its arguments, the source contents and the
+/// contents of every header the source includes (transitively).
+/// Only headers in the given system include directories, like the Qt headers, and headers which
+/// are not found in any include directory, like the standard library, are considered stable.
+///
+/// The builder must not contain any files itself, as compiling the cache misses would otherwise
+/// compile these as well.
+///
+/// Returns the paths of the objects in the cache directory, which can be passed to [cc::Build::objects].
+pub(crate) fn compile_cached(
+    builder: &cc::Build,
+    sources: &[PathBuf],
+    system_include_dirs: &[PathBuf],
+    cache_dir: &Path,
+) -> Vec<PathBuf> {
+    assert_eq!(
+        builder.get_files().count(),
+        0,
+        "CXX-Qt internal error: The builder of cached objects must not contain any files!"
+    );
+    let compiler = builder.get_compiler();
+    let include_dirs = include_dirs(compiler.args());
+
+    let mut compiler_hasher = StableHasher::default();
+    compiler_hasher.write(compiler.path().as_os_str().as_encoded_bytes());
+    for arg in compiler.args() {
+        compiler_hasher.write(arg.as_encoded_bytes());
+    }
+
+    let mut includes = IncludeScanner::new(include_dirs, system_include_dirs.to_vec());
+    let objects: Vec<PathBuf> = sources
+        .iter()
+        .map(|source| {
+            let mut hasher = compiler_hasher.clone();
+            includes.hash_with_includes(source, &mut hasher);
+            cache_dir.join(format!("{:032x}.o", hasher.finish()))
+        })
+        .collect();
+
+    let misses: Vec<(&PathBuf, &PathBuf)> = sources
+        .iter()
+        .zip(objects.iter())
+        .filter(|(_, object)| !object.exists())
+        .collect();
+    if !misses.is_empty() {
+        let mut miss_builder = builder.clone();
+        miss_builder.files(misses.iter().map(|(source, _)| source));
+        let compiled = miss_builder.compile_intermediates();
+        assert_eq!(
+            compiled.len(),
+            misses.len(),
+            "CXX-Qt internal error: Expected one object file per generated source!"
+        );
+        for (compiled, (_, object)) in compiled.iter().zip(misses) {
+            fs::copy(compiled, object)
+                .unwrap_or_else(|_| panic!("Failed to cache object file {}!", compiled.display()));
+        }
+    }
+
+    // Remove the objects of sources which no longer exist or changed, so the cache does not grow
+    let used: HashSet<&PathBuf> = objects.iter().collect();
+    if let Ok(entries) = fs::read_dir(cache_dir) {
+        for entry in entries.flatten() {
+            if !used.contains(&entry.path()) {
+                let _ = fs::remove_file(entry.path());
+            }
+        }
+    }
+
+    objects
+}
+
+/// The include directories passed to the compiler as `-I` or `/I` arguments
+fn include_dirs(args: &[impl AsRef<OsStr>]) -> Vec<PathBuf> {
+    let mut dirs = vec![];
+    let mut args = args.iter().filter_map(|arg| arg.as_ref().to_str());
+    while let Some(arg) = args.next() {
+        if let Some(dir) = arg.strip_prefix("-I").or_else(|| arg.strip_prefix("/I")) {
+            if dir.is_empty() {
+                // The directory may also be passed as a separate argument
+                if let Some(dir) = args.next() {
+                    dirs.push(PathBuf::from(dir));
+                }
+            } else {
+                dirs.push(PathBuf::from(dir));
+            }
+        }
+    }
+    dirs
+}
+
+/// A hash which does not depend on the Rust version or process, as the cached objects are kept
+/// between builds. This is the 128-bit variant of [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/).
+#[derive(Clone)]
+struct StableHasher(u128);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0x6c62272e07bb014262b821756295c58d)
+    }
+}
+
+impl StableHasher {
+    /// Hash the bytes, prefixed by their length so that consecutive writes cannot be confused
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= u128::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0000000001000000000000000000013b);
+        }
+    }
+
+    fn finish(&self) -> u128 {
+        self.0
+    }
+}
+
+/// Finds the headers included by a C++ file, which are not in a system include directory
+struct IncludeScanner {
+    include_dirs: Vec<PathBuf>,
+    system_include_dirs: Vec<PathBuf>,
+    /// The resolved includes of every file that was scanned so far
+    scanned: HashMap<PathBuf, Vec<PathBuf>>,
+}
+
+impl IncludeScanner {
+    fn new(include_dirs: Vec<PathBuf>, system_include_dirs: Vec<PathBuf>) -> Self {
+        Self {
+            include_dirs,
+            system_include_dirs,
+            scanned: HashMap::new(),
+        }
+    }
+
+    /// Hash the contents of the file and of every header it includes
+    fn hash_with_includes(&mut self, path: &Path, hasher: &mut StableHasher) {
+        let mut visited = HashSet::new();
+        let mut queue = vec![path.to_path_buf()];
+        while let Some(file) = queue.pop() {
+            if !visited.insert(file.clone()) {
+                continue;
+            }
+            hasher.write(file.as_os_str().as_encoded_bytes());
+            hasher.write(&fs::read(&file).unwrap_or_default());
+            queue.extend(self.includes(&file).iter().cloned());
+        }
+    }
+
+    fn includes(&mut self, file: &Path) -> &[PathBuf] {
+        if !self.scanned.contains_key(file) {
+            let includes = fs::read_to_string(file)
+                .unwrap_or_default()
+                .lines()
+                .filter_map(include_directive)
+                .filter_map(|include| self.resolve(file, include))
+                .filter(|resolved| {
+                    !self
+                        .system_include_dirs
+                        .iter()
+                        .any(|dir| resolved.starts_with(dir))
+                })
+                .collect();
+            self.scanned.insert(file.to_path_buf(), includes);
+        }
+        &self.scanned[file]
+    }
+
+    fn resolve(&self, file: &Path, include: &str) -> Option<PathBuf> {
+        file.parent()
+            .into_iter()
+            .chain(self.include_dirs.iter().map(PathBuf::as_path))
+            .map(|dir| dir.join(include))
+            .find(|path| path.is_file())
+    }
+}
+
+/// The path of an `#include "..."` or `#include <...>` directive
+fn include_directive(line: &str) -> Option<&str> {
+    let directive = line.trim_start().strip_prefix('#')?.trim_start();
+    let path = directive.strip_prefix("include")?.trim();
+    let (open, close) = match path.chars().next()? {
+        '"' => ('"', '"'),
+        '<' => ('<', '>'),
+        _ => return None,
+    };
+    path.strip_prefix(open)?.split(close).next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_include_directive() {
+        assert_eq!(include_directive("#include \"a/b.h\""), Some("a/b.h"));
+        assert_eq!(
+            include_directive("#include <QtCore/QObject>"),
+            Some("QtCore/QObject")
+        );
+        assert_eq!(
+            include_directive("  #  include  <vector> // comment"),
+            Some("vector")
+        );
+        assert_eq!(include_directive("#include MACRO"), None);
+        assert_eq!(include_directive("#define include"), None);
+        assert_eq!(include_directive("// #include \"a.h\""), None);
+    }
+
+    #[test]
+    fn test_include_dirs() {
+        assert_eq!(
+            include_dirs(&[
+                "-std=c++17",
+                "-I/a",
+                "-I",
+                "/b",
+                "/I/c",
+                "-DI=1",
+                "-isystem",
+                "/d"
+            ]),
+            [
+                PathBuf::from("/a"),
+                PathBuf::from("/b"),
+                PathBuf::from("/c")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_if_changed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("file.h");
+        write_if_changed(&path, "first");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first");
+
+        // Unchanged contents keep the modification time
+        let past = SystemTime::now() - Duration::from_secs(60);
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(past)
+            .unwrap();
+        write_if_changed(&path, "first");
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), past);
+
+        write_if_changed(&path, "second");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        assert_ne!(fs::metadata(&path).unwrap().modified().unwrap(), past);
+    }
+
+    #[test]
+    fn test_hash_with_includes() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().join("project");
+        let system = dir.path().join("system");
+        fs::create_dir_all(project.join("nested")).unwrap();
+        fs::create_dir_all(&system).unwrap();
+        let source = project.join("source.cpp");
+        fs::write(
+            &source,
+            "#include \"nested/header.h\"\n#include <system.h>\n",
+        )
+        .unwrap();
+        fs::write(project.join("nested/header.h"), "#include \"other.h\"\n").unwrap();
+        fs::write(project.join("nested/other.h"), "first").unwrap();
+        fs::write(system.join("system.h"), "first").unwrap();
+
+        let hash = || {
+            let mut scanner =
+                IncludeScanner::new(vec![project.clone(), system.clone()], vec![system.clone()]);
+            let mut hasher = StableHasher::default();
+            scanner.hash_with_includes(&source, &mut hasher);
+            hasher.finish()
+        };
+        let first = hash();
+        assert_eq!(first, hash());
+
+        // Headers in system include directories are considered stable
+        fs::write(system.join("system.h"), "second").unwrap();
+        assert_eq!(first, hash());
+
+        // Any other header included transitively changes the hash
+        fs::write(project.join("nested/other.h"), "second").unwrap();
+        assert_ne!(first, hash());
+    }
+}
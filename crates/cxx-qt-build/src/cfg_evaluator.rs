@@ -53,6 +53,56 @@ impl CfgEvaluator for CargoEnvCfgEvaluator {
     }
 }
 
+/// Evaluates cfgs given as `--cfg name` or `--cfg name="value"` on the command line,
+/// for build systems other than Cargo
+///
+/// Modified from the cxxbridge command line tool of CXX, a cfg which is not given is an error
+/// instead of silently being false, except for features.
+#[doc(hidden)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FlagsCfgEvaluator {
+    map: Map<String, Set<String>>,
+}
+
+impl FlagsCfgEvaluator {
+    /// Add a cfg, where a cfg without a value is enabled unless it is `name=false`
+    pub fn insert(&mut self, name: impl Into<String>, value: Option<String>) {
+        let values = self.map.entry(name.into()).or_default();
+        values.extend(value);
+    }
+}
+
+impl CfgEvaluator for FlagsCfgEvaluator {
+    fn eval(&self, name: &str, query_value: Option<&str>) -> CfgResult {
+        let values = self.map.get(name);
+        if let Some(query_value) = query_value {
+            return if let Some(values) = values {
+                CfgResult::from(values.contains(query_value))
+            } else if name == "feature" {
+                CfgResult::False
+            } else {
+                let msg = format!("pass `--cfg {name}=\"...\"` to be able to use this attribute");
+                CfgResult::Undetermined { msg }
+            };
+        }
+
+        let Some(values) = values else {
+            let msg = format!(
+                "pass either `--cfg {name}=true` or `--cfg {name}=false` to be able to use this cfg attribute"
+            );
+            return CfgResult::Undetermined { msg };
+        };
+        let is_true = values.is_empty() || values.contains("true");
+        let is_false = values.contains("false");
+        if is_true && is_false {
+            let msg = format!("the flags say both {name}=false and {name}=true");
+            CfgResult::Undetermined { msg }
+        } else {
+            CfgResult::from(is_true)
+        }
+    }
+}
+
 impl CargoEnv {
     fn load() -> Self {
         const CARGO_FEATURE_PREFIX: &str = "CARGO_FEATURE_";
@@ -159,3 +209,39 @@ impl PartialEq for CaseAgnosticByte {
         self.cmp(rhs) == Ordering::Equal
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_true(result: CfgResult) -> Option<bool> {
+        match result {
+            CfgResult::True => Some(true),
+            CfgResult::False => Some(false),
+            CfgResult::Undetermined { .. } => None,
+        }
+    }
+
+    #[test]
+    fn test_flags_cfg_evaluator() {
+        let mut cfgs = FlagsCfgEvaluator::default();
+        cfgs.insert("enabled", None);
+        cfgs.insert("disabled", Some("false".to_owned()));
+        cfgs.insert("feature", Some("qml".to_owned()));
+        cfgs.insert("target_os", Some("linux".to_owned()));
+
+        assert_eq!(is_true(cfgs.eval("enabled", None)), Some(true));
+        assert_eq!(is_true(cfgs.eval("disabled", None)), Some(false));
+        assert_eq!(is_true(cfgs.eval("feature", Some("qml"))), Some(true));
+        assert_eq!(is_true(cfgs.eval("feature", Some("widgets"))), Some(false));
+        assert_eq!(is_true(cfgs.eval("target_os", Some("linux"))), Some(true));
+        assert_eq!(
+            is_true(cfgs.eval("target_os", Some("windows"))),
+            Some(false)
+        );
+
+        // Cfgs which are not given are an error, instead of silently being false
+        assert_eq!(is_true(cfgs.eval("unknown", None)), None);
+        assert_eq!(is_true(cfgs.eval("unknown", Some("value"))), None);
+    }
+}
@@ -6,8 +6,13 @@
 //! This module takes care of displaying errors emitted by CXX-Qt as nicely-printed diagnostics
 //! using codespan-reporting.
 
-use std::{fmt::Display, ops::Range, path::PathBuf};
+use std::{
+    fmt::Display,
+    ops::Range,
+    path::{Path, PathBuf},
+};
 
+use cxx_qt_gen::{LintDiagnostic, Suggestion};
 use proc_macro2::{LineColumn, Span};
 
 /// We need to wrap the CXX and CXX-Qt errors in a single error type so that they
@@ -63,14 +68,15 @@ impl std::iter::IntoIterator for GeneratedError {
     fn into_iter(self) -> Self::IntoIter {
         match self {
             GeneratedError::Cxx(cxx_err) => IntoIter::Cxx(cxx_err.into_iter()),
-            GeneratedError::CxxQt(_) => IntoIter::CxxQt(std::iter::once(self)),
+            // A syn::Error can contain multiple messages
+            GeneratedError::CxxQt(syn_err) => IntoIter::CxxQt(syn_err.into_iter()),
         }
     }
 }
 
 pub(crate) enum IntoIter {
     Cxx(<cxx_gen::Error as std::iter::IntoIterator>::IntoIter),
-    CxxQt(std::iter::Once<GeneratedError>),
+    CxxQt(<cxx_qt_gen::Error as std::iter::IntoIterator>::IntoIter),
 }
 
 impl Iterator for IntoIter {
@@ -78,14 +84,25 @@ impl Iterator for IntoIter {
     fn next(&mut self) -> Option<Self::Item> {
         match self {
             IntoIter::Cxx(cxx_iter) => cxx_iter.next().map(GeneratedError::from),
-            IntoIter::CxxQt(cxxqt_iter) => cxxqt_iter.next(),
+            IntoIter::CxxQt(cxxqt_iter) => cxxqt_iter.next().map(GeneratedError::from),
         }
     }
 }
 
+/// A [Suggestion] for one of the errors of a [Diagnostic]
+///
+/// This stores the location instead of the span, so that it can be sent between threads.
+struct FixIt {
+    error_index: usize,
+    start: LineColumn,
+    end: LineColumn,
+    replacement: String,
+}
+
 pub(crate) struct Diagnostic {
     file_path: PathBuf,
     errors: Vec<GeneratedError>,
+    fix_its: Vec<FixIt>,
 }
 
 impl Diagnostic {
@@ -93,16 +110,37 @@ impl Diagnostic {
         Self {
             file_path,
             errors: error.into_iter().collect(),
+            fix_its: vec![],
         }
     }
 
-    fn byte_span_in(error: &GeneratedError, source: &str) -> Option<Range<usize>> {
-        error.span().and_then(|span| {
-            let start_offset = line_column_to_byte_in(span.start(), source)?;
-            let end_offset = line_column_to_byte_in(span.end(), source)?;
+    /// Show the suggestions made by the errors as a fix-it
+    pub(crate) fn with_suggestions(mut self, suggestions: Vec<Suggestion>) -> Self {
+        for suggestion in suggestions {
+            let error_index = self.errors.iter().position(|error| {
+                matches!(error, GeneratedError::CxxQt(syn_err) if suggestion.is_from(syn_err))
+            });
+            if let Some(error_index) = error_index {
+                self.fix_its.push(FixIt {
+                    error_index,
+                    start: suggestion.span.start(),
+                    end: suggestion.span.end(),
+                    replacement: suggestion.replacement,
+                });
+            }
+        }
+        self
+    }
 
-            Some(start_offset..end_offset)
-        })
+    fn byte_span_in(span: Span, source: &str) -> Option<Range<usize>> {
+        Self::byte_range_in(span.start(), span.end(), source)
+    }
+
+    fn byte_range_in(start: LineColumn, end: LineColumn, source: &str) -> Option<Range<usize>> {
+        let start_offset = line_column_to_byte_in(start, source)?;
+        let end_offset = line_column_to_byte_in(end, source)?;
+
+        Some(start_offset..end_offset)
     }
 
     fn create_codespan_diagnostic(
@@ -115,20 +153,70 @@ impl Diagnostic {
             .with_message(format!("{error}"))
             .with_code(error.context());
 
-        if let Some(span) = Self::byte_span_in(error, source) {
+        if let Some(span) = error
+            .span()
+            .and_then(|span| Self::byte_span_in(span, source))
+        {
             diagnostic = diagnostic.with_labels(vec![Label::primary((), span)]);
         }
 
         diagnostic
     }
 
+    /// Add a fix-it to a diagnostic as a label and a note showing the fixed line
+    fn add_suggestion(
+        diagnostic: &mut codespan_reporting::diagnostic::Diagnostic<()>,
+        source: &str,
+        fix_it: &FixIt,
+    ) {
+        use codespan_reporting::diagnostic::Label;
+
+        // The message of the error already contains the suggestion, so there is nothing to add
+        // if the code can't be found
+        let replacement = &fix_it.replacement;
+        let Some(span) = Self::byte_range_in(fix_it.start, fix_it.end, source) else {
+            return;
+        };
+
+        diagnostic.labels.push(
+            Label::secondary((), span.clone()).with_message(format!("help: `{replacement}`")),
+        );
+
+        let line_start = source[..span.start]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let line_end = source[span.end..]
+            .find('\n')
+            .map_or(source.len(), |index| span.end + index);
+        let fixed_line = format!(
+            "{}{replacement}{}",
+            &source[line_start..span.start],
+            &source[span.end..line_end]
+        );
+        diagnostic.notes.push(format!(
+            "help: with the suggestion applied\n  {}",
+            fixed_line.trim()
+        ));
+    }
+
     fn create_codespan_diagnostics(
         &self,
         source: &str,
     ) -> Vec<codespan_reporting::diagnostic::Diagnostic<()>> {
         self.errors
             .iter()
-            .map(|error| Self::create_codespan_diagnostic(source, error))
+            .enumerate()
+            .map(|(index, error)| {
+                let mut diagnostic = Self::create_codespan_diagnostic(source, error);
+                for fix_it in self
+                    .fix_its
+                    .iter()
+                    .filter(|fix_it| fix_it.error_index == index)
+                {
+                    Self::add_suggestion(&mut diagnostic, source, fix_it);
+                }
+                diagnostic
+            })
             .collect()
     }
 
@@ -165,6 +253,21 @@ impl Diagnostic {
     }
 }
 
+/// Format a lint warning as a single line with the location of the lint in the file,
+/// as Cargo only shows the first line of a warning of a build script
+pub(crate) fn lint_warning(file_path: &Path, diagnostic: &LintDiagnostic) -> String {
+    let start = diagnostic.span.start();
+    format!(
+        "{}:{}:{}: {} (cxx_qt lint `{}`)",
+        file_path.display(),
+        start.line,
+        // Columns of a span start at 0, but editors count them from 1
+        start.column + 1,
+        diagnostic.message,
+        diagnostic.lint
+    )
+}
+
 fn line_column_to_byte_in(line_column: LineColumn, source: &str) -> Option<usize> {
     let mut line = 1;
     source
@@ -187,3 +290,50 @@ fn line_column_to_byte_in(line_column: LineColumn, source: &str) -> Option<usize
         .map(|(byte, _char)| byte)
         .next()
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{BridgeOptions, GeneratedCpp};
+    use cxx_qt_gen::{LintConfig, MetadataPaths};
+
+    #[test]
+    fn test_suggestions() {
+        let source = r#"
+            #[cxx_qt::bridge]
+            mod qobject {
+                extern "RustQt" {
+                    #[qobjet]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        "#;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("qobject.rs");
+        std::fs::write(&path, source).unwrap();
+        let Err(diagnostic) = GeneratedCpp::new(
+            &path,
+            "src/qobject.rs",
+            &BridgeOptions {
+                include_prefix: "crate",
+                lints: &LintConfig::default(),
+                metadata_paths: &MetadataPaths::default(),
+                cfgs: None,
+            },
+        ) else {
+            panic!("Expected the misspelled attribute to fail");
+        };
+
+        // The suggestion is shown as a fix-it of the error which made it
+        let diagnostics = diagnostic.create_codespan_diagnostics(source);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0]
+            .message
+            .ends_with("help: did you mean `qobject`?"));
+        assert_eq!(diagnostics[0].labels.len(), 2);
+        assert_eq!(diagnostics[0].labels[1].message, "help: `qobject`");
+        assert_eq!(
+            diagnostics[0].notes,
+            vec!["help: with the suggestion applied\n  #[qobject]"]
+        );
+    }
+}
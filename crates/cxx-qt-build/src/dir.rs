@@ -29,18 +29,47 @@ pub(crate) fn gen() -> PathBuf {
     out().join("cxxqtgen")
 }
 
-// Clean a directory by removing it and recreating it.
-pub(crate) fn clean(path: impl AsRef<Path>) -> Result<()> {
-    let result = std::fs::remove_dir_all(&path);
-    if let Err(err) = result {
-        // If the path doesn't exist that's fine, otherwise we want to panic
+// Clean a directory by recreating it, but move its previous contents aside instead of removing them.
+// This allows unchanged files to be restored with their original modification time,
+// see [crate::cache::write_if_changed].
+// The previous contents are removed with [remove_previous].
+pub(crate) fn clean_keeping_previous(path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    let previous = previous(path);
+    remove_previous(path)?;
+    if let Err(err) = std::fs::rename(path, &previous) {
         if err.kind() != std::io::ErrorKind::NotFound {
             return Err(err);
         }
     }
 
-    std::fs::create_dir_all(path)?;
-    Ok(())
+    std::fs::create_dir_all(path)
+}
+
+// Remove the previous contents of a directory that was cleaned with [clean_keeping_previous].
+pub(crate) fn remove_previous(path: impl AsRef<Path>) -> Result<()> {
+    match std::fs::remove_dir_all(previous(path.as_ref())) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// The path a file inside the crate target directory had before the directory was cleaned by
+/// [clean_keeping_previous], if it is inside the crate target directory.
+pub(crate) fn previous_file(path: impl AsRef<Path>) -> Option<PathBuf> {
+    // Outside of a build script, e.g. in the cxxqtbridge tool, there is no crate target directory
+    env::var_os("OUT_DIR")?;
+    let crate_target = crate_target();
+    path.as_ref()
+        .strip_prefix(&crate_target)
+        .ok()
+        .map(|relative| previous(&crate_target).join(relative))
+}
+
+fn previous(path: &Path) -> PathBuf {
+    let mut previous = path.as_os_str().to_owned();
+    previous.push(".previous");
+    PathBuf::from(previous)
 }
 
 /// The target directory, namespaced by crate
@@ -171,6 +200,13 @@ pub(crate) fn is_exporting_crate() -> bool {
     crate_export().is_some()
 }
 
+/// The directory in which compiled objects of generated C++ files are cached between builds
+pub(crate) fn object_cache() -> PathBuf {
+    let path = out().join("cxx-qt-build").join("objects");
+    std::fs::create_dir_all(&path).expect("Failed to create object cache path!");
+    path
+}
+
 pub(crate) fn initializers(key: &str) -> PathBuf {
     let path = out().join("cxx-qt-build").join("initializers").join(key);
     std::fs::create_dir_all(&path).expect("Failed to create initializers path!");
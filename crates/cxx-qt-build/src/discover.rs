@@ -0,0 +1,328 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Discovery of the Rust files containing a bridge by following the module tree of a crate
+
+use crate::diagnostics::{Diagnostic, GeneratedError};
+
+use cxx_gen::CfgEvaluator;
+use cxx_qt_gen::{eval_cfg_attributes, parse_qt_file, CxxQtItem};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+use syn::{Attribute, Expr, ExprLit, Item, ItemMod, Lit, Meta};
+
+/// The Rust files found while walking the module tree of a crate
+#[derive(Default)]
+pub(crate) struct DiscoveredFiles {
+    /// Every Rust file that was scanned, a change to any of these could add or remove a bridge
+    pub scanned: Vec<PathBuf>,
+    /// The Rust files containing a top-level `#[cxx_qt::bridge]` or `#[cxx::bridge]`
+    pub bridges: Vec<PathBuf>,
+    /// The canonical paths of the scanned files, as #[path] attributes can reach a file via different paths
+    visited: HashSet<PathBuf>,
+}
+
+impl DiscoveredFiles {
+    /// Walk the module tree starting from the given crate roots, such as `src/lib.rs`
+    ///
+    /// Roots which do not exist are skipped, as are `mod` declarations whose file cannot be found
+    /// (for example modules which are generated into `OUT_DIR` and `include!`d).
+    ///
+    /// Modules which are disabled by a `#[cfg(...)]` attribute are skipped as well, as their bridges
+    /// are not compiled on the Rust side. This includes cfgs which can not be evaluated by the
+    /// build script, such as `#[cfg(test)]`.
+    pub(crate) fn from_roots(
+        roots: impl IntoIterator<Item = PathBuf>,
+        cfg_evaluator: &dyn CfgEvaluator,
+    ) -> Result<Self, Diagnostic> {
+        let mut discovered = Self::default();
+        for root in roots {
+            if root.is_file() {
+                // Crate roots are mod-rs files, so their child modules live next to them
+                let dir = parent_dir(&root);
+                discovered.visit_file(root, &dir, &dir, cfg_evaluator)?;
+            }
+        }
+        Ok(discovered)
+    }
+
+    /// Scan a single Rust file
+    ///
+    /// `module_dir` is the directory in which the child modules of this file are looked up,
+    /// `path_dir` is the directory that `#[path]` attributes of top-level items are relative to.
+    fn visit_file(
+        &mut self,
+        path: PathBuf,
+        module_dir: &Path,
+        path_dir: &Path,
+        cfg_evaluator: &dyn CfgEvaluator,
+    ) -> Result<(), Diagnostic> {
+        // Guard against the same file being reached twice via #[path] attributes
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+        if !self.visited.insert(canonical) {
+            return Ok(());
+        }
+        self.scanned.push(path.clone());
+
+        let file = parse_qt_file(&path)
+            .map_err(|err| Diagnostic::new(path.clone(), GeneratedError::from(err)))?;
+
+        let mut has_bridge = false;
+        for item in &file.items {
+            match item {
+                CxxQtItem::Cxx(_) | CxxQtItem::CxxQt(_) => has_bridge = true,
+                CxxQtItem::Item(item) => {
+                    if let Item::Mod(module) = item.as_ref() {
+                        self.visit_module(module, module_dir, path_dir, cfg_evaluator)?;
+                    }
+                }
+            }
+        }
+
+        if has_bridge {
+            self.bridges.push(path);
+        }
+        Ok(())
+    }
+
+    /// Follow a `mod` item, either into the file it declares or into its inline content
+    fn visit_module(
+        &mut self,
+        module: &ItemMod,
+        module_dir: &Path,
+        path_dir: &Path,
+        cfg_evaluator: &dyn CfgEvaluator,
+    ) -> Result<(), Diagnostic> {
+        if !eval_cfg_attributes(cfg_evaluator, &module.attrs).unwrap_or(false) {
+            return Ok(());
+        }
+
+        let name = module.ident.to_string();
+        let path_attr = path_attribute(&module.attrs);
+
+        if let Some((_, items)) = &module.content {
+            // Unlike for a file, #[path] on an inline module is relative to the directory of its children
+            let dir = module_dir.join(path_attr.as_ref().unwrap_or(&name));
+            for item in items {
+                // Bridges are only supported at the top-level of a file,
+                // so inline modules are only searched for further mod declarations
+                if let Item::Mod(module) = item {
+                    self.visit_module(module, &dir, &dir, cfg_evaluator)?;
+                }
+            }
+            return Ok(());
+        }
+
+        if let Some(path) = path_attr {
+            // A file loaded via #[path] is treated like a mod-rs file
+            let file = path_dir.join(path);
+            if file.is_file() {
+                let dir = parent_dir(&file);
+                self.visit_file(file, &dir, &dir, cfg_evaluator)?;
+            }
+            return Ok(());
+        }
+
+        let dir = module_dir.join(&name);
+        let candidates = [module_dir.join(format!("{name}.rs")), dir.join("mod.rs")];
+        if let Some(file) = candidates.into_iter().find(|file| file.is_file()) {
+            // Both foo.rs and foo/mod.rs look up their child modules in foo/,
+            // but #[path] attributes in foo.rs are relative to the directory of foo.rs itself
+            let path_dir = parent_dir(&file);
+            self.visit_file(file, &dir, &path_dir, cfg_evaluator)?;
+        }
+        Ok(())
+    }
+}
+
+fn parent_dir(path: &Path) -> PathBuf {
+    path.parent().map(Path::to_path_buf).unwrap_or_default()
+}
+
+/// The value of a `#[path = "..."]` attribute, if there is one
+fn path_attribute(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::NameValue(name_value) if name_value.path.is_ident("path") => {
+            match &name_value.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(path),
+                    ..
+                }) => Some(path.value()),
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::FlagsCfgEvaluator;
+
+    const BRIDGE: &str = "#[cxx::bridge]\nmod ffi {}\n";
+
+    /// Write the given files into a temporary directory
+    fn crate_dir(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for (path, contents) in files {
+            let path = dir.path().join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        }
+        dir
+    }
+
+    /// Discover the bridges from src/lib.rs, relative to the crate directory
+    fn discover(dir: &tempfile::TempDir) -> DiscoveredFiles {
+        discover_with_cfgs(dir, &FlagsCfgEvaluator::default())
+    }
+
+    fn discover_with_cfgs(
+        dir: &tempfile::TempDir,
+        cfg_evaluator: &FlagsCfgEvaluator,
+    ) -> DiscoveredFiles {
+        DiscoveredFiles::from_roots([dir.path().join("src/lib.rs")], cfg_evaluator).unwrap_or_else(
+            |diagnostic| {
+                diagnostic.report();
+                panic!("Failed to discover the bridges");
+            },
+        )
+    }
+
+    fn relative(dir: &tempfile::TempDir, paths: &[PathBuf]) -> Vec<String> {
+        let mut paths: Vec<_> = paths
+            .iter()
+            .map(|path| {
+                path.strip_prefix(dir.path())
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn test_mod_rs_and_file_modules() {
+        let dir = crate_dir(&[
+            ("src/lib.rs", "mod file; mod folder; mod missing;"),
+            ("src/file.rs", &format!("mod child;\n{BRIDGE}")),
+            ("src/file/child.rs", BRIDGE),
+            ("src/folder/mod.rs", "mod child;"),
+            ("src/folder/child.rs", BRIDGE),
+        ]);
+        let discovered = discover(&dir);
+
+        assert_eq!(
+            relative(&dir, &discovered.bridges),
+            ["src/file.rs", "src/file/child.rs", "src/folder/child.rs"]
+        );
+        assert_eq!(discovered.scanned.len(), 5);
+    }
+
+    #[test]
+    fn test_path_attributes() {
+        let dir = crate_dir(&[
+            (
+                "src/lib.rs",
+                r#"
+                #[path = "other/renamed.rs"]
+                mod file;
+                mod inline {
+                    #[path = "in_inline.rs"]
+                    mod child;
+                }
+                mod non_mod_rs;
+                "#,
+            ),
+            ("src/other/renamed.rs", "mod child;"),
+            // A file loaded via #[path] looks up its children next to itself
+            ("src/other/child.rs", BRIDGE),
+            ("src/inline/in_inline.rs", BRIDGE),
+            (
+                "src/non_mod_rs.rs",
+                r#"
+                #[path = "sibling.rs"]
+                mod sibling;
+                #[path = "renamed_inline"]
+                mod inline {
+                    mod child;
+                }
+                "#,
+            ),
+            ("src/sibling.rs", BRIDGE),
+            ("src/non_mod_rs/renamed_inline/child.rs", BRIDGE),
+        ]);
+        let discovered = discover(&dir);
+
+        assert_eq!(
+            relative(&dir, &discovered.bridges),
+            [
+                "src/inline/in_inline.rs",
+                "src/non_mod_rs/renamed_inline/child.rs",
+                "src/other/child.rs",
+                "src/sibling.rs",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cycles() {
+        let dir = crate_dir(&[
+            (
+                "src/lib.rs",
+                &format!("#[path = \"../src/lib.rs\"]\nmod again;\nmod child;\n{BRIDGE}"),
+            ),
+            ("src/child.rs", "#[path = \"lib.rs\"]\nmod root;"),
+        ]);
+        let discovered = discover(&dir);
+
+        assert_eq!(relative(&dir, &discovered.bridges), ["src/lib.rs"]);
+        assert_eq!(
+            relative(&dir, &discovered.scanned),
+            ["src/child.rs", "src/lib.rs"]
+        );
+    }
+
+    #[test]
+    fn test_cfg_modules() {
+        let dir = crate_dir(&[
+            (
+                "src/lib.rs",
+                r#"
+                #[cfg(feature = "enabled")]
+                mod enabled;
+                #[cfg(feature = "disabled")]
+                mod disabled;
+                #[cfg(not(feature = "disabled"))]
+                mod negated;
+                #[cfg(test)]
+                mod tests;
+                #[cfg(feature = "disabled")]
+                mod inline {
+                    mod child;
+                }
+                "#,
+            ),
+            ("src/enabled.rs", BRIDGE),
+            ("src/disabled.rs", BRIDGE),
+            ("src/negated.rs", BRIDGE),
+            ("src/tests.rs", BRIDGE),
+            ("src/inline/child.rs", BRIDGE),
+        ]);
+        let mut cfg_evaluator = FlagsCfgEvaluator::default();
+        cfg_evaluator.insert("feature", Some("enabled".to_owned()));
+        let discovered = discover_with_cfgs(&dir, &cfg_evaluator);
+
+        assert_eq!(
+            relative(&dir, &discovered.bridges),
+            ["src/enabled.rs", "src/negated.rs"]
+        );
+    }
+}
@@ -8,6 +8,8 @@
 use core::panic;
 use std::collections::HashSet;
 
+use cxx_qt_gen::BridgeMetadata;
+
 use crate::{dir, Dependency, Manifest};
 
 /// When generating a library with cxx-qt-build, the library may need to export certain flags or headers.
@@ -18,6 +20,7 @@ pub struct Interface {
     pub(crate) exported_include_prefixes: Vec<String>,
     pub(crate) manifest: Manifest,
     pub(crate) dependencies: Vec<Dependency>,
+    pub(crate) bridges: Vec<BridgeMetadata>,
 }
 
 impl Default for Interface {
@@ -32,6 +35,7 @@ impl Default for Interface {
             exported_include_prefixes: vec![super::crate_name()],
             manifest: Manifest::default(),
             dependencies: Vec::new(),
+            bridges: Vec::new(),
         }
     }
 }
@@ -97,6 +101,13 @@ impl Interface {
         let manifest_json = serde_json::to_string_pretty(&self.manifest)
             .expect("Failed to convert Manifest to JSON!");
         std::fs::write(&manifest_path, manifest_json).expect("Failed to write manifest.json!");
+
+        // The metadata of the bridges is written next to the manifest, so that downstream
+        // dependencies can import the QObjects
+        let metadata_json = serde_json::to_string_pretty(&self.bridges)
+            .expect("Failed to convert bridge metadata to JSON!");
+        std::fs::write(dir::crate_target().join("metadata.json"), metadata_json)
+            .expect("Failed to write metadata.json!");
         println!(
             "cargo::metadata=CXX_QT_MANIFEST_PATH={}",
             manifest_path.display()
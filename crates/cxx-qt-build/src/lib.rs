@@ -11,7 +11,9 @@
 //! for CXX-Qt or CXX macros and generate any resulting C++ code. It also builds
 //! the C++ code into a binary with any cxx-qt-lib code and Qt linked.
 
+mod cache;
 mod cfg_evaluator;
+pub use cfg_evaluator::FlagsCfgEvaluator;
 mod utils;
 
 mod diagnostics;
@@ -20,6 +22,9 @@ use diagnostics::{Diagnostic, GeneratedError};
 pub(crate) mod dir;
 use dir::INCLUDE_VERB;
 
+mod discover;
+use discover::DiscoveredFiles;
+
 mod dependencies;
 use dependencies::{Dependency, Manifest};
 
@@ -39,28 +44,23 @@ pub use qt_build_utils::{QResource, QResources};
 use quote::ToTokens;
 use std::fs::OpenOptions;
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     env,
     fs::File,
-    io::Write,
     path::{Path, PathBuf},
 };
 
 use cxx_qt_gen::{
-    parse_qt_file, self_inlining::qualify_self_types, write_cpp, write_rust, CppFragment,
-    CxxQtItem, GeneratedCppBlocks, GeneratedOpt, GeneratedRustBlocks, Parser,
+    lint, metadata_env_var, metatypes_json, parse_qt_file, self_inlining::qualify_self_types,
+    write_cpp, write_qmltypes, write_rust, BridgeMetadata, CppFragment, CxxQtItem,
+    GeneratedCppBlocks, GeneratedOpt, GeneratedRustBlocks, LintConfig, MetadataPaths, Parser,
+    Suggestion, LINT_CONFIG_ENV_VAR,
 };
-
-// TODO: we need to eventually support having multiple modules defined in a single file. This
-// is currently an issue because we are using the Rust file name to derive the cpp file name
-// and are blindly re-writing files.
-//
-// As we use struct names for the QObject files, we should actually be able to support multiple
-// QObject macros and at most one "raw CXX" macro per file already. For now this remains a TODO
-// as to keep things simpler. We also want to able to warn users about duplicate names eventually.
+pub use cxx_qt_gen::{Lint, LintLevel};
 
 struct GeneratedCppFilePaths {
     plain_cpp: PathBuf,
+    plain_header: PathBuf,
     qobject: Option<PathBuf>,
     qobject_header: Option<PathBuf>,
 }
@@ -69,15 +69,23 @@ struct GeneratedCpp {
     cxx_qt: Option<CppFragment>,
     cxx: cxx_gen::GeneratedCode,
     file_ident: String,
+    metadata: Option<BridgeMetadata>,
+    metatypes: Option<serde_json::Value>,
+    /// The lints found in the bridge which are reported as warnings
+    warnings: Vec<String>,
 }
 
 impl GeneratedCpp {
-    /// Generate QObject and cxx header/source C++ file contents
+    /// Generate QObject and cxx header/source C++ file contents for each bridge in the file
+    ///
+    /// The first `#[cxx_qt::bridge]` of the file, or the first bridge if there is none,
+    /// uses the name of the file for the generated files. The name of the module is appended
+    /// for any other bridge, so that adding a bridge to a file does not rename existing headers.
     pub fn new(
         rust_file_path: impl AsRef<Path>,
         relative_path: impl AsRef<Path>,
-        include_prefix: &str,
-    ) -> Result<Self, Diagnostic> {
+        options: &BridgeOptions,
+    ) -> Result<Vec<Self>, Diagnostic> {
         let to_diagnostic = |err| Diagnostic::new(rust_file_path.as_ref().to_owned(), err);
 
         let rust_file_path = rust_file_path.as_ref();
@@ -86,14 +94,6 @@ impl GeneratedCpp {
             .map_err(GeneratedError::from)
             .map_err(to_diagnostic)?;
 
-        let mut cxx_qt = None;
-        let mut tokens = proc_macro2::TokenStream::new();
-
-        // Add any attributes in the file into the tokenstream
-        for attr in &file.attrs {
-            tokens.extend(attr.into_token_stream());
-        }
-
         // Match upstream where they use the file name and folders as the ident
         //
         // We need the relative path here as we want the folders
@@ -104,82 +104,132 @@ impl GeneratedCpp {
             .to_string_lossy()
             .into_owned();
 
+        // Add any attributes in the file into the tokenstream of each bridge
+        let mut file_attrs = proc_macro2::TokenStream::new();
+        for attr in &file.attrs {
+            file_attrs.extend(attr.into_token_stream());
+        }
+
+        // Find any CXX or CXX-Qt blocks
+        let bridges: Vec<_> = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                CxxQtItem::Cxx(module) | CxxQtItem::CxxQt(module) => Some((item, &module.ident)),
+                CxxQtItem::Item(_) => None,
+            })
+            .collect();
+        if bridges.is_empty() {
+            return Err(to_diagnostic(GeneratedError::from(cxx_qt_gen::Error::new(
+                proc_macro2::Span::call_site(),
+                "No #[cxx::bridge] or #[cxx_qt::bridge] module found!",
+            ))));
+        }
+
+        let primary_bridge = bridges
+            .iter()
+            .position(|(bridge, _)| matches!(bridge, CxxQtItem::CxxQt(_)))
+            .unwrap_or(0);
+        bridges
+            .into_iter()
+            .enumerate()
+            .map(|(index, (bridge, module_ident))| {
+                let file_ident = if index == primary_bridge {
+                    file_ident.clone()
+                } else {
+                    format!("{file_ident}_{module_ident}")
+                };
+                // Suggestions of the errors are shown as a fix-it
+                let (result, suggestions) = Suggestion::collect(|| {
+                    Self::from_bridge(rust_file_path, &file_attrs, bridge, file_ident, options)
+                });
+                result.map_err(|err| to_diagnostic(err).with_suggestions(suggestions))
+            })
+            .collect()
+    }
+
+    /// Generate the C++ file contents of a single CXX or CXX-Qt bridge
+    fn from_bridge(
+        rust_file_path: &Path,
+        file_attrs: &proc_macro2::TokenStream,
+        bridge: &CxxQtItem,
+        file_ident: String,
+        options: &BridgeOptions,
+    ) -> Result<Self, GeneratedError> {
+        let mut cxx_qt = None;
+        let mut metadata = None;
+        let mut metatypes = None;
+        let mut warnings = vec![];
+        let mut tokens = file_attrs.clone();
+
         // The include path we inject needs any prefix (eg the crate name) too
-        let include_ident = format!("{include_prefix}/{file_ident}");
-
-        let mut cxx_qt_opt = GeneratedOpt::default();
-        cxx_qt_opt.cfg_evaluator = Box::new(cfg_evaluator::CargoEnvCfgEvaluator);
-
-        // Loop through the items looking for any CXX or CXX-Qt blocks
-        let mut found_bridge = false;
-        for item in &file.items {
-            match item {
-                CxxQtItem::Cxx(m) => {
-                    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
-                    if found_bridge {
-                        panic!(
-                            "Unfortunately only files with either a single cxx or a single cxx_qt module are currently supported.
-                            The file {} has more than one of these.",
-                            rust_file_path.display());
-                    }
-                    found_bridge = true;
+        let include_ident = format!("{}/{file_ident}", options.include_prefix);
 
-                    tokens.extend(m.into_token_stream());
-                }
-                CxxQtItem::CxxQt(m) => {
-                    // TODO: later we will allow for multiple CXX or CXX-Qt blocks in one file
-                    if found_bridge {
-                        panic!(
-                            "Unfortunately only files with either a single cxx or a single cxx_qt module are currently supported.
-                            The file {} has more than one of these.",
-                            rust_file_path.display());
-                    }
-                    found_bridge = true;
-
-                    let mut parser = Parser::from(*m.clone())
-                        .map_err(GeneratedError::from)
-                        .map_err(to_diagnostic)?;
-                    qualify_self_types(&mut parser)
-                        .map_err(GeneratedError::from)
-                        .map_err(to_diagnostic)?;
-                    let generated_cpp = GeneratedCppBlocks::from(&parser, &cxx_qt_opt)
-                        .map_err(GeneratedError::from)
-                        .map_err(to_diagnostic)?;
-                    let generated_rust = GeneratedRustBlocks::from(&parser)
-                        .map_err(GeneratedError::from)
-                        .map_err(to_diagnostic)?;
-
-                    // TODO: we'll have to extend the C++ data here rather than overwriting
-                    // assuming we share the same file
-                    cxx_qt = Some(write_cpp(&generated_cpp, &include_ident));
-                    let rust_tokens = write_rust(&generated_rust, Some(&include_ident));
-
-                    // We need to do this and can't rely on the macro, as we need to generate the
-                    // CXX bridge Rust code that is then fed into the cxx_gen generation.
-                    tokens.extend(rust_tokens);
-                }
-                CxxQtItem::Item(item) => {
-                    tokens.extend(item.into_token_stream());
-                }
+        if let CxxQtItem::CxxQt(module) = bridge {
+            let mut cxx_qt_opt = GeneratedOpt::default();
+            cxx_qt_opt.cfg_evaluator = options.cfg_evaluator();
+            cxx_qt_opt.file_ident = file_ident.clone();
+
+            let mut parser = Parser::from_with_metadata(*module.clone(), options.metadata_paths)?;
+            qualify_self_types(&mut parser)?;
+
+            // Denied lints are reported like any other error of the bridge
+            let (errors, lint_warnings): (Vec<_>, Vec<_>) = lint(&parser, options.lints)?
+                .into_iter()
+                .partition(|diagnostic| diagnostic.is_error());
+            if let Some(error) = errors
+                .iter()
+                .map(|diagnostic| diagnostic.to_error())
+                .reduce(|mut combined, error| {
+                    combined.combine(error);
+                    combined
+                })
+            {
+                return Err(error.into());
             }
+            warnings = lint_warnings
+                .iter()
+                .map(|diagnostic| diagnostics::lint_warning(rust_file_path, diagnostic))
+                .collect();
+
+            let generated_cpp = GeneratedCppBlocks::from(&parser, &cxx_qt_opt)?;
+            let generated_rust = GeneratedRustBlocks::from(&parser)?;
+            metadata = Some(BridgeMetadata::from_parser(
+                &parser,
+                &format!("{include_ident}.cxxqt.h"),
+            )?);
+            metatypes = Some(metatypes_json(
+                &parser,
+                &format!("{include_ident}.cxxqt.h"),
+            )?);
+
+            cxx_qt = Some(write_cpp(&generated_cpp, &include_ident));
+            let rust_tokens = write_rust(&generated_rust, Some(&include_ident));
+
+            // We need to do this and can't rely on the macro, as we need to generate the
+            // CXX bridge Rust code that is then fed into the cxx_gen generation.
+            tokens.extend(rust_tokens);
+        } else {
+            tokens.extend(bridge.into_token_stream());
         }
 
         let mut opt = cxx_gen::Opt::default();
-        opt.cfg_evaluator = Box::new(cfg_evaluator::CargoEnvCfgEvaluator);
-        let cxx = cxx_gen::generate_header_and_cc(tokens, &opt)
-            .map_err(GeneratedError::from)
-            .map_err(to_diagnostic)?;
+        opt.cfg_evaluator = options.cfg_evaluator();
+        let cxx = cxx_gen::generate_header_and_cc(tokens, &opt)?;
 
         Ok(GeneratedCpp {
             cxx_qt,
             cxx,
             file_ident,
+            metadata,
+            metatypes,
+            warnings,
         })
     }
 
     /// Write generated .cpp and .h files to specified directories. Returns the paths of all files written.
     pub fn write_to_directories(
-        self,
+        &self,
         cpp_directory: impl AsRef<Path>,
         header_directory: impl AsRef<Path>,
     ) -> GeneratedCppFilePaths {
@@ -188,6 +238,7 @@ impl GeneratedCpp {
 
         let mut cpp_file_paths = GeneratedCppFilePaths {
             plain_cpp: PathBuf::new(),
+            plain_header: PathBuf::new(),
             qobject: None,
             qobject_header: None,
         };
@@ -201,16 +252,12 @@ impl GeneratedCpp {
                 std::fs::create_dir_all(directory)
                     .expect("Could not create directory to write cxx-qt generated files");
             }
-            let mut header =
-                File::create(&header_path).expect("Could not create cxx-qt header file");
             let header_generated = match cxx_qt_generated {
                 CppFragment::Pair { header, source: _ } => header,
                 CppFragment::Header(header) => header,
                 CppFragment::Source(_) => panic!("Unexpected call for source fragment."),
             };
-            header
-                .write_all(header_generated.as_bytes())
-                .expect("Could not write cxx-qt header file");
+            cache::write_if_changed(&header_path, header_generated);
             cpp_file_paths.qobject_header = Some(header_path);
 
             let cpp_path = PathBuf::from(format!(
@@ -222,14 +269,12 @@ impl GeneratedCpp {
                 std::fs::create_dir_all(directory)
                     .expect("Could not create directory to write cxx-qt generated files");
             }
-            let mut cpp = File::create(&cpp_path).expect("Could not create cxx-qt source file");
             let source_generated = match cxx_qt_generated {
                 CppFragment::Pair { header: _, source } => source,
                 CppFragment::Header(_) => panic!("Unexpected call for header fragment."),
                 CppFragment::Source(source) => source,
             };
-            cpp.write_all(source_generated.as_bytes())
-                .expect("Could not write cxx-qt source file");
+            cache::write_if_changed(&cpp_path, source_generated);
             cpp_file_paths.qobject = Some(cpp_path);
         }
 
@@ -242,10 +287,8 @@ impl GeneratedCpp {
             std::fs::create_dir_all(directory)
                 .expect("Could not create directory to write cxx-qt generated header files");
         }
-        let mut header = File::create(header_path).expect("Could not create cxx header file");
-        header
-            .write_all(&self.cxx.header)
-            .expect("Could not write cxx header file");
+        cache::write_if_changed(&header_path, &self.cxx.header);
+        cpp_file_paths.plain_header = header_path;
 
         let cpp_path = PathBuf::from(format!(
             "{}/{}.cxx.cpp",
@@ -256,44 +299,192 @@ impl GeneratedCpp {
             std::fs::create_dir_all(directory)
                 .expect("Could not create directory to write cxx-qt generated source files");
         }
-        let mut cpp = File::create(&cpp_path).expect("Could not create cxx source file");
-        cpp.write_all(&self.cxx.implementation)
-            .expect("Could not write cxx source file");
+        cache::write_if_changed(&cpp_path, &self.cxx.implementation);
         cpp_file_paths.plain_cpp = cpp_path;
 
         cpp_file_paths
     }
 }
 
-/// Generate C++ files from a given list of Rust files, returning the generated paths
+/// The names of the generated files of every bridge, by the Rust file which contains the bridge
+#[derive(Default)]
+struct FileIdents(HashMap<String, PathBuf>);
+
+impl FileIdents {
+    /// Bridges in different files could generate files with the same name,
+    /// such as `mod bar` in `foo.rs` next to `foo_bar.rs`, so this is reported as an error
+    fn insert(&mut self, rust_file_path: &Path, file_ident: &str) -> Result<(), Diagnostic> {
+        if let Some(other_path) = self.0.get(file_ident) {
+            return Err(Diagnostic::new(
+                rust_file_path.to_owned(),
+                GeneratedError::from(cxx_qt_gen::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!(
+                        "The generated files of a bridge in this file are named {file_ident}, which conflicts with a bridge in {}!",
+                        other_path.display()
+                    ),
+                )),
+            ));
+        }
+        self.0
+            .insert(file_ident.to_owned(), rust_file_path.to_owned());
+        Ok(())
+    }
+}
+
+/// The options for generating the C++ code of every bridge
+#[doc(hidden)]
+pub struct BridgeOptions<'a> {
+    /// The prefix of the include paths of the generated headers
+    pub include_prefix: &'a str,
+    /// The lints which are checked, warnings are returned with the generated code
+    pub lints: &'a LintConfig,
+    /// The metadata of the crates QObjects can be imported from with `#[import = "crate"]`
+    pub metadata_paths: &'a MetadataPaths,
+    /// The cfgs given on the command line, otherwise cfgs are evaluated from the environment of Cargo
+    pub cfgs: Option<&'a FlagsCfgEvaluator>,
+}
+
+impl BridgeOptions<'_> {
+    fn cfg_evaluator(&self) -> Box<dyn cxx_gen::CfgEvaluator> {
+        match self.cfgs {
+            Some(cfgs) => Box::new(cfgs.clone()),
+            None => Box::new(cfg_evaluator::CargoEnvCfgEvaluator),
+        }
+    }
+}
+
+/// Generate C++ files from a given list of Rust files, returning the generated paths, the
+/// metadata of the bridges and the metatypes of their QObjects
 fn generate_cxxqt_cpp_files(
-    rs_source: &[impl AsRef<Path>],
+    rs_source: &[impl AsRef<Path> + Sync],
     header_dir: impl AsRef<Path>,
-    include_prefix: &str,
-) -> Vec<GeneratedCppFilePaths> {
+    options: &BridgeOptions,
+) -> (
+    Vec<GeneratedCppFilePaths>,
+    Vec<BridgeMetadata>,
+    Vec<serde_json::Value>,
+) {
     let cxx_qt_dir = dir::gen();
     std::fs::create_dir_all(&cxx_qt_dir).expect("Failed to create cxx-qt-gen directory!");
-    std::fs::write(cxx_qt_dir.join("include-prefix.txt"), include_prefix).expect("");
+    std::fs::write(
+        cxx_qt_dir.join("include-prefix.txt"),
+        options.include_prefix,
+    )
+    .expect("");
 
-    let header_dir = header_dir.as_ref().join(include_prefix);
+    let header_dir = header_dir.as_ref().join(options.include_prefix);
     let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
 
-    let mut generated_file_paths: Vec<GeneratedCppFilePaths> = Vec::with_capacity(rs_source.len());
     for rs_path in rs_source {
+        println!(
+            "cargo::rerun-if-changed={}",
+            manifest_dir.join(rs_path).display()
+        );
+    }
+
+    // Each file is generated independently, the results are then collected in the original order
+    // so that the output does not depend on the scheduling of the threads
+    let generated = qt_build_utils::parallel_map(rs_source, |rs_path| {
         let path = manifest_dir.join(rs_path);
-        println!("cargo::rerun-if-changed={}", path.display());
+        GeneratedCpp::new(&path, rs_path, options)
+            .map(|generated_bridges| (path, generated_bridges))
+    });
 
-        let generated_code = match GeneratedCpp::new(&path, rs_path, include_prefix) {
+    let mut generated_file_paths: Vec<GeneratedCppFilePaths> = Vec::with_capacity(rs_source.len());
+    let mut bridges = vec![];
+    let mut metatypes = vec![];
+    let mut file_idents = FileIdents::default();
+    for generated_bridges in generated {
+        let (path, generated_bridges) = match generated_bridges {
             Ok(v) => v,
             Err(diagnostic) => {
                 diagnostic.report();
                 std::process::exit(1);
             }
         };
-        generated_file_paths.push(generated_code.write_to_directories(&cxx_qt_dir, &header_dir));
+        for generated_code in generated_bridges {
+            if let Err(diagnostic) = file_idents.insert(&path, &generated_code.file_ident) {
+                diagnostic.report();
+                std::process::exit(1);
+            }
+            for warning in &generated_code.warnings {
+                println!("cargo::warning={warning}");
+            }
+            generated_file_paths
+                .push(generated_code.write_to_directories(&cxx_qt_dir, &header_dir));
+            bridges.extend(generated_code.metadata);
+            metatypes.extend(generated_code.metatypes);
+        }
     }
 
-    generated_file_paths
+    (generated_file_paths, bridges, metatypes)
+}
+
+/// Generate the C++ files for the bridges in a single Rust file, outside of a build script.
+///
+/// This is the implementation of the `cxxqtbridge` command line tool for build systems other than Cargo.
+/// Sources are written to `cpp_dir` and headers to `header_dir` joined with the include prefix of the options.
+/// The generated files are named after the path of the Rust file relative to `relative_to`.
+/// Lint warnings are printed to stderr.
+///
+/// Returns the paths of all generated files, or reports the errors to stderr and returns [None].
+#[doc(hidden)]
+pub fn generate_bridge_files(
+    rust_file: impl AsRef<Path>,
+    relative_to: impl AsRef<Path>,
+    cpp_dir: impl AsRef<Path>,
+    header_dir: impl AsRef<Path>,
+    options: &BridgeOptions,
+) -> Option<Vec<PathBuf>> {
+    let rust_file = rust_file.as_ref();
+    let relative_path = rust_file
+        .strip_prefix(relative_to)
+        .unwrap_or_else(|_| Path::new(rust_file.file_name().unwrap_or_default()));
+    let header_dir = header_dir.as_ref().join(options.include_prefix);
+
+    let generated_bridges = match GeneratedCpp::new(rust_file, relative_path, options) {
+        Ok(v) => v,
+        Err(diagnostic) => {
+            diagnostic.report();
+            return None;
+        }
+    };
+
+    let mut generated_files = vec![];
+    for generated_code in generated_bridges {
+        for warning in &generated_code.warnings {
+            eprintln!("warning: {warning}");
+        }
+        let paths = generated_code.write_to_directories(&cpp_dir, &header_dir);
+        generated_files.extend(paths.qobject_header);
+        generated_files.extend(paths.qobject);
+        generated_files.push(paths.plain_header);
+        generated_files.push(paths.plain_cpp);
+    }
+    Some(generated_files)
+}
+
+/// Add the files of [CxxQtBuilder::cpp_file] and the generated sources to the builder
+///
+/// With a cache directory, the generated sources are compiled through [cache::compile_cached].
+/// This happens before the other files are added, as these would otherwise be compiled for
+/// every cache miss as well.
+fn add_sources(
+    builder: &mut cc::Build,
+    cpp_files: &[&Path],
+    generated_sources: &[PathBuf],
+    cache_dir: Option<&Path>,
+    system_include_dirs: &[PathBuf],
+) {
+    if let Some(cache_dir) = cache_dir {
+        let objects =
+            cache::compile_cached(builder, generated_sources, system_include_dirs, cache_dir);
+        builder.objects(objects);
+    } else {
+        builder.files(generated_sources);
+    }
+    builder.files(cpp_files);
 }
 
 pub(crate) fn crate_name() -> String {
@@ -367,9 +558,12 @@ pub struct CxxQtBuilder {
     qt_modules: HashSet<String>,
     qml_module: Option<QmlModule>,
     cc_builder: cc::Build,
+    /// C++ sources generated during the build, these are compiled through [cache::compile_cached]
+    generated_sources: Vec<PathBuf>,
     include_prefix: String,
     crate_include_root: Option<String>,
     additional_include_dirs: Vec<PathBuf>,
+    lints: LintConfig,
 }
 
 impl CxxQtBuilder {
@@ -409,9 +603,11 @@ impl CxxQtBuilder {
             qt_modules,
             qml_module: None,
             cc_builder: cc::Build::new(),
+            generated_sources: vec![],
             include_prefix: crate_name(),
             crate_include_root: Some(String::new()),
             additional_include_dirs: vec![],
+            lints: LintConfig::default(),
         }
     }
 
@@ -469,6 +665,52 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Discover the rust files to parse through the cxx-qt macro by following the module tree of the crate.
+    ///
+    /// Starting from `src/lib.rs` and `src/main.rs`, every `mod` declaration is followed (including
+    /// any `#[path]` attribute) and each file with a top-level `#[cxx_qt::bridge]` or `#[cxx::bridge]`
+    /// module is added as if it was passed to [Self::file]. Files which were already added are not added again.
+    ///
+    /// ```no_run
+    /// use cxx_qt_build::CxxQtBuilder;
+    ///
+    /// CxxQtBuilder::new().discover_files().build();
+    /// ```
+    ///
+    /// Modules which are disabled by a `#[cfg(...)]` attribute for this build are skipped,
+    /// as are modules whose cfg can not be known by the build script, such as `#[cfg(test)]`.
+    ///
+    /// Note: Modules which are only reachable through macros, such as `include!`, cannot be discovered
+    /// and need to be specified with [Self::file].
+    pub fn discover_files(self) -> Self {
+        let manifest_dir =
+            PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("Could not get manifest dir"));
+        let roots = ["src/lib.rs", "src/main.rs"].map(|root| manifest_dir.join(root));
+        let discovered = DiscoveredFiles::from_roots(roots, &cfg_evaluator::CargoEnvCfgEvaluator)
+            .unwrap_or_else(|diagnostic| {
+                diagnostic.report();
+                std::process::exit(1);
+            });
+
+        // Adding a mod declaration or a bridge to any of the scanned files could change the result
+        for scanned in &discovered.scanned {
+            println!("cargo::rerun-if-changed={}", scanned.display());
+        }
+
+        let relative = |path: PathBuf| {
+            path.strip_prefix(&manifest_dir)
+                .map(Path::to_path_buf)
+                .unwrap_or(path)
+        };
+        let new_sources = discovered
+            .bridges
+            .into_iter()
+            .map(relative)
+            .filter(|source| !self.rust_sources.contains(source))
+            .collect::<Vec<_>>();
+        self.files(new_sources)
+    }
+
     #[doc(hidden)]
     pub fn initializer(mut self, initializer: qt_build_utils::Initializer) -> Self {
         if let Some(ref init_file) = initializer.file {
@@ -528,6 +770,25 @@ impl CxxQtBuilder {
         self
     }
 
+    /// Set the level of a [Lint] for the bridges of this crate.
+    ///
+    /// Lints are opt-in, every lint is allowed by default.
+    /// Warnings are shown by Cargo when building the crate.
+    /// Denied lints fail the build, they are also reported as errors by the `#[cxx_qt::bridge]` macro.
+    ///
+    /// ```no_run
+    /// # use cxx_qt_build::{CxxQtBuilder, Lint, LintLevel};
+    /// CxxQtBuilder::new()
+    ///     .file("src/cxxqt_module.rs")
+    ///     .lint(Lint::UnsafeInvokable, LintLevel::Deny)
+    ///     .lint(Lint::MutableInvokable, LintLevel::Warn)
+    ///     .build();
+    /// ```
+    pub fn lint(mut self, lint: Lint, level: LintLevel) -> Self {
+        self.lints = std::mem::take(&mut self.lints).set(lint, level);
+        self
+    }
+
     /// Include files listed in a .qrc file into the binary
     /// with [Qt's resource system](https://doc.qt.io/qt-6/resources.html).
     /// ```no_run
@@ -685,7 +946,7 @@ impl CxxQtBuilder {
         // Wrap the File in a block scope so the file is closed before the compiler is run.
         // Otherwise MSVC fails to open cxx.h because the process for this build script already has it open.
         {
-            std::fs::write(h_path, cxx_gen::HEADER).expect("Failed to write cxx.h");
+            cache::write_if_changed(h_path, cxx_gen::HEADER);
         }
     }
 
@@ -715,6 +976,19 @@ impl CxxQtBuilder {
         }
     }
 
+    // A dependency exports the metadata of its bridges, so that QObjects can be imported with
+    // `#[import = "crate"]`.
+    // The path to the metadata is passed to the bridge macro as an environment variable,
+    // as well as to our own code generation.
+    fn import_dependency_metadata(dependency: &Dependency, metadata_paths: &mut MetadataPaths) {
+        let metadata_path = dependency.path.join("metadata.json");
+        if metadata_path.exists() {
+            let env_var = metadata_env_var(&dependency.manifest.name);
+            println!("cargo::rustc-env={env_var}={}", metadata_path.display());
+            metadata_paths.insert(&dependency.manifest.name, metadata_path);
+        }
+    }
+
     fn is_debug() -> bool {
         let debug = std::env::var("DEBUG").unwrap_or_else(|_| "0".to_owned());
         debug != "0" && debug != "false" || debug != "none"
@@ -748,7 +1022,7 @@ impl CxxQtBuilder {
     /// Returns the list of Moc products. Especially the qml_metatypes.json files are needed for
     /// the QML module generation later
     fn moc_cpp_files(&mut self, qtbuild: &mut qt_build_utils::QtBuild) -> Vec<MocProducts> {
-        self.cpp_files.iter().filter(|file| file.enable_moc).map(|CppFile {
+        let moc_inputs: Vec<(PathBuf, MocArguments)> = self.cpp_files.iter().filter(|file| file.enable_moc).map(|CppFile {
             path,
             moc_arguments,
             enable_moc: _,
@@ -777,29 +1051,78 @@ impl CxxQtBuilder {
                 }
                 moc_arguments = moc_arguments.uri(qml_module.uri.clone());
             }
-            let moc_products = qtbuild.moc().compile(path, moc_arguments);
+            (path.clone(), moc_arguments)
+        })
+        .collect();
+
+        // Run moc over all headers in parallel, the products stay in the order of the headers
+        let moc = qtbuild.moc();
+        let moc_products = qt_build_utils::parallel_map(&moc_inputs, |(path, moc_arguments)| {
+            moc.compile(path, moc_arguments.clone())
+        });
+
+        for products in &moc_products {
             // Include the moc folder
-            if let Some(dir) = moc_products.cpp.parent() {
+            if let Some(dir) = products.cpp.parent() {
                 self.cc_builder.include(dir);
             }
-            self.cc_builder.file(moc_products.cpp.clone());
-            moc_products
-        })
-        .collect()
+            self.generated_sources.push(products.cpp.clone());
+        }
+        moc_products
     }
 
     fn generate_cpp_files_from_cxxqt_bridges(
         &mut self,
         header_dir: impl AsRef<Path>,
         include_prefix: &str,
-    ) {
-        for files in generate_cxxqt_cpp_files(&self.rust_sources, &header_dir, include_prefix) {
-            self.cpp_files.push(files.plain_cpp.into());
+        metadata_paths: &MetadataPaths,
+    ) -> Vec<BridgeMetadata> {
+        let (generated_files, bridges, metatypes) = generate_cxxqt_cpp_files(
+            &self.rust_sources,
+            &header_dir,
+            &BridgeOptions {
+                include_prefix,
+                lints: &self.lints,
+                metadata_paths,
+                cfgs: None,
+            },
+        );
+        for files in generated_files {
+            self.generated_sources.push(files.plain_cpp);
             if let (Some(qobject), Some(qobject_header)) = (files.qobject, files.qobject_header) {
-                self.cpp_files.push(qobject.into());
+                self.generated_sources.push(qobject);
                 self.cpp_files.push(qobject_header.into());
             }
         }
+        self.write_bridge_metatypes(&metatypes);
+
+        bridges
+    }
+
+    /// Write the metatypes of the bridges and, for a QML module, an early `plugin.qmltypes`
+    ///
+    /// These are generated from the bridges directly, so tools like qmlls can already see the
+    /// QML types before moc and qmltyperegistrar have run (or when they fail).
+    /// The exported `plugin.qmltypes` is replaced with the output of qmltyperegistrar later on.
+    fn write_bridge_metatypes(&self, metatypes: &[serde_json::Value]) {
+        let metatypes_json =
+            serde_json::to_string_pretty(metatypes).expect("Failed to convert metatypes to JSON");
+        cache::write_if_changed(dir::gen().join("metatypes.json"), metatypes_json);
+
+        if let Some(qml_module) = &self.qml_module {
+            if let Some(plugin_dir) = dir::module_export(&qml_module.uri) {
+                std::fs::create_dir_all(&plugin_dir).expect("Could not create plugin directory");
+                cache::write_if_changed(
+                    plugin_dir.join("plugin.qmltypes"),
+                    write_qmltypes(
+                        metatypes,
+                        &qml_module.uri.as_dots(),
+                        qml_module.version_major,
+                        qml_module.version_minor,
+                    ),
+                );
+            }
+        }
     }
 
     fn export_object_file(
@@ -885,6 +1208,7 @@ impl CxxQtBuilder {
             // but we cannot have separate builds until we can configure includes,
             // qt modules, files, cc_builder options etc in the QmlModule itself
             let cc_builder = &mut self.cc_builder;
+            let generated_sources = &mut self.generated_sources;
             qtbuild.cargo_link_libraries(cc_builder);
 
             let mut qml_metatypes_json: Vec<PathBuf> = moc_products
@@ -894,8 +1218,7 @@ impl CxxQtBuilder {
 
             // Inject CXX-Qt builtin meta types
             let builtins_path = dir::out().join("builtins.h");
-            std::fs::write(&builtins_path, include_str!("../cpp/builtins.h"))
-                .expect("Failed to write builtins.h");
+            cache::write_if_changed(&builtins_path, include_str!("../cpp/builtins.h"));
             qml_metatypes_json.push(
                 qtbuild
                     .moc()
@@ -917,15 +1240,14 @@ impl CxxQtBuilder {
                 qml_module.plugin_type,
             );
             if let Some(qmltyperegistrar) = qml_module_registration_files.qmltyperegistrar {
-                cc_builder.file(qmltyperegistrar);
+                generated_sources.push(qmltyperegistrar);
             }
-            cc_builder
-                .file(qml_module_registration_files.plugin)
-                // In comparison to the other RCC files, we don't need to link this with whole-archive or
-                // anything like that.
-                // The plugin_init file already takes care of loading the resources associated with this
-                // RCC file.
-                .file(qml_module_registration_files.rcc);
+            generated_sources.push(qml_module_registration_files.plugin);
+            // In comparison to the other RCC files, we don't need to link this with whole-archive or
+            // anything like that.
+            // The plugin_init file already takes care of loading the resources associated with this
+            // RCC file.
+            generated_sources.push(qml_module_registration_files.rcc);
 
             // Add any include paths the qml module registration needs
             // this is most likely the moc folder for the plugin
@@ -933,9 +1255,7 @@ impl CxxQtBuilder {
                 cc_builder.include(include_path);
             }
 
-            for qmlcachegen_file in qml_module_registration_files.qmlcachegen {
-                cc_builder.file(qmlcachegen_file);
-            }
+            generated_sources.extend(qml_module_registration_files.qmlcachegen);
             // Set the appropriate compile-time definitions in the cc_builder
             if qml_module.plugin_type == PluginType::Static {
                 // This is required, as described here: https://doc.qt.io/qt-6/plugins-howto.html#creating-static-plugins
@@ -1230,7 +1550,12 @@ extern "C" bool {init_fun}() {{
     /// Generate and compile cxx-qt C++ code, as well as compile any additional files from
     /// [CxxQtBuilder::cpp_file] and [CxxQtBuilder::cc_builder].
     pub fn build(mut self) -> Interface {
-        dir::clean(dir::crate_target()).expect("Failed to clean crate export directory!");
+        // Keep the previous contents of the crate export directory around until the end of the
+        // build, so that unchanged generated headers keep their modification time
+        dir::clean_keeping_previous(dir::crate_target())
+            .expect("Failed to clean crate export directory!");
+        // Files added through the cc_builder callback would be compiled again for every cache miss
+        let cache_objects = self.cc_builder.get_files().count() == 0;
 
         // We will do these two steps first, as setting up the dependencies can modify flags we
         // need further down the line
@@ -1238,8 +1563,10 @@ extern "C" bool {init_fun}() {{
         // dependencies
         Self::write_common_headers();
         let dependencies = Dependency::find_all();
+        let mut metadata_paths = MetadataPaths::default();
         for dependency in &dependencies {
             self.include_dependency(dependency);
+            Self::import_dependency_metadata(dependency, &mut metadata_paths);
         }
         let qt_modules = self.qt_modules(&dependencies);
 
@@ -1316,13 +1643,17 @@ extern "C" bool {init_fun}() {{
 
         Self::setup_cc_builder(&mut self.cc_builder, &include_paths);
 
+        // Pass the lint config on to the #[cxx_qt::bridge] macro, so it can report denied lints
+        println!("cargo::rustc-env={LINT_CONFIG_ENV_VAR}={}", self.lints);
+
         // Generate files
-        self.generate_cpp_files_from_cxxqt_bridges(&header_root, &self.include_prefix.clone());
+        let bridges = self.generate_cpp_files_from_cxxqt_bridges(
+            &header_root,
+            &self.include_prefix.clone(),
+            &metadata_paths,
+        );
 
         let moc_products = self.moc_cpp_files(&mut qtbuild);
-        for cpp_file in self.cpp_files.iter().filter(|file| file.compile) {
-            self.cc_builder.file(&cpp_file.path);
-        }
 
         // Bridges for QML modules are handled separately because
         // the metatypes_json generated by moc needs to be passed to qmltyperegistrar
@@ -1331,6 +1662,23 @@ extern "C" bool {init_fun}() {{
         self.generate_qrc_files_from_resources();
         let qrc_initializers = self.generate_cpp_from_qrc_files(&mut qtbuild);
 
+        // Compile the generated sources before the initializers are added to the builder,
+        // but after the QML modules have added their definitions
+        let has_generated_sources = !self.generated_sources.is_empty();
+        let cpp_files: Vec<&Path> = self
+            .cpp_files
+            .iter()
+            .filter(|file| file.compile)
+            .map(|file| file.path.as_path())
+            .collect();
+        add_sources(
+            &mut self.cc_builder,
+            &cpp_files,
+            &self.generated_sources,
+            cache_objects.then(dir::object_cache).as_deref(),
+            &qtbuild.include_paths(),
+        );
+
         let dependency_initializers = dependencies::initializers(&dependencies);
         let private_initializers = dependency_initializers
             .into_iter()
@@ -1355,10 +1703,13 @@ extern "C" bool {init_fun}() {{
 
         // Only compile if we have added files to the builder
         // otherwise we end up with no static library but ask cargo to link to it which causes an error
-        if self.cc_builder.get_files().count() > 0 {
+        if self.cc_builder.get_files().count() > 0 || has_generated_sources {
             self.cc_builder.compile(&static_lib_name());
         }
 
+        dir::remove_previous(dir::crate_target())
+            .expect("Failed to remove previous crate export directory!");
+
         Interface {
             manifest: Manifest {
                 name: crate_name(),
@@ -1368,7 +1719,147 @@ extern "C" bool {init_fun}() {{
                 exported_include_prefixes: vec![],
             },
             dependencies,
+            bridges,
             ..Default::default()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Generate the bridges of a Rust file with the given contents, returning their file idents
+    fn generated_file_idents(file_name: &str, contents: &str) -> Vec<String> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(file_name);
+        std::fs::write(&path, contents).unwrap();
+        GeneratedCpp::new(
+            &path,
+            Path::new("src").join(file_name),
+            &BridgeOptions {
+                include_prefix: "crate",
+                lints: &LintConfig::default(),
+                metadata_paths: &MetadataPaths::default(),
+                cfgs: None,
+            },
+        )
+        .unwrap_or_else(|diagnostic| {
+            diagnostic.report();
+            panic!("Failed to generate {file_name}");
+        })
+        .into_iter()
+        .map(|generated| generated.file_ident)
+        .collect()
+    }
+
+    const CXX_QT_BRIDGE: &str = r#"
+        #[cxx_qt::bridge]
+        mod qobject {
+            extern "RustQt" {
+                #[qobject]
+                type MyObject = super::MyObjectRust;
+            }
+        }
+    "#;
+
+    const CXX_BRIDGE: &str = r#"
+        #[cxx::bridge]
+        mod helpers {
+            extern "Rust" {
+                fn helper() -> i32;
+            }
+        }
+    "#;
+
+    #[test]
+    fn test_single_bridge() {
+        assert_eq!(
+            generated_file_idents("object.rs", CXX_QT_BRIDGE),
+            ["src/object"]
+        );
+    }
+
+    #[test]
+    fn test_multiple_bridges_keep_name_of_cxx_qt_bridge() {
+        // Adding a CXX bridge before the CXX-Qt bridge does not rename the CXX-Qt header
+        assert_eq!(
+            generated_file_idents("object.rs", &format!("{CXX_BRIDGE}{CXX_QT_BRIDGE}")),
+            ["src/object_helpers", "src/object"]
+        );
+        assert_eq!(
+            generated_file_idents("object.rs", &format!("{CXX_QT_BRIDGE}{CXX_BRIDGE}")),
+            ["src/object", "src/object_helpers"]
+        );
+    }
+
+    #[test]
+    fn test_multiple_bridges_without_cxx_qt_bridge() {
+        let second_bridge = CXX_BRIDGE
+            .replace("mod helpers", "mod more_helpers")
+            .replace("fn helper", "fn other_helper");
+        assert_eq!(
+            generated_file_idents("helpers.rs", &format!("{CXX_BRIDGE}{second_bridge}")),
+            ["src/helpers", "src/helpers_more_helpers"]
+        );
+    }
+
+    #[test]
+    fn test_conflicting_file_idents() {
+        let mut file_idents = FileIdents::default();
+        assert!(file_idents
+            .insert(Path::new("src/foo.rs"), "src/foo_bar")
+            .is_ok());
+        assert!(file_idents
+            .insert(Path::new("src/foo.rs"), "src/foo")
+            .is_ok());
+        assert!(file_idents
+            .insert(Path::new("src/foo_bar.rs"), "src/foo_bar")
+            .is_err());
+    }
+
+    #[test]
+    fn test_add_sources_with_cpp_files() {
+        // The target of the tests is the host, which rustc reports
+        let rustc = std::process::Command::new(std::env::var("RUSTC").unwrap_or("rustc".into()))
+            .arg("-vV")
+            .output()
+            .unwrap();
+        let host = String::from_utf8(rustc.stdout)
+            .unwrap()
+            .lines()
+            .find_map(|line| line.strip_prefix("host: ").map(str::to_owned))
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache_dir = dir.path().join("objects");
+        std::fs::create_dir(&cache_dir).unwrap();
+        let generated = dir.path().join("generated.cpp");
+        std::fs::write(&generated, "int generated() { return 1; }\n").unwrap();
+        let user = dir.path().join("user.cpp");
+        std::fs::write(&user, "int user() { return 2; }\n").unwrap();
+
+        let mut builder = cc::Build::new();
+        builder
+            .cpp(true)
+            .cargo_metadata(false)
+            .target(&host)
+            .host(&host)
+            .opt_level(0)
+            .out_dir(dir.path().join("out"));
+        add_sources(
+            &mut builder,
+            &[user.as_path()],
+            std::slice::from_ref(&generated),
+            Some(&cache_dir),
+            &[],
+        );
+
+        // Only the user file is compiled with the builder, the generated one is a cached object
+        assert_eq!(
+            builder.get_files().collect::<Vec<_>>(),
+            vec![user.as_path()]
+        );
+        assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 1);
+    }
+}
@@ -4,11 +4,24 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::syntax::cfg::{parse_attribute, CfgExpr};
+use crate::{
+    parser::extract_cfgs,
+    syntax::cfg::{parse_attribute, CfgExpr},
+};
 use cxx_gen::{CfgEvaluator, CfgResult};
 use quote::quote;
 use syn::{Attribute, Error, LitStr};
 
+/// Evaluate the `#[cfg(...)]` attributes of an item, other attributes are ignored
+///
+/// Returns an error if a cfg can not be determined by the evaluator.
+pub fn eval_cfg_attributes(
+    cfg_evaluator: &dyn CfgEvaluator,
+    attrs: &[Attribute],
+) -> Result<bool, Error> {
+    try_eval_attributes(cfg_evaluator, &extract_cfgs(attrs))
+}
+
 pub(crate) fn try_eval_attributes(
     cfg_evaluator: &dyn CfgEvaluator,
     attrs: &[Attribute],
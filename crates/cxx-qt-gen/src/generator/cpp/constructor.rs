@@ -16,8 +16,14 @@ fn default_constructor(
     qobject: &GeneratedCppQObject,
     base_class: String,
     initializers: String,
+    statements: String,
 ) -> GeneratedCppQObjectBlocks {
     let class_name = qobject.name.cxx_unqualified();
+    let body = if statements.is_empty() {
+        "{ }".to_owned()
+    } else {
+        format!("{{\n{statements}}}")
+    };
     let rust_obj = qobject.rust_struct.cxx_qualified();
     let constructor = if qobject.has_qobject_macro {
         CppFragment::Pair {
@@ -27,7 +33,7 @@ fn default_constructor(
             {class_name}::{class_name}(QObject* parent)
               : {base_class}(parent)
               , ::rust::cxxqt1::CxxQtType<{rust_obj}>(::{namespace_internals}::createRs()){initializers}
-            {{ }}
+            {body}
             "#,
                 namespace_internals = qobject.namespace_internals,
             ),
@@ -40,7 +46,7 @@ fn default_constructor(
             {class_name}::{class_name}()
               {base_class_line}
               , ::rust::cxxqt1::CxxQtType<{rust_obj}>(::{namespace_internals}::createRs()){initializers}
-            {{ }}
+            {body}
             "#,
                 base_class_line = if base_class.is_empty() {
                     // CODECOV_EXCLUDE_START
@@ -84,6 +90,7 @@ pub fn generate(
     constructors: &[&Constructor],
     base_class: String,
     class_initializers: &[String],
+    class_statements: &[String],
     type_names: &TypeNames,
 ) -> Result<GeneratedCppQObjectBlocks> {
     let initializers = class_initializers
@@ -91,9 +98,20 @@ pub fn generate(
         .map(|initializer| format!("\n  , {initializer}"))
         .collect::<Vec<_>>()
         .join("");
+    // Statements which are run in the body of the constructor, before the Rust initialize
+    let statements = class_statements
+        .iter()
+        .map(|statement| format!("  {statement}\n"))
+        .collect::<Vec<_>>()
+        .join("");
 
     if constructors.is_empty() {
-        return Ok(default_constructor(qobject, base_class, initializers));
+        return Ok(default_constructor(
+            qobject,
+            base_class,
+            initializers,
+            statements,
+        ));
     }
 
     let mut generated = GeneratedCppQObjectBlocks::default();
@@ -143,7 +161,7 @@ pub fn generate(
                   : {base_class}({base_args})
                   , ::rust::cxxqt1::CxxQtType<{rust_obj}>(::{namespace_internals}::newRs{index}(::std::move(args.new_))){initializers}
                 {{
-                  ::{namespace_internals}::initialize{index}(*this, ::std::move(args.initialize));
+                {statements}  ::{namespace_internals}::initialize{index}(*this, ::std::move(args.initialize));
                 }}
                 "#,
             },
@@ -173,6 +191,7 @@ mod tests {
             namespace_internals: "rust".to_owned(),
             blocks: GeneratedCppQObjectBlocks::default(),
             has_qobject_macro: true,
+            doc_comment: String::new(),
         }
     }
 
@@ -200,6 +219,7 @@ mod tests {
             &[],
             "BaseClass".to_owned(),
             &["member1(1)".to_owned(), "member2{ 2 }".to_owned()],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -230,6 +250,7 @@ mod tests {
             &[],
             "BaseClass".to_owned(),
             &[],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -252,6 +273,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_constructor_with_statements() {
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[],
+            "BaseClass".to_owned(),
+            &[],
+            &["statement1();".to_owned(), "statement2();".to_owned()],
+            &type_names_with_qobject(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            blocks.methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(QObject* parent = nullptr);".to_owned(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(QObject* parent)
+                      : BaseClass(parent)
+                      , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::rust::createRs())
+                    {{
+                      statement1();
+                      statement2();
+                    }}
+                    "
+                ),
+            }]
+        );
+    }
+
+    #[test]
+    fn constructor_with_statements() {
+        let blocks = generate(
+            &qobject_for_testing(),
+            &[&mock_constructor()],
+            "BaseClass".to_owned(),
+            &[],
+            &["statement();".to_owned()],
+            &type_names_with_qobject(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            blocks.private_methods,
+            vec![CppFragment::Pair {
+                header: "explicit MyObject(::rust::CxxQtConstructorArguments0&& args);".to_owned(),
+                source: formatdoc!(
+                    "
+                    MyObject::MyObject(::rust::CxxQtConstructorArguments0&& args)
+                      : BaseClass()
+                      , ::rust::cxxqt1::CxxQtType<MyObjectRust>(::rust::newRs0(::std::move(args.new_)))
+                    {{
+                      statement();
+                      ::rust::initialize0(*this, ::std::move(args.initialize));
+                    }}
+                    "
+                ),
+            }]
+        );
+    }
+
     #[test]
     fn default_constructor_no_qobject_macro() {
         let mut qobject = qobject_for_testing();
@@ -261,6 +344,7 @@ mod tests {
             &[],
             "BaseClass".to_owned(),
             &[],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -293,6 +377,7 @@ mod tests {
             }],
             "BaseClass".to_owned(),
             &[],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -343,6 +428,7 @@ mod tests {
             }],
             "BaseClass".to_owned(),
             &["initializer".to_owned()],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -397,6 +483,7 @@ mod tests {
             ],
             "BaseClass".to_owned(),
             &["initializer".to_owned()],
+            &[],
             &type_names_with_qobject(),
         )
         .unwrap();
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::{
-    generator::{cpp::signal::generate_cpp_signal, GeneratedOpt},
+    generator::{cpp::signal::generate_cpp_signal, naming::signals::is_overloaded, GeneratedOpt},
     naming::TypeNames,
     parser::externcxxqt::ParsedExternCxxQt,
     CppFragment,
@@ -34,7 +34,8 @@ pub fn generate(
     for block in blocks {
         for signal in &block.signals {
             let qobject_name = type_names.lookup(&signal.qobject_ident)?;
-            let data = generate_cpp_signal(signal, qobject_name, type_names, opt)?;
+            let overloaded = is_overloaded(&signal.name, block.signal_names(&signal.qobject_ident));
+            let data = generate_cpp_signal(signal, overloaded, qobject_name, type_names, opt)?;
             debug_assert!(data.methods.is_empty());
             let block = GeneratedCppExternCxxQtBlocks {
                 includes: data.includes,
@@ -71,6 +72,7 @@ pub fn generate(
 
 #[cfg(test)]
 mod tests {
+    use crate::metadata::MetadataPaths;
     use quote::format_ident;
     use syn::parse_quote;
 
@@ -93,6 +95,7 @@ mod tests {
             },
             &format_ident!("qobject"),
             None,
+            &MetadataPaths::default(),
         )
         .unwrap()];
 
@@ -120,6 +123,7 @@ mod tests {
             },
             &format_ident!("qobject"),
             None,
+            &MetadataPaths::default(),
         )
         .unwrap()];
         let mut type_names = TypeNames::default();
@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks};
+use indoc::formatdoc;
+
+/// Generate a destructor which calls cxx_qt::Finalize before the QObject is destroyed
+///
+/// As the destructor body runs before the base classes are destroyed,
+/// the QObject and its threading guard are still valid when the Rust code is called.
+pub fn generate(class_name: &str, namespace_internals: &str) -> GeneratedCppQObjectBlocks {
+    GeneratedCppQObjectBlocks {
+        has_destructor: true,
+        // The destructor is declared by the class definition when has_destructor is set
+        methods: vec![CppFragment::Source(formatdoc! {
+            r#"
+            {class_name}::~{class_name}()
+            {{
+              ::{namespace_internals}::finalize(*this);
+            }}
+            "#
+        })],
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::require_source;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn test_generate_cpp_finalize() {
+        let blocks = generate("MyObject", "rust::cxxqtgen1");
+
+        assert!(blocks.has_destructor);
+        assert_eq!(blocks.methods.len(), 1);
+        let source = require_source(&blocks.methods[0]).unwrap();
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+                MyObject::~MyObject()
+                {
+                  ::rust::cxxqtgen1::finalize(*this);
+                }
+            "#}
+        );
+    }
+}
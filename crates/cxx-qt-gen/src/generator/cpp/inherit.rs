@@ -98,6 +98,7 @@ mod tests {
         let base_class = Some("TestBaseClass".to_owned());
         let opt = GeneratedOpt {
             cfg_evaluator: Box::new(CfgEvaluatorTest::default()),
+            ..Default::default()
         };
         let generated =
             generate(&inherited_methods, &base_class, &TypeNames::default(), &opt).unwrap();
@@ -10,17 +10,86 @@ use crate::{
             fragment::{CppFragment, CppNamedType},
             get_cpp_params,
             qobject::GeneratedCppQObjectBlocks,
+            utils::doxygen_comment,
             GeneratedOpt,
         },
+        naming::method::throwing_cxx_name,
     },
     naming::cpp::{syn_return_type_to_cpp_except, syn_type_to_cpp_return_type},
     naming::TypeNames,
-    parser::method::{ParsedMethod, ParsedQInvokableSpecifiers},
+    parser::{
+        method::{ParsedMethod, ParsedQInvokableSpecifiers},
+        parameter::ParsedFunctionParameter,
+    },
 };
+use indoc::formatdoc;
 use syn::Result;
 
+/// Generate a Q_INVOKABLE which calls the method implemented by Rust, so that a `rust::Error`
+/// returned by it is thrown as a JavaScript error when the QObject belongs to a QML engine.
+///
+/// Without a QML engine the error is rethrown, C++ callers can also call the throwing method directly.
+fn generate_qml_throwing_invokable(
+    class_name: &str,
+    return_cxx_ty: &str,
+    ident: &str,
+    parameters: &[CppNamedType],
+    is_const: &str,
+    doc_comment: &str,
+) -> CppFragment {
+    let throwing_ident = throwing_cxx_name(ident);
+    let parameter_types = parameters
+        .iter()
+        .map(|parameter| format!("{ty} {ident}", ident = parameter.ident, ty = parameter.ty))
+        .collect::<Vec<_>>()
+        .join(", ");
+    // References are passed on as they are, values are moved
+    let arguments = parameters
+        .iter()
+        .map(|parameter| {
+            if parameter.ty.ends_with('&') {
+                parameter.ident.clone()
+            } else {
+                format!("::std::move({ident})", ident = parameter.ident)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let fallback = if return_cxx_ty == "void" {
+        ""
+    } else {
+        "\n    return {};"
+    };
+
+    CppFragment::Pair {
+        header: format!(
+            "{doc_comment}Q_INVOKABLE {return_cxx_ty} {ident}({parameter_types}){is_const};"
+        ),
+        source: formatdoc! {
+            r#"
+            {return_cxx_ty}
+            {class_name}::{ident}({parameter_types}){is_const}
+            {{
+              try {{
+                return {throwing_ident}({arguments});
+              }} catch (const ::rust::Error& error) {{
+                auto* engine = qmlEngine(this);
+                if (engine == nullptr) {{
+                  throw;
+                }}
+                engine->throwError(QString::fromUtf8(error.what()));{fallback}
+              }}
+            }}
+            "#,
+        },
+    }
+}
+
+/// Generate the C++ declarations of the methods of a QObject,
+/// the errors of invokables are only thrown to QML when the QObject is a QML element
 pub fn generate_cpp_methods(
     invokables: &Vec<&ParsedMethod>,
+    qml_element: bool,
     type_names: &TypeNames,
     opt: &GeneratedOpt,
 ) -> Result<GeneratedCppQObjectBlocks> {
@@ -35,12 +104,23 @@ pub fn generate_cpp_methods(
 
         let parameters: Vec<CppNamedType> = get_cpp_params(&invokable.method, type_names)?;
 
-        let parameter_types = parameters
-            .iter()
-            .map(|parameter| format!("{ty} {ident}", ident = parameter.ident, ty = parameter.ty))
-            .collect::<Vec<String>>()
-            .join(", ");
-        let is_const = if invokable.mutable { "" } else { " const" };
+        let parameter_types = |parameters: &[CppNamedType]| {
+            parameters
+                .iter()
+                .map(|parameter| {
+                    format!("{ty} {ident}", ident = parameter.ident, ty = parameter.ty)
+                })
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        // Static methods have no instance, so they are never const
+        let (is_static, is_const) = if invokable.is_static {
+            ("static ", "")
+        } else if invokable.mutable {
+            ("", "")
+        } else {
+            ("", " const")
+        };
 
         let mut is_final = "";
         let mut is_override = "";
@@ -76,10 +156,51 @@ pub fn generate_cpp_methods(
         //
         // CXX generates the source and we just need the matching header.
         let has_noexcept = syn_return_type_to_cpp_except(&invokable.method.sig.output);
+        let ident = invokable.name.cxx_unqualified();
+        let doc_comment = doxygen_comment(&invokable.docs);
+
+        // Parameters with default values are implemented as overloads, so that they can be
+        // called with fewer arguments from QML as well
+        let mut overloads = vec![parameters.as_slice()];
+        if let Some(first_default) = ParsedFunctionParameter::first_default(&invokable.parameters) {
+            overloads.extend((first_default..parameters.len()).map(|count| &parameters[..count]));
+        }
+
+        if qml_element && invokable.throws_to_qml() {
+            generated
+                .includes
+                .insert("#include <QtQml/QQmlEngine>".to_owned());
+            let class_name = type_names
+                .lookup(&invokable.qobject_ident)?
+                .cxx_unqualified();
+            let throwing_ident = throwing_cxx_name(&ident);
+            for parameters in overloads {
+                generated.methods.push(CppFragment::Header(format!(
+                    "{doc_comment}{return_cxx_ty} {throwing_ident}({parameter_types}){is_const};",
+                    parameter_types = parameter_types(parameters),
+                )));
+                generated.methods.push(generate_qml_throwing_invokable(
+                    &class_name,
+                    return_cxx_ty,
+                    &ident,
+                    parameters,
+                    is_const,
+                    &doc_comment,
+                ));
+            }
+            continue;
+        }
+
         generated.methods.push(CppFragment::Header(format!(
-            "{is_qinvokable}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override}{is_pure};",
-            ident = invokable.name.cxx_unqualified(),
+            "{doc_comment}{is_qinvokable}{is_static}{is_virtual}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept}{is_final}{is_override}{is_pure};",
+            parameter_types = parameter_types(overloads[0]),
         )));
+        for parameters in &overloads[1..] {
+            generated.methods.push(CppFragment::Header(format!(
+                "{doc_comment}{is_qinvokable}{is_static}{return_cxx_ty} {ident}({parameter_types}){is_const} {has_noexcept};",
+                parameter_types = parameter_types(parameters),
+            )));
+        }
     }
 
     Ok(generated)
@@ -89,8 +210,9 @@ pub fn generate_cpp_methods(
 mod tests {
     use super::*;
 
-    use crate::generator::cpp::property::tests::require_header;
+    use crate::generator::cpp::property::tests::{require_header, require_pair};
     use crate::tests::CfgEvaluatorTest;
+    use indoc::indoc;
     use pretty_assertions::assert_str_eq;
     use std::collections::HashSet;
     use syn::{parse_quote, ForeignItemFn};
@@ -106,9 +228,10 @@ mod tests {
         let type_names = TypeNames::mock();
         let opt = GeneratedOpt {
             cfg_evaluator: Box::new(CfgEvaluatorTest::default()),
+            ..Default::default()
         };
         let generated =
-            generate_cpp_methods(&invokables.iter().collect(), &type_names, &opt).unwrap();
+            generate_cpp_methods(&invokables.iter().collect(), false, &type_names, &opt).unwrap();
 
         assert!(generated.methods.is_empty());
     }
@@ -156,6 +279,7 @@ mod tests {
 
         let generated = generate_cpp_methods(
             &invokables.iter().collect(),
+            false,
             &type_names,
             &GeneratedOpt::default(),
         )
@@ -191,6 +315,136 @@ mod tests {
         assert_eq!(generated.private_methods.len(), 0);
     }
 
+    #[test]
+    fn test_generate_cpp_invokables_static() {
+        let method_declaration: ForeignItemFn = parse_quote! {
+            #[cxx_name = "staticInvokable"]
+            #[Self = "MyObject"]
+            fn static_invokable(param: i32) -> i32;
+        };
+
+        let method = ParsedMethod::mock_qinvokable(&method_declaration);
+        let generated = generate_cpp_methods(
+            &vec![&method],
+            false,
+            &TypeNames::mock(),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE static ::std::int32_t staticInvokable(::std::int32_t param) noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_defaults() {
+        let method_declaration: ForeignItemFn = parse_quote! {
+            #[cxx_name = "defaultInvokable"]
+            fn default_invokable(self: &MyObject, first: i32, #[default = 1] second: i32, #[default = 2] third: i32);
+        };
+
+        let method = ParsedMethod::mock_qinvokable(&method_declaration);
+        let generated = generate_cpp_methods(
+            &vec![&method],
+            false,
+            &TypeNames::mock(),
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.methods.len(), 3);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE void defaultInvokable(::std::int32_t first, ::std::int32_t second, ::std::int32_t third) const noexcept;"
+        );
+        let header = require_header(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE void defaultInvokable(::std::int32_t first) const noexcept;"
+        );
+        let header = require_header(&generated.methods[2]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE void defaultInvokable(::std::int32_t first, ::std::int32_t second) const noexcept;"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_result() {
+        let method_declaration: ForeignItemFn = parse_quote! {
+            #[cxx_name = "parseValue"]
+            fn parse_value(self: &MyObject, text: &QString, base: i32) -> Result<i32>;
+        };
+
+        let method = ParsedMethod::mock_qinvokable(&method_declaration);
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+        let generated =
+            generate_cpp_methods(&vec![&method], true, &type_names, &GeneratedOpt::default())
+                .unwrap();
+
+        assert!(generated.includes.contains("#include <QtQml/QQmlEngine>"));
+        assert_eq!(generated.methods.len(), 2);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "::std::int32_t parseValueOrThrow(QString const& text, ::std::int32_t base) const;"
+        );
+        let (header, source) = require_pair(&generated.methods[1]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE ::std::int32_t parseValue(QString const& text, ::std::int32_t base) const;"
+        );
+        assert_str_eq!(
+            source,
+            indoc! {r#"
+                ::std::int32_t
+                MyObject::parseValue(QString const& text, ::std::int32_t base) const
+                {
+                  try {
+                    return parseValueOrThrow(text, ::std::move(base));
+                  } catch (const ::rust::Error& error) {
+                    auto* engine = qmlEngine(this);
+                    if (engine == nullptr) {
+                      throw;
+                    }
+                    engine->throwError(QString::fromUtf8(error.what()));
+                    return {};
+                  }
+                }
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_invokables_result_without_qml() {
+        let method_declaration: ForeignItemFn = parse_quote! {
+            #[cxx_name = "parseValue"]
+            fn parse_value(self: &MyObject, text: &QString) -> Result<i32>;
+        };
+
+        let method = ParsedMethod::mock_qinvokable(&method_declaration);
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QString", None, None, None);
+        let generated =
+            generate_cpp_methods(&vec![&method], false, &type_names, &GeneratedOpt::default())
+                .unwrap();
+
+        // Without a QML module the error is thrown to C++ as a rust::Error
+        assert!(generated.includes.is_empty());
+        assert_eq!(generated.methods.len(), 1);
+        let header = require_header(&generated.methods[0]).unwrap();
+        assert_str_eq!(
+            header,
+            "Q_INVOKABLE ::std::int32_t parseValue(QString const& text) const ;"
+        );
+    }
+
     #[test]
     fn test_generate_cpp_invokables_mapped_cxx_name() {
         let method_declaration: ForeignItemFn = parse_quote! {
@@ -206,7 +460,8 @@ mod tests {
         type_names.mock_insert("B", None, Some("B2"), None);
 
         let generated =
-            generate_cpp_methods(&invokables, &type_names, &GeneratedOpt::default()).unwrap();
+            generate_cpp_methods(&invokables, false, &type_names, &GeneratedOpt::default())
+                .unwrap();
 
         // methods
         assert_eq!(generated.methods.len(), 1);
@@ -6,11 +6,13 @@
 mod constructor;
 pub mod cxxqttype;
 pub mod externcxxqt;
+pub mod finalize;
 pub mod fragment;
 pub mod inherit;
 pub mod method;
 pub mod property;
 pub mod qenum;
+pub mod qinterface;
 pub mod qnamespace;
 pub mod qobject;
 pub mod signal;
@@ -50,12 +52,11 @@ impl GeneratedCppBlocks {
 
         let mut includes = BTreeSet::new();
 
-        let mut forward_declares: Vec<_> = parser
-            .cxx_qt_data
+        let mut forward_declares: Vec<_> = structures
             .qnamespaces
             .iter()
-            .map(|parsed_qnamespace| qnamespace::generate(parsed_qnamespace, &mut includes))
-            .collect();
+            .map(|qnamespace| qnamespace::generate(qnamespace, &mut includes, opt))
+            .collect::<Result<Vec<String>>>()?;
         forward_declares.extend(
             parser
                 .cxx_qt_data
@@ -82,11 +83,30 @@ impl GeneratedCppBlocks {
                     }
                 })
                 .collect::<Result<Vec<GeneratedCppQObject>>>()?,
-            extern_cxx_qt: externcxxqt::generate(
-                &parser.cxx_qt_data.extern_cxxqt_blocks,
-                &parser.type_names,
-                opt,
-            )?,
+            extern_cxx_qt: parser
+                .cxx_qt_data
+                .qinterfaces
+                .iter()
+                .map(|interface| qinterface::generate(interface, &parser.type_names, opt))
+                .chain(
+                    structures
+                        .qnamespaces
+                        .iter()
+                        .map(|qnamespace| {
+                            qnamespace::generate_qml_singleton(qnamespace, &parser.type_names, opt)
+                        })
+                        .filter_map(Result::transpose),
+                )
+                .chain(
+                    externcxxqt::generate(
+                        &parser.cxx_qt_data.extern_cxxqt_blocks,
+                        &parser.type_names,
+                        opt,
+                    )?
+                    .into_iter()
+                    .map(Ok),
+                )
+                .collect::<Result<Vec<_>>>()?,
         })
     }
 }
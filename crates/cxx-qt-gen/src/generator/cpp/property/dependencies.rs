@@ -0,0 +1,99 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    naming::{
+        property::{NameState, QPropertyNames},
+        qobject::QObjectNames,
+        signals::is_overloaded,
+    },
+    structuring::StructuredQObject,
+};
+use crate::{naming::Name, parser::property::ParsedQProperty};
+use syn::{Error, Result};
+
+/// Generate the constructor statements which connect the notify signals of the dependencies
+/// of each property to the notify signal of the property
+pub fn generate(
+    properties: &[ParsedQProperty],
+    qobject_idents: &QObjectNames,
+    structured_qobject: &StructuredQObject,
+) -> Result<Vec<String>> {
+    let class_name = qobject_idents.name.cxx_unqualified();
+    let mut statements = vec![];
+
+    // An overloaded notify signal is selected by its empty parameter list
+    let signal_names = structured_qobject.signal_names();
+    let signal_pointer = |name: &Name| {
+        let signal = name.cxx_unqualified();
+        if is_overloaded(name, signal_names.iter().copied()) {
+            format!("QOverload<>::of(&{class_name}::{signal})")
+        } else {
+            format!("&{class_name}::{signal}")
+        }
+    };
+
+    for property in properties {
+        if property.flags.depends_on.is_empty() {
+            continue;
+        }
+
+        let property_ident = property.name.rust_unqualified();
+        let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
+        let Some(notify) = &idents.notify else {
+            return Err(Error::new(
+                property_ident.span(),
+                "depends_on can only be used with properties that have a NOTIFY signal!",
+            ));
+        };
+
+        // The notify signal is emitted by the dependencies, so it cannot take any parameters
+        if let NameState::Custom(name) = notify {
+            let has_parameters = structured_qobject.signals.iter().any(|signal| {
+                signal.name.rust_unqualified() == name.rust_unqualified()
+                    && !signal.parameters.is_empty()
+            });
+            if has_parameters {
+                return Err(Error::new(
+                    property_ident.span(),
+                    "depends_on can only be used with a NOTIFY signal without parameters!",
+                ));
+            }
+        }
+
+        for dependency in &property.flags.depends_on {
+            if dependency == property_ident {
+                return Err(Error::new(
+                    dependency.span(),
+                    "A property cannot depend on itself!",
+                ));
+            }
+
+            let source = properties
+                .iter()
+                .find(|source| source.name.rust_unqualified() == dependency)
+                .ok_or_else(|| {
+                    Error::new(
+                        dependency.span(),
+                        "depends_on must refer to the Rust name of a property on the same QObject!",
+                    )
+                })?;
+            let source_idents = QPropertyNames::try_from_property(source, structured_qobject)?;
+            let Some(source_notify) = &source_idents.notify else {
+                return Err(Error::new(
+                    dependency.span(),
+                    "Properties in depends_on must have a NOTIFY signal!",
+                ));
+            };
+
+            statements.push(format!(
+                "QObject::connect(this, {source_notify}, this, {notify});",
+                source_notify = signal_pointer(source_notify),
+                notify = signal_pointer(notify),
+            ));
+        }
+    }
+
+    Ok(statements)
+}
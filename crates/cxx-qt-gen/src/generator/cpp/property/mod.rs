@@ -5,7 +5,9 @@
 
 use crate::generator::structuring::StructuredQObject;
 use crate::generator::{
-    cpp::{qobject::GeneratedCppQObjectBlocks, signal::generate_cpp_signals},
+    cpp::{
+        qobject::GeneratedCppQObjectBlocks, signal::generate_cpp_signals, utils::doxygen_comment,
+    },
     naming::{property::QPropertyNames, qobject::QObjectNames},
     GeneratedOpt,
 };
@@ -14,11 +16,15 @@ use crate::{
 };
 use syn::Result;
 
+mod dependencies;
 mod getter;
 mod meta;
+mod reset;
 mod setter;
 mod signal;
 
+pub use dependencies::generate as generate_cpp_property_dependencies;
+
 pub fn generate_cpp_properties(
     properties: &Vec<ParsedQProperty>,
     qobject_idents: &QObjectNames,
@@ -34,9 +40,11 @@ pub fn generate_cpp_properties(
         let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
         let cxx_ty = syn_type_to_cpp_type(&property.ty, type_names)?;
 
-        generated
-            .metaobjects
-            .push(meta::generate(&idents, &property.flags, &cxx_ty));
+        generated.metaobjects.push(format!(
+            "{doc_comment}{meta}",
+            doc_comment = doxygen_comment(&property.docs),
+            meta = meta::generate(&idents, &property.flags, &cxx_ty)
+        ));
 
         if let Some(getter) = getter::generate(&idents, &cxx_ty) {
             generated.methods.push(getter);
@@ -46,6 +54,10 @@ pub fn generate_cpp_properties(
             generated.methods.push(setter)
         }
 
+        if let Some(reset) = reset::generate(&idents) {
+            generated.methods.push(reset)
+        }
+
         if let Some(notify) = signal::generate(&idents, &qobject_idents.name) {
             signals.push(notify)
         }
@@ -53,6 +65,7 @@ pub fn generate_cpp_properties(
 
     generated.append(&mut generate_cpp_signals(
         &signals.iter().collect(),
+        &structured_qobject.signal_names(),
         qobject_idents,
         type_names,
         opt,
@@ -271,6 +284,24 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_reset_auto() {
+        let mut input: ItemStruct = parse_quote! {
+            #[qproperty(i32, num, READ, WRITE, RESET)]
+            struct MyStruct;
+        };
+        let generated = setup_generated(&mut input).unwrap();
+
+        assert_str_eq!(
+            generated.metaobjects[0],
+            "Q_PROPERTY(::std::int32_t num READ getNum WRITE setNum RESET resetNum)"
+        );
+
+        assert_eq!(generated.methods.len(), 3);
+        let header = require_header(&generated.methods[2]).unwrap();
+        assert_str_eq!(header, "Q_SLOT void resetNum() noexcept;");
+    }
+
     #[test]
     fn test_constant_and_required() {
         let mut input: ItemStruct = parse_quote! {
@@ -350,10 +381,16 @@ pub mod tests {
         );
 
         let header = require_header(&generated.methods[4]).unwrap();
-        assert_str_eq!(header, "Q_SIGNAL void trivialPropertyChanged();");
+        assert_str_eq!(
+            header,
+            "/// Notify for the Q_PROPERTY\nQ_SIGNAL void trivialPropertyChanged();"
+        );
 
         let header = require_header(&generated.methods[5]).unwrap();
-        assert_str_eq!(header, "Q_SIGNAL void opaquePropertyChanged();");
+        assert_str_eq!(
+            header,
+            "/// Notify for the Q_PROPERTY\nQ_SIGNAL void opaquePropertyChanged();"
+        );
 
         assert_eq!(generated.fragments.len(), 2);
         let (header, source) = require_pair(&generated.fragments[0]).unwrap();
@@ -480,6 +517,7 @@ pub mod tests {
             name: Name::mock_name_with_cxx("mapped_property", "mappedProperty"),
             ty: parse_quote! { A },
             flags: QPropertyFlags::default(),
+            docs: vec![],
         }];
         let qobject_idents = create_qobjectname();
 
@@ -514,7 +552,10 @@ pub mod tests {
         assert_str_eq!(header, "Q_SLOT void setMappedProperty(A1 value) noexcept;");
 
         let header = require_header(&generated.methods[2]).unwrap();
-        assert_str_eq!(header, "Q_SIGNAL void mappedPropertyChanged();");
+        assert_str_eq!(
+            header,
+            "/// Notify for the Q_PROPERTY\nQ_SIGNAL void mappedPropertyChanged();"
+        );
 
         assert_eq!(generated.fragments.len(), 1);
 
@@ -576,4 +617,151 @@ pub mod tests {
         // private methods
         assert_eq!(generated.private_methods.len(), 0);
     }
+
+    #[test]
+    fn test_generate_cpp_property_dependencies() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(QString, first)]
+                    #[qproperty(QString, last, READ, WRITE, NOTIFY = last_updated)]
+                    #[qproperty(QString, full_name, cxx_name = "fullName", READ = get_full_name, NOTIFY, depends_on(first, last))]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn last_updated(self: Pin<&mut MyObject>, value: i32);
+                }
+
+                unsafe extern "RustQt" {
+                    fn get_full_name(self: &MyObject) -> QString;
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let structured_qobject = structures.qobjects.first().unwrap();
+
+        let statements = generate_cpp_property_dependencies(
+            &structured_qobject.declaration.properties,
+            &create_qobjectname(),
+            structured_qobject,
+        )
+        .unwrap();
+
+        assert_eq!(statements.len(), 2);
+        assert_str_eq!(
+            statements[0],
+            "QObject::connect(this, &MyObject::firstChanged, this, &MyObject::fullNameChanged);"
+        );
+        assert_str_eq!(
+            statements[1],
+            "QObject::connect(this, &MyObject::last_updated, this, &MyObject::fullNameChanged);"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_property_dependencies_overloaded_notify() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, value)]
+                    #[qproperty(i32, doubled, READ, NOTIFY, depends_on(value))]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    #[cxx_name = "valueChanged"]
+                    fn value_changed_from(self: Pin<&mut MyObject>, previous: i32);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let structured_qobject = structures.qobjects.first().unwrap();
+
+        let statements = generate_cpp_property_dependencies(
+            &structured_qobject.declaration.properties,
+            &create_qobjectname(),
+            structured_qobject,
+        )
+        .unwrap();
+
+        assert_eq!(statements.len(), 1);
+        assert_str_eq!(
+            statements[0],
+            "QObject::connect(this, QOverload<>::of(&MyObject::valueChanged), this, &MyObject::doubledChanged);"
+        );
+    }
+
+    #[test]
+    fn test_generate_cpp_property_dependencies_invalid() {
+        let assert_invalid = |module: ItemMod| {
+            let parser = Parser::from(module).unwrap();
+            let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+            let structured_qobject = structures.qobjects.first().unwrap();
+
+            assert!(generate_cpp_property_dependencies(
+                &structured_qobject.declaration.properties,
+                &create_qobjectname(),
+                structured_qobject,
+            )
+            .is_err());
+        };
+
+        // Unknown property
+        assert_invalid(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, derived, READ, NOTIFY, depends_on(unknown))]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        });
+
+        // Dependency without a notify signal
+        assert_invalid(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, source, READ)]
+                    #[qproperty(i32, derived, READ, NOTIFY, depends_on(source))]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        });
+
+        // Depends on itself
+        assert_invalid(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, derived, READ, NOTIFY, depends_on(derived))]
+                    type MyObject = super::MyObjectRust;
+                }
+            }
+        });
+
+        // Notify signal with parameters
+        assert_invalid(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, source)]
+                    #[qproperty(i32, derived, READ, NOTIFY = derived_updated, depends_on(source))]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn derived_updated(self: Pin<&mut MyObject>, value: i32);
+                }
+            }
+        });
+    }
 }
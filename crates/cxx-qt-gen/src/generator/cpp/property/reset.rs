@@ -0,0 +1,20 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    cpp::fragment::CppFragment,
+    naming::property::{NameState, QPropertyNames},
+};
+
+pub fn generate(idents: &QPropertyNames) -> Option<CppFragment> {
+    // Only generates reset code if the state provided is Auto (not custom provided by user)
+    if let Some(NameState::Auto(reset)) = &idents.reset {
+        Some(CppFragment::Header(format!(
+            "Q_SLOT void {ident_reset}() noexcept;",
+            ident_reset = reset.cxx_unqualified(),
+        )))
+    } else {
+        None
+    }
+}
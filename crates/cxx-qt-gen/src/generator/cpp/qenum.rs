@@ -131,6 +131,7 @@ mod tests {
         .unwrap()];
         let opt = GeneratedOpt {
             cfg_evaluator: Box::new(CfgEvaluatorTest::default()),
+            ..Default::default()
         };
         let generated = generate_on_qobject(qenums.iter(), &Name::mock("MyObject"), &opt).unwrap();
 
@@ -0,0 +1,165 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        cpp::{
+            externcxxqt::GeneratedCppExternCxxQtBlocks, fragment::CppFragment,
+            method::generate_cpp_methods, signal::generate_cpp_signal,
+        },
+        naming::signals::is_overloaded,
+        GeneratedOpt,
+    },
+    naming::TypeNames,
+    parser::qinterface::ParsedQInterface,
+    writer::cpp::{namespaced, pair_as_header},
+};
+use indoc::formatdoc;
+use syn::Result;
+
+/// Generate the abstract C++ class for a #[qinterface] trait
+///
+/// The class is defined after the CXX header is included, so that the methods can use any type
+/// from the bridge, but before the QObjects which derive from it.
+pub fn generate(
+    interface: &ParsedQInterface,
+    type_names: &TypeNames,
+    opt: &GeneratedOpt,
+) -> Result<GeneratedCppExternCxxQtBlocks> {
+    let mut generated = GeneratedCppExternCxxQtBlocks::default();
+    generated
+        .includes
+        .insert("#include <cxx-qt/casting.h>".to_owned());
+
+    let ident = interface.name.cxx_unqualified();
+    let namespace = interface.name.namespace().unwrap_or_default();
+    generated
+        .forward_declares
+        .push(namespaced(namespace, &format!("class {ident};")));
+
+    let mut methods =
+        generate_cpp_methods(&interface.methods.iter().collect(), false, type_names, opt)?.methods;
+
+    let mut signal_fragments = vec![];
+    for signal in &interface.signals {
+        let overloaded = is_overloaded(
+            &signal.name,
+            interface.signals.iter().map(|signal| &signal.name),
+        );
+        let mut data = generate_cpp_signal(signal, overloaded, &interface.name, type_names, opt)?;
+        generated.includes.append(&mut data.includes);
+        generated
+            .forward_declares
+            .append(&mut data.forward_declares);
+        signal_fragments.append(&mut data.fragments);
+        methods.append(&mut data.methods);
+    }
+
+    let class_definition = namespaced(
+        namespace,
+        &formatdoc! { r#"
+            class {ident} : public QObject
+            {{
+              Q_OBJECT
+            public:
+              explicit {ident}(QObject* parent = nullptr)
+                : QObject(parent)
+              {{
+              }}
+              virtual ~{ident}() = default;
+
+              {methods}
+            }};
+            "#,
+            methods = methods
+                .iter()
+                .filter_map(pair_as_header)
+                .collect::<Vec<String>>()
+                .join("\n  "),
+        },
+    );
+    generated.fragments.push(CppFragment::Header(formatdoc! {
+        r#"
+        {class_definition}
+        Q_DECLARE_METATYPE({qualified}*)
+        "#,
+        qualified = interface.name.cxx_qualified(),
+    }));
+    generated.fragments.append(&mut signal_fragments);
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::cpp::property::tests::require_header;
+    use crate::parser::Parser;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::{parse_quote, ItemMod};
+
+    #[test]
+    fn test_generate_cpp_qinterface() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt")]
+            mod ffi {
+                #[qinterface]
+                trait Device {
+                    #[qinvokable]
+                    #[cxx_name = "connectTo"]
+                    fn connect_to(self: Pin<&mut Self>, port: i32) -> bool;
+
+                    fn name(&self) -> i32;
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut Self>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let interface = &parser.cxx_qt_data.qinterfaces[0];
+
+        let generated = generate(interface, &parser.type_names, &GeneratedOpt::default()).unwrap();
+
+        assert!(generated.includes.contains("#include <cxx-qt/casting.h>"));
+        assert_eq!(generated.forward_declares.len(), 2);
+        assert_str_eq!(
+            generated.forward_declares[0],
+            indoc! {"
+                namespace cxx_qt {
+                class Device;
+                } // namespace cxx_qt
+            "}
+        );
+
+        // The class definition followed by the signal connection helper
+        assert_eq!(generated.fragments.len(), 2);
+        assert_str_eq!(
+            require_header(&generated.fragments[0]).unwrap(),
+            indoc! {"
+                namespace cxx_qt {
+                class Device : public QObject
+                {
+                  Q_OBJECT
+                public:
+                  explicit Device(QObject* parent = nullptr)
+                    : QObject(parent)
+                  {
+                  }
+                  virtual ~Device() = default;
+
+                  Q_INVOKABLE virtual bool connectTo(::std::int32_t port) noexcept = 0;
+                  virtual ::std::int32_t name() const noexcept = 0;
+                  Q_SIGNAL void ready();
+                };
+
+                } // namespace cxx_qt
+
+                Q_DECLARE_METATYPE(cxx_qt::Device*)
+            "}
+        );
+    }
+}
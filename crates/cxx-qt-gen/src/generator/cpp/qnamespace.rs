@@ -5,19 +5,256 @@
 
 use std::collections::BTreeSet;
 
+use convert_case::{Case, Casing};
 use indoc::formatdoc;
+use syn::Result;
 
-use crate::{parser::qnamespace::ParsedQNamespace, writer::cpp::namespaced};
+use crate::{
+    generator::{
+        cfg::try_eval_attributes,
+        cpp::{externcxxqt::GeneratedCppExternCxxQtBlocks, get_cpp_params, utils::Indent},
+        structuring::StructuredQNamespace,
+        GeneratedOpt,
+    },
+    naming::{
+        cpp::{syn_return_type_to_cpp_except, syn_type_to_cpp_return_type},
+        TypeNames,
+    },
+    parser::function::ParsedFunction,
+    writer::cpp::namespaced,
+    CppFragment,
+};
+
+/// The free functions of the namespace, which are enabled by their cfg attributes
+fn enabled_functions<'a>(
+    qnamespace: &StructuredQNamespace<'a>,
+    opt: &GeneratedOpt,
+) -> Result<Vec<&'a ParsedFunction>> {
+    let mut functions = vec![];
+    for function in &qnamespace.functions {
+        if try_eval_attributes(opt.cfg_evaluator.as_ref(), &function.cfgs)? {
+            functions.push(*function);
+        }
+    }
+    Ok(functions)
+}
 
 /// Generate the declaration of the namespace, including the Q_NAMESPACE macro.
-pub fn generate(qnamespace: &ParsedQNamespace, includes: &mut BTreeSet<String>) -> String {
+pub fn generate(
+    qnamespace: &StructuredQNamespace,
+    includes: &mut BTreeSet<String>,
+    opt: &GeneratedOpt,
+) -> Result<String> {
     includes.insert("#include <QtCore/QObject>".to_owned());
     let mut result = "Q_NAMESPACE".to_owned();
-    if qnamespace.qml_element {
+    // If there are any free functions, the QML element is the singleton which exposes them
+    if qnamespace.declaration.qml_element && enabled_functions(qnamespace, opt)?.is_empty() {
         includes.insert("#include <QtQml/QQmlEngine>".to_owned());
         result = formatdoc! { r#"
             {result}
             QML_ELEMENT"#};
     }
-    namespaced(&qnamespace.namespace, &result)
+    Ok(namespaced(&qnamespace.declaration.namespace, &result))
+}
+
+/// The name of the singleton class, which is unique for each generated file,
+/// as several bridges can add free functions to the same namespace
+fn qml_singleton_class_name(file_ident: &str) -> String {
+    let ident = file_ident
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    format!("QmlFunctions{}", ident.to_case(Case::Pascal))
+}
+
+/// Generate a QML singleton for a `#[qml_element]` namespace with free functions.
+///
+/// Functions in a namespace cannot be called from QML, so the singleton exposes each of them as a
+/// static Q_INVOKABLE. The enums of the namespace remain available as it extends the namespace.
+pub fn generate_qml_singleton(
+    qnamespace: &StructuredQNamespace,
+    type_names: &TypeNames,
+    opt: &GeneratedOpt,
+) -> Result<Option<GeneratedCppExternCxxQtBlocks>> {
+    let functions = enabled_functions(qnamespace, opt)?;
+    if !qnamespace.declaration.qml_element || functions.is_empty() {
+        return Ok(None);
+    }
+
+    let mut generated = GeneratedCppExternCxxQtBlocks::default();
+    generated
+        .includes
+        .insert("#include <QtQml/QQmlEngine>".to_owned());
+
+    let namespace = &qnamespace.declaration.namespace;
+    let qml_name = namespace.rsplit("::").next().unwrap_or(namespace);
+    let class_name = qml_singleton_class_name(&opt.file_ident);
+
+    let invokables = functions
+        .into_iter()
+        .map(|function| {
+            let return_cxx_ty =
+                syn_type_to_cpp_return_type(&function.method.sig.output, type_names)?
+                    .unwrap_or_else(|| "void".to_owned());
+            let parameters = get_cpp_params(&function.method, type_names)?;
+            let parameter_types = parameters
+                .iter()
+                .map(|parameter| {
+                    format!("{ty} {ident}", ident = parameter.ident, ty = parameter.ty)
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let parameter_idents = parameters
+                .iter()
+                .map(|parameter| parameter.ident.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let has_noexcept = syn_return_type_to_cpp_except(&function.method.sig.output);
+            let ident = function.name.cxx_unqualified();
+
+            // The definition of the free function is generated by CXX
+            Ok(formatdoc! { r#"
+                Q_INVOKABLE static {return_cxx_ty} {ident}({parameter_types}) {has_noexcept}
+                {{
+                  return ::{namespace}::{ident}({parameter_idents});
+                }}"# })
+        })
+        .collect::<Result<Vec<_>>>()?
+        .join("\n\n");
+
+    generated.fragments.push(CppFragment::Header(namespaced(
+        namespace,
+        &formatdoc! { r#"
+            class {class_name} : public QObject
+            {{
+              Q_OBJECT
+              QML_NAMED_ELEMENT({qml_name})
+              QML_SINGLETON
+              QML_EXTENDED_NAMESPACE({namespace})
+
+            public:
+              explicit {class_name}(QObject* parent = nullptr)
+                : QObject(parent)
+              {{
+              }}
+
+            {invokables}
+            }};
+            "#,
+            invokables = invokables.indented(2),
+        },
+    )));
+
+    Ok(Some(generated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::structuring::Structures;
+    use crate::tests::CfgEvaluatorTest;
+    use crate::Parser;
+    use indoc::indoc;
+    use pretty_assertions::assert_str_eq;
+    use syn::parse_quote;
+
+    fn parse_bridge(qml_element: bool) -> Parser {
+        let qml_element = qml_element.then(|| quote::quote! { #[qml_element] });
+        Parser::from(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                #qml_element
+                qnamespace!("cxx_qt::utils");
+
+                #[namespace = "cxx_qt::utils"]
+                extern "RustQt" {
+                    #[qinvokable]
+                    #[cxx_name = "clampValue"]
+                    fn clamp_value(value: i32, min: i32, max: i32) -> i32;
+
+                    #[qinvokable]
+                    #[cfg(test_cfg_disabled)]
+                    fn disabled();
+                }
+            }
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_generate_qml_singleton() {
+        let parser = parse_bridge(true);
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let opt = GeneratedOpt {
+            cfg_evaluator: Box::new(CfgEvaluatorTest::default()),
+            file_ident: "src/utils".to_owned(),
+        };
+
+        let mut includes = BTreeSet::new();
+        let declaration = generate(&structures.qnamespaces[0], &mut includes, &opt).unwrap();
+        assert_str_eq!(
+            declaration,
+            indoc! {r#"
+                namespace cxx_qt::utils {
+                Q_NAMESPACE
+                } // namespace cxx_qt::utils
+            "#}
+        );
+
+        let generated =
+            generate_qml_singleton(&structures.qnamespaces[0], &parser.type_names, &opt)
+                .unwrap()
+                .unwrap();
+        assert!(generated.includes.contains("#include <QtQml/QQmlEngine>"));
+        assert_eq!(generated.fragments.len(), 1);
+        let CppFragment::Header(header) = &generated.fragments[0] else {
+            panic!("Expected a header fragment");
+        };
+        assert_str_eq!(
+            header,
+            indoc! {r#"
+                namespace cxx_qt::utils {
+                class QmlFunctionsSrcUtils : public QObject
+                {
+                  Q_OBJECT
+                  QML_NAMED_ELEMENT(utils)
+                  QML_SINGLETON
+                  QML_EXTENDED_NAMESPACE(cxx_qt::utils)
+
+                public:
+                  explicit QmlFunctionsSrcUtils(QObject* parent = nullptr)
+                    : QObject(parent)
+                  {
+                  }
+
+                  Q_INVOKABLE static ::std::int32_t clampValue(::std::int32_t value, ::std::int32_t min, ::std::int32_t max) noexcept
+                  {
+                    return ::cxx_qt::utils::clampValue(value, min, max);
+                  }
+                };
+
+                } // namespace cxx_qt::utils
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_generate_without_qml_element() {
+        let parser = parse_bridge(false);
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let opt = GeneratedOpt {
+            cfg_evaluator: Box::new(CfgEvaluatorTest::default()),
+            file_ident: "src/utils".to_owned(),
+        };
+
+        let mut includes = BTreeSet::new();
+        generate(&structures.qnamespaces[0], &mut includes, &opt).unwrap();
+        assert!(!includes.contains("#include <QtQml/QQmlEngine>"));
+        assert!(
+            generate_qml_singleton(&structures.qnamespaces[0], &parser.type_names, &opt)
+                .unwrap()
+                .is_none()
+        );
+    }
 }
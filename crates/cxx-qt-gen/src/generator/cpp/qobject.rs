@@ -6,8 +6,15 @@
 use crate::{
     generator::{
         cpp::{
-            constructor, cxxqttype, fragment::CppFragment, inherit, method::generate_cpp_methods,
-            property::generate_cpp_properties, qenum, signal::generate_cpp_signals, threading,
+            constructor, cxxqttype, finalize,
+            fragment::CppFragment,
+            inherit,
+            method::generate_cpp_methods,
+            property::{generate_cpp_properties, generate_cpp_property_dependencies},
+            qenum,
+            signal::generate_cpp_signals,
+            threading,
+            utils::doxygen_comment,
         },
         naming::{namespace::NamespaceName, qobject::QObjectNames},
         structuring::StructuredQObject,
@@ -44,6 +51,8 @@ pub struct GeneratedCppQObjectBlocks {
     pub methods: Vec<CppFragment>,
     /// List of private methods for the QObject
     pub private_methods: Vec<CppFragment>,
+    /// Whether the destructor is defined in the methods, otherwise a default destructor is declared
+    pub has_destructor: bool,
 }
 
 impl GeneratedCppQObjectBlocks {
@@ -58,6 +67,7 @@ impl GeneratedCppQObjectBlocks {
         self.metaobjects.append(&mut other.metaobjects);
         self.methods.append(&mut other.methods);
         self.private_methods.append(&mut other.private_methods);
+        self.has_destructor |= other.has_destructor;
     }
 
     pub fn from(qobject: &ParsedQObject) -> GeneratedCppQObjectBlocks {
@@ -95,6 +105,8 @@ pub struct GeneratedCppQObject {
     pub blocks: GeneratedCppQObjectBlocks,
     /// Whether this type has a #[qobject] / Q_OBJECT macro
     pub has_qobject_macro: bool,
+    /// Doxygen comment for the class, from the doc comments of the QObject
+    pub doc_comment: String,
 }
 
 impl GeneratedCppQObject {
@@ -114,9 +126,10 @@ impl GeneratedCppQObject {
             namespace_internals: namespace_idents.internal,
             blocks: GeneratedCppQObjectBlocks::from(qobject),
             has_qobject_macro: qobject.has_qobject_macro,
+            doc_comment: doxygen_comment(&qobject.docs),
         };
 
-        let base_class = if let Some(ident) = &qobject.base_class {
+        let base_class = if let Some(ident) = structured_qobject.base_class() {
             type_names.lookup(ident)?.cxx_qualified()
         } else if qobject.has_qobject_macro {
             "QObject".to_owned()
@@ -146,11 +159,24 @@ impl GeneratedCppQObject {
         )?);
         generated.blocks.append(&mut generate_cpp_methods(
             &structured_qobject.methods,
+            qobject.qml_metadata.is_some(),
             type_names,
             opt,
         )?);
+        // Override the pure virtual methods of the interface this type implements
+        if let Some(interface) = structured_qobject.interface {
+            let implementation_methods =
+                interface.implementation_methods(qobject.name.rust_unqualified());
+            generated.blocks.append(&mut generate_cpp_methods(
+                &implementation_methods.iter().collect(),
+                false,
+                type_names,
+                opt,
+            )?);
+        }
         generated.blocks.append(&mut generate_cpp_signals(
             &structured_qobject.signals,
+            &structured_qobject.signal_names(),
             &qobject_idents,
             type_names,
             opt,
@@ -179,6 +205,21 @@ impl GeneratedCppQObject {
             class_initializers.push(initializer);
         }
 
+        // If this type has a finalize hook then call it from the destructor
+        if structured_qobject.finalize {
+            generated.blocks.append(&mut finalize::generate(
+                &generated.name.cxx_unqualified(),
+                &generated.namespace_internals,
+            ));
+        }
+
+        // Connect the notify signals of properties which depend on each other
+        let class_statements = generate_cpp_property_dependencies(
+            &qobject.properties,
+            &qobject_idents,
+            structured_qobject,
+        )?;
+
         // Include casting header
         let mut result = GeneratedCppQObjectBlocks::default();
         result.includes.insert("#include <cxx-qt/casting.h>".into());
@@ -190,6 +231,7 @@ impl GeneratedCppQObject {
             &structured_qobject.constructors,
             base_class,
             &class_initializers,
+            &class_statements,
             type_names,
         )?);
 
@@ -6,10 +6,10 @@
 use crate::{
     generator::{
         cfg::try_eval_attributes,
-        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks},
+        cpp::{fragment::CppFragment, qobject::GeneratedCppQObjectBlocks, utils::doxygen_comment},
         naming::{
             qobject::QObjectNames,
-            signals::{QSignalHelperNames, QSignalNames},
+            signals::{is_overloaded, QSignalHelperNames, QSignalNames},
         },
         GeneratedOpt,
     },
@@ -40,6 +40,8 @@ struct Parameters {
     named_types: String,
     /// name with type of parameters including self
     named_types_with_self: String,
+    /// Raw types of the parameters
+    types: String,
     /// Raw types of the parameters including self
     types_with_self: String,
     /// Raw ::std::move values of the parameters including self
@@ -66,6 +68,7 @@ fn parameter_types_and_values(
     }
 
     let parameter_named_types = parameter_named_types_with_self.join(", ");
+    let parameter_types = parameter_types_with_self.join(", ");
 
     let is_const = if mutable { "" } else { " const" };
     // Insert the extra argument into the closure
@@ -77,6 +80,7 @@ fn parameter_types_and_values(
     Ok(Parameters {
         named_types: parameter_named_types,
         named_types_with_self: parameter_named_types_with_self.join(", "),
+        types: parameter_types,
         types_with_self: parameter_types_with_self.join(", "),
         values_with_self: parameter_values_with_self.join(", "),
     })
@@ -84,6 +88,7 @@ fn parameter_types_and_values(
 
 pub fn generate_cpp_signal(
     signal: &ParsedSignal,
+    overloaded: bool,
     qobject_name: &Name,
     type_names: &TypeNames,
     opt: &GeneratedOpt,
@@ -104,7 +109,7 @@ pub fn generate_cpp_signal(
     let qobject_ident_namespaced = qobject_name.cxx_qualified();
 
     // Prepare the idents
-    let idents = QSignalNames::from(signal);
+    let idents = QSignalNames::new(signal, overloaded);
     let idents_helper = QSignalHelperNames::new(&idents, qobject_name)?;
 
     let signal_ident = idents.name.cxx_unqualified();
@@ -118,6 +123,14 @@ pub fn generate_cpp_signal(
     let parameter_types_with_self = parameters.types_with_self;
     let parameter_values_with_self = parameters.values_with_self;
 
+    // An overloaded signal needs to be selected by its parameter types
+    let signal_pointer = if overloaded {
+        let parameter_types = parameters.types;
+        format!("QOverload<{parameter_types}>::of(&{qobject_ident_namespaced}::{signal_ident})")
+    } else {
+        format!("&{qobject_ident_namespaced}::{signal_ident}")
+    };
+
     let param_struct = idents_helper.struct_param;
     let signal_handler_alias = idents_helper.handler_alias;
     let signal_handler_alias_namespaced = idents_helper.handler_alias_namespaced;
@@ -140,7 +153,8 @@ pub fn generate_cpp_signal(
     // Generate the Q_SIGNAL if this is not an existing signal
     if !signal.inherit {
         generated.methods.push(CppFragment::Header(format!(
-            "Q_SIGNAL void {signal_ident}({parameters_named_types}){is_const};"
+            "{doc_comment}Q_SIGNAL void {signal_ident}({parameters_named_types}){is_const};",
+            doc_comment = doxygen_comment(&signal.docs)
         )));
     }
 
@@ -186,7 +200,7 @@ pub fn generate_cpp_signal(
             {{
                 return ::QObject::connect(
                     &self,
-                    &{qobject_ident_namespaced}::{signal_ident},
+                    {signal_pointer},
                     &self,
                     [&, closure = ::std::move(closure)]({parameters_named_types}) mutable {{
                         closure.template operator()<{parameter_types_with_self}>({parameter_values_with_self});
@@ -201,8 +215,11 @@ pub fn generate_cpp_signal(
     Ok(generated)
 }
 
+/// Generate the given signals of a QObject, `signal_names` are the names of all of its signals
+/// including the NOTIFY signals of properties, so that overloads can be detected
 pub fn generate_cpp_signals(
     signals: &Vec<&ParsedSignal>,
+    signal_names: &[&Name],
     qobject_idents: &QObjectNames,
     type_names: &TypeNames,
     opt: &GeneratedOpt,
@@ -210,7 +227,8 @@ pub fn generate_cpp_signals(
     let mut generated = GeneratedCppQObjectBlocks::default();
 
     for &signal in signals {
-        let data = generate_cpp_signal(signal, &qobject_idents.name, type_names, opt)?;
+        let overloaded = is_overloaded(&signal.name, signal_names.iter().copied());
+        let data = generate_cpp_signal(signal, overloaded, &qobject_idents.name, type_names, opt)?;
         let mut block = GeneratedCppQObjectBlocks {
             includes: data.includes,
             forward_declares_namespaced: data.forward_declares,
@@ -248,8 +266,10 @@ mod tests {
         let type_names = TypeNames::mock();
         let opt = GeneratedOpt {
             cfg_evaluator: Box::new(CfgEvaluatorTest::default()),
+            ..Default::default()
         };
-        let generated = generate_cpp_signals(&signals, &qobject_idents, &type_names, &opt).unwrap();
+        let generated =
+            generate_cpp_signals(&signals, &[], &qobject_idents, &type_names, &opt).unwrap();
 
         assert_eq!(generated.methods.len(), 0);
     }
@@ -268,6 +288,7 @@ mod tests {
         type_names.mock_insert("QColor", None, None, None);
         let generated = generate_cpp_signals(
             &signals,
+            &[],
             &qobject_idents,
             &type_names,
             &GeneratedOpt::default(),
@@ -355,6 +376,7 @@ mod tests {
 
         let generated = generate_cpp_signals(
             &signals,
+            &[],
             &qobject_idents,
             &type_names,
             &GeneratedOpt::default(),
@@ -438,6 +460,7 @@ mod tests {
         let qobject_idents = create_qobjectname();
         let generated = generate_cpp_signals(
             &signals,
+            &[],
             &qobject_idents,
             &TypeNames::mock(),
             &GeneratedOpt::default(),
@@ -517,9 +540,14 @@ mod tests {
         let mut type_names = TypeNames::default();
         type_names.mock_insert("MyObject", None, None, None);
         let qobject_name = type_names.lookup(&signal.qobject_ident).unwrap();
-        let generated =
-            generate_cpp_signal(&signal, qobject_name, &type_names, &GeneratedOpt::default())
-                .unwrap();
+        let generated = generate_cpp_signal(
+            &signal,
+            false,
+            qobject_name,
+            &type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
 
         assert_eq!(generated.methods.len(), 0);
 
@@ -596,9 +624,14 @@ mod tests {
         let mut type_names = TypeNames::default();
         type_names.mock_insert("MyObject", None, Some("ObjCpp"), Some("mynamespace"));
         let qobject_name = type_names.lookup(&signal.qobject_ident).unwrap();
-        let generated =
-            generate_cpp_signal(&signal, qobject_name, &type_names, &GeneratedOpt::default())
-                .unwrap();
+        let generated = generate_cpp_signal(
+            &signal,
+            false,
+            qobject_name,
+            &type_names,
+            &GeneratedOpt::default(),
+        )
+        .unwrap();
 
         assert_eq!(generated.methods.len(), 0);
 
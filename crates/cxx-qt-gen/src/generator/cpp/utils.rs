@@ -3,6 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::parser::doc_lines;
+use syn::Attribute;
+
 /// A trait to allow indenting multi-line string
 /// This is specifically useful when using formatdoc! with a multi-line string argument.
 /// As the formatdoc! formatting doesn't support indenting multi-line arguments, we can indent
@@ -20,6 +23,24 @@ impl Indent for str {
     }
 }
 
+/// Convert the given doc comments into a Doxygen comment to be placed before a C++ declaration
+///
+/// This is empty if there are no doc comments, otherwise each line ends with a newline.
+/// Trailing backslashes are removed, as they would continue the comment onto the next line of C++.
+pub(crate) fn doxygen_comment(docs: &[Attribute]) -> String {
+    doc_lines(docs)
+        .into_iter()
+        .map(|line| {
+            let line = line.trim_end_matches(|c: char| c == '\\' || c.is_whitespace());
+            if line.is_empty() {
+                "///\n".to_owned()
+            } else {
+                format!("/// {line}\n")
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,4 +69,33 @@ mod tests {
         "#}
         );
     }
+
+    #[test]
+    fn doxygen_comment_from_docs() {
+        let method: syn::ForeignItemFn = syn::parse_quote! {
+            /// A method
+            ///
+            /// With details
+            fn method(&self);
+        };
+        assert_str_eq!(
+            doxygen_comment(&method.attrs),
+            "/// A method\n///\n/// With details\n"
+        );
+        assert!(doxygen_comment(&[]).is_empty());
+    }
+
+    #[test]
+    fn doxygen_comment_trailing_backslash() {
+        let method: syn::ForeignItemFn = syn::parse_quote! {
+            /// A path like C:\
+            /// A line continuation \\ \
+            /// \
+            fn method(&self);
+        };
+        assert_str_eq!(
+            doxygen_comment(&method.attrs),
+            "/// A path like C:\n/// A line continuation\n///\n"
+        );
+    }
 }
@@ -18,12 +18,16 @@ pub mod structuring;
 pub struct GeneratedOpt {
     /// Impl for handling conditional compilation attributes.
     pub cfg_evaluator: Box<dyn CfgEvaluator>,
+    /// The identifier of the generated files, eg `directory/file_ident`.
+    /// This is used to give generated helper classes a unique name.
+    pub file_ident: String,
 }
 
 impl Default for GeneratedOpt {
     fn default() -> Self {
         Self {
             cfg_evaluator: Box::new(UnsupportedCfgEvaluator),
+            file_ident: String::new(),
         }
     }
 }
@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use quote::format_ident;
+use syn::Ident;
+
+/// For a method with default values, generate the Rust ident of the overload which takes
+/// the given number of parameters and fills in the remaining default values
+pub fn default_overload_ident(ident: &Ident, parameter_count: usize) -> Ident {
+    format_ident!("{ident}_cxx_qt_default_{parameter_count}")
+}
+
+/// For a method which throws its errors to QML, generate the C++ name of the method
+/// which is implemented by Rust and throws a `rust::Error` instead
+pub fn throwing_cxx_name(cxx_name: &str) -> String {
+    format!("{cxx_name}OrThrow")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_overload_ident() {
+        assert_eq!(
+            default_overload_ident(&format_ident!("greet"), 1),
+            "greet_cxx_qt_default_1"
+        );
+    }
+
+    #[test]
+    fn test_throwing_cxx_name() {
+        assert_eq!(throwing_cxx_name("parseValue"), "parseValueOrThrow");
+    }
+}
@@ -54,7 +54,7 @@ pub struct QPropertyNames {
     pub getter: NameState,
     pub setter: Option<NameState>,
     pub notify: Option<NameState>,
-    pub reset: Option<Name>,
+    pub reset: Option<NameState>,
 }
 
 impl QPropertyNames {
@@ -102,8 +102,15 @@ impl QPropertyNames {
 
         let reset = flags
             .reset
-            .as_ref()
-            .map(|ident| structured_qobject.method_lookup(ident))
+            .clone()
+            .map(|reset| {
+                NameState::from_flag_with_auto_fn(
+                    &reset,
+                    || reset_name_from_property(property_name),
+                    structured_qobject,
+                    false,
+                )
+            })
             .transpose()?;
 
         Ok(Self {
@@ -138,6 +145,13 @@ pub fn setter_name_from_property(name: &Name) -> Name {
         .with_cxx_name(format!("set{}", capitalise_first(name.cxx_unqualified())))
 }
 
+/// For a given property name generate the reset name
+pub fn reset_name_from_property(name: &Name) -> Name {
+    name.clone()
+        .with_rust_name(format_ident!("reset_{}", name.rust_unqualified()))
+        .with_cxx_name(format!("reset{}", capitalise_first(name.cxx_unqualified())))
+}
+
 /// For a given property name generate the notify signal name
 pub fn notify_name_from_property(name: &Name) -> Name {
     name.clone()
@@ -158,6 +172,7 @@ pub mod tests {
             name: Name::mock_name_with_cxx("my_property", "myProperty"),
             ty: parse_quote! { i32 },
             flags: QPropertyFlags::default(),
+            docs: vec![],
         };
 
         let obj = ParsedQObject::mock();
@@ -11,18 +11,35 @@ pub struct QSignalNames {
     pub name: Name,
     pub connect_name: Name,
     pub on_name: Ident,
+    /// Whether another signal of the QObject shares the C++ name of this signal
+    pub overloaded: bool,
 }
 
 impl From<&ParsedSignal> for QSignalNames {
     fn from(signal: &ParsedSignal) -> Self {
+        Self::new(signal, false)
+    }
+}
+
+impl QSignalNames {
+    pub fn new(signal: &ParsedSignal, overloaded: bool) -> Self {
         Self {
             name: signal.name.clone(),
             connect_name: connect_name_from_signal(&signal.name),
             on_name: on_from_signal(signal.name.rust_unqualified()),
+            overloaded,
         }
     }
 }
 
+/// Whether another of the given signal names of the same QObject shares the C++ name of the signal
+pub fn is_overloaded<'a>(name: &Name, names: impl IntoIterator<Item = &'a Name>) -> bool {
+    names.into_iter().any(|other| {
+        other.cxx_unqualified() == name.cxx_unqualified()
+            && other.rust_unqualified() != name.rust_unqualified()
+    })
+}
+
 fn connect_name_from_signal(name: &Name) -> Name {
     name.clone()
         .with_rust_name(format_ident!("connect_{}", name.rust_unqualified()))
@@ -48,7 +65,12 @@ pub struct QSignalHelperNames {
 
 impl QSignalHelperNames {
     pub fn new(idents: &QSignalNames, qobject_name: &Name) -> Result<Self> {
-        let signal_ident = &idents.name.cxx_unqualified();
+        // Overloaded signals share their C++ name, so the helpers are named after the Rust name
+        let signal_ident = if idents.overloaded {
+            idents.name.rust_unqualified().to_string()
+        } else {
+            idents.name.cxx_unqualified().to_owned()
+        };
         let qobject_ident = qobject_name.rust_unqualified().to_string();
         let handler_alias = format_ident!("{qobject_ident}CxxQtSignalHandler{signal_ident}");
         let namespace = {
@@ -147,4 +169,35 @@ mod tests {
         );
         assert_eq!(names.on_name, format_ident!("on_existing_signal"));
     }
+
+    #[test]
+    fn test_overloaded_signal() {
+        let signals = [
+            ParsedSignal::mock(&parse_quote! {
+                #[cxx_name = "valueChanged"]
+                fn value_changed_int(self: Pin<&mut MyObject>, value: i32);
+            }),
+            ParsedSignal::mock(&parse_quote! {
+                #[cxx_name = "valueChanged"]
+                fn value_changed_string(self: Pin<&mut MyObject>, value: &QString);
+            }),
+        ];
+        let names = signals.iter().map(|signal| &signal.name);
+
+        assert!(is_overloaded(&signals[0].name, names));
+        let names = QSignalNames::new(&signals[0], true);
+        let helpers =
+            QSignalHelperNames::new(&names, &Name::new(format_ident!("MyObject"))).unwrap();
+        assert_eq!(
+            helpers.struct_param,
+            format_ident!("MyObjectCxxQtSignalParamsvalue_changed_int")
+        );
+        assert_eq!(
+            helpers.connect_name.cxx_unqualified(),
+            "MyObject_valueChangedConnect"
+        );
+
+        // A signal does not overload itself
+        assert!(!is_overloaded(&signals[0].name, [&signals[0].name]));
+    }
 }
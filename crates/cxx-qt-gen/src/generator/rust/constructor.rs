@@ -61,6 +61,65 @@ fn argument_members(args: &[Type]) -> Vec<TokenStream> {
     })
 }
 
+/// Generate the implementation of `cxx_qt::Instantiate` for the given constructor arguments,
+/// which calls the C++ constructor from Rust
+fn generate_instantiate(
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+    index: usize,
+    arguments: &[Type],
+    lifetime: &Option<Lifetime>,
+    cfgs: &[Attribute],
+) -> Result<GeneratedRustFragment> {
+    let qobject_name_rust = qobject_names.name.rust_unqualified();
+    let qobject_name_rust_qualified = type_names.rust_qualified(qobject_name_rust)?;
+
+    let (new_fn, new_fn_attrs, new_fn_qualified) = qobject_names
+        .cxx_qt_ffi_method(&format!("new{index}"))
+        .with_cxx_name("qobjectNew".to_owned())
+        .into_cxx_parts();
+
+    let arguments_lifetime = lifetime_of_arguments(lifetime, arguments)?;
+    let argument_names: Vec<Ident> = (0..arguments.len())
+        .map(|index| format_ident!("arg{index}"))
+        .collect();
+    let argument_types_qualified: Vec<Type> = arguments
+        .iter()
+        .map(|arg| syn_type_cxx_bridge_to_qualified(arg, type_names))
+        .collect::<Result<_>>()?;
+
+    // Pointers are passed to the C++ constructor as-is, which requires the function to be unsafe
+    let is_unsafe = arguments.iter().any(syn_type_is_cxx_bridge_unsafe);
+    let new_safety = unsafe_if(is_unsafe);
+    let new_call = quote! { #new_fn_qualified(#(#argument_names),*) };
+    let new_call = if is_unsafe {
+        quote! { unsafe { #new_call } }
+    } else {
+        new_call
+    };
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: vec![parse_quote! {
+            #(#cfgs)*
+            unsafe extern "C++" {
+                include!(<cxx-qt/instantiate.h>);
+
+                #[doc(hidden)]
+                #(#new_fn_attrs)*
+                #new_safety fn #new_fn #arguments_lifetime (#(#argument_names: #arguments),*) -> UniquePtr<#qobject_name_rust>;
+            }
+        }],
+        cxx_qt_mod_contents: vec![parse_quote! {
+            #(#cfgs)*
+            impl #arguments_lifetime cxx_qt::Instantiate<(#(#argument_types_qualified,)*)> for #qobject_name_rust_qualified {
+                fn new_unique((#(#argument_names,)*): (#(#argument_types_qualified,)*)) -> cxx::UniquePtr<Self> {
+                    #new_call
+                }
+            }
+        }],
+    })
+}
+
 fn generate_default_constructor(
     qobject_idents: &QObjectNames,
     namespace: &NamespaceName,
@@ -185,13 +244,21 @@ fn unsafe_if(condition: bool) -> Option<TokenStream> {
     }
 }
 
+/// Generate the constructors of a QObject
+///
+/// `cxx_qt::Instantiate` is only implemented for the declared constructors if `instantiable` is set,
+/// as C++ can not construct abstract classes or classes which do not derive from QObject.
 pub fn generate(
     constructors: &[&Constructor],
     qobject_names: &QObjectNames,
     namespace: &NamespaceName,
     type_names: &TypeNames,
     cfgs: &[Attribute],
+    instantiable: bool,
 ) -> Result<GeneratedRustFragment> {
+    // The implicit default constructor does not implement Instantiate, as the QObject could
+    // inherit pure virtual methods from its C++ base class.
+    // Declaring `impl cxx_qt::Constructor<()> for T {}` opts into it.
     if constructors.is_empty() {
         return Ok(generate_default_constructor(qobject_names, namespace, cfgs));
     }
@@ -461,7 +528,18 @@ pub fn generate(
                     qobject,
                     (#(#extract_initialize_arguments,)*));
             }
-        }])
+        }]);
+
+        if instantiable {
+            result.append(generate_instantiate(
+                qobject_names,
+                type_names,
+                index,
+                &constructor.arguments,
+                &constructor.lifetime,
+                cfgs,
+            )?);
+        }
     }
     Ok(result)
 }
@@ -504,6 +582,7 @@ mod tests {
             &mock_namespace(),
             &type_names,
             &[],
+            true,
         )
         .unwrap()
     }
@@ -538,6 +617,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn constructor_not_instantiable() {
+        let constructor = mock_constructor();
+        let instantiable = generate_mocked(&[&constructor]);
+        let blocks = generate(
+            &[&constructor],
+            &mock_name(),
+            &mock_namespace(),
+            &TypeNames::mock(),
+            &[],
+            false,
+        )
+        .unwrap();
+
+        // Only the extern block of qobjectNew and the Instantiate impl are missing
+        assert_eq!(
+            blocks.cxx_mod_contents.len() + 1,
+            instantiable.cxx_mod_contents.len()
+        );
+        assert_eq!(
+            blocks.cxx_qt_mod_contents.len() + 1,
+            instantiable.cxx_qt_mod_contents.len()
+        );
+        let tokens = blocks
+            .cxx_qt_mod_contents
+            .iter()
+            .map(|item| quote! { #item }.to_string())
+            .collect::<String>();
+        assert!(!tokens.contains("Instantiate"));
+    }
+
     fn assert_empty_argument_struct<T: quote::ToTokens>(
         tokens: &T,
         rust_name: &str,
@@ -677,7 +787,7 @@ mod tests {
     ) {
         // the index here starts with 5, as this is part of the larger multiple_constructors test.
         assert_tokens_eq(
-            &blocks.cxx_mod_contents[5],
+            &blocks.cxx_mod_contents[6],
             quote! {
                 #namespace_attr
                 #[cxx_name = "CxxQtConstructorArguments1"]
@@ -692,7 +802,7 @@ mod tests {
         );
 
         assert_tokens_eq(
-            &blocks.cxx_mod_contents[6],
+            &blocks.cxx_mod_contents[7],
             quote! {
                 #namespace_attr
                 #[cxx_name="CxxQtConstructorBaseArguments1"]
@@ -705,7 +815,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &blocks.cxx_mod_contents[7],
+            &blocks.cxx_mod_contents[8],
             quote! {
                 #namespace_attr
                 #[cxx_name="CxxQtConstructorNewArguments1"]
@@ -716,7 +826,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &blocks.cxx_mod_contents[8],
+            &blocks.cxx_mod_contents[9],
             quote! {
                 #namespace_attr
                 #[cxx_name="CxxQtConstructorInitializeArguments1"]
@@ -728,7 +838,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &blocks.cxx_mod_contents[9],
+            &blocks.cxx_mod_contents[10],
             quote! {
                 #[allow(clippy::needless_lifetimes)]
                 extern "Rust" {
@@ -748,7 +858,7 @@ mod tests {
         );
 
         assert_tokens_eq(
-            &blocks.cxx_qt_mod_contents[4],
+            &blocks.cxx_qt_mod_contents[5],
             quote! {
                 #[allow(clippy::needless_lifetimes)]
                 impl<'lifetime> ::cxx_qt::ConstructorDeclared<(*const QObject,)> for qobject::MyObject {}
@@ -756,7 +866,7 @@ mod tests {
         );
 
         assert_tokens_eq(
-            &blocks.cxx_qt_mod_contents[5],
+            &blocks.cxx_qt_mod_contents[6],
             quote! {
                 #[doc(hidden)]
                 pub fn route_arguments_MyObject_1<'lifetime>(arg0: *const QObject) -> qobject::CxxQtConstructorArgumentsMyObject1<'lifetime>
@@ -784,7 +894,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &blocks.cxx_qt_mod_contents[6],
+            &blocks.cxx_qt_mod_contents[7],
             quote! {
                 #[doc(hidden)]
                 #[allow(unused_variables)]
@@ -798,7 +908,7 @@ mod tests {
             },
         );
         assert_tokens_eq(
-            &blocks.cxx_qt_mod_contents[7],
+            &blocks.cxx_qt_mod_contents[8],
             quote! {
                 #[doc(hidden)]
                 #[allow(unused_variables)]
@@ -836,8 +946,8 @@ mod tests {
             },
         ]);
 
-        assert_eq!(blocks.cxx_mod_contents.len(), 10);
-        assert_eq!(blocks.cxx_qt_mod_contents.len(), 8);
+        assert_eq!(blocks.cxx_mod_contents.len(), 12);
+        assert_eq!(blocks.cxx_qt_mod_contents.len(), 10);
 
         let namespace_attr = quote! {
                 #[namespace = "qobject::cxx_qt_MyObject"]
@@ -846,6 +956,56 @@ mod tests {
         assert_empty_constructor_blocks(&blocks, &namespace_attr);
 
         assert_full_constructor_blocks(&blocks, &namespace_attr);
+
+        // Instantiate without arguments
+        assert_tokens_eq(
+            &blocks.cxx_mod_contents[5],
+            quote! {
+                unsafe extern "C++" {
+                    include!(<cxx-qt/instantiate.h>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "qobjectNew"]
+                    #[namespace = "rust::cxxqt1"]
+                    fn cxx_qt_ffi_MyObject_new0() -> UniquePtr<MyObject>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &blocks.cxx_qt_mod_contents[4],
+            quote! {
+                impl cxx_qt::Instantiate<()> for qobject::MyObject {
+                    fn new_unique((): ()) -> cxx::UniquePtr<Self> {
+                        qobject::cxx_qt_ffi_MyObject_new0()
+                    }
+                }
+            },
+        );
+
+        // Instantiate with a pointer argument
+        assert_tokens_eq(
+            &blocks.cxx_mod_contents[11],
+            quote! {
+                unsafe extern "C++" {
+                    include!(<cxx-qt/instantiate.h>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "qobjectNew"]
+                    #[namespace = "rust::cxxqt1"]
+                    unsafe fn cxx_qt_ffi_MyObject_new1(arg0: *const QObject) -> UniquePtr<MyObject>;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &blocks.cxx_qt_mod_contents[9],
+            quote! {
+                impl cxx_qt::Instantiate<(*const QObject,)> for qobject::MyObject {
+                    fn new_unique((arg0,): (*const QObject,)) -> cxx::UniquePtr<Self> {
+                        unsafe { qobject::cxx_qt_ffi_MyObject_new1(arg0) }
+                    }
+                }
+            },
+        );
     }
 
     #[test]
@@ -859,6 +1019,7 @@ mod tests {
             &mock_namespace(),
             &TypeNames::mock(),
             &[],
+            true,
         )
         .is_err());
     }
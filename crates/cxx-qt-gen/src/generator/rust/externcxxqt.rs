@@ -2,7 +2,7 @@
 // SPDX-FileContributor: Andrew Hayzen <andrew.hayzen@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
-use crate::generator::naming::qobject::QObjectNames;
+use crate::generator::naming::{qobject::QObjectNames, signals::is_overloaded};
 use crate::{
     generator::rust::{fragment::GeneratedRustFragment, signals::generate_rust_signal},
     naming::TypeNames,
@@ -97,6 +97,10 @@ impl GeneratedRustFragment {
             let qobject_name = type_names.lookup(&signal.qobject_ident)?;
             generated.push(generate_rust_signal(
                 signal,
+                is_overloaded(
+                    &signal.name,
+                    extern_cxxqt_block.signal_names(&signal.qobject_ident),
+                ),
                 qobject_name,
                 type_names,
                 // Copy the same safety as the extern C++Qt block into the generated extern C++
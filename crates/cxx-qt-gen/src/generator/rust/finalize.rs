@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::naming::{namespace::NamespaceName, qobject::QObjectNames},
+    naming::TypeNames,
+};
+use quote::format_ident;
+use syn::{parse_quote, Attribute, Result};
+
+use super::fragment::GeneratedRustFragment;
+
+/// Generate the function called by the C++ destructor of the QObject, which calls cxx_qt::Finalize
+pub fn generate(
+    qobject_names: &QObjectNames,
+    namespace: &NamespaceName,
+    type_names: &TypeNames,
+    cfgs: &[Attribute],
+) -> Result<GeneratedRustFragment> {
+    let namespace_internals = &namespace.internal;
+    let qobject_name = qobject_names.name.cxx_unqualified();
+    let qobject_name_rust = qobject_names.name.rust_unqualified();
+    let qobject_name_rust_qualified = type_names.rust_qualified(qobject_name_rust)?;
+    let finalize_rust = format_ident!("finalize_{qobject_name}");
+
+    Ok(GeneratedRustFragment {
+        cxx_mod_contents: vec![parse_quote! {
+            #(#cfgs)*
+            extern "Rust" {
+                #[namespace = #namespace_internals]
+                #[cxx_name = "finalize"]
+                fn #finalize_rust(qobject: Pin<&mut #qobject_name_rust>);
+            }
+        }],
+        cxx_qt_mod_contents: vec![parse_quote! {
+            #[doc(hidden)]
+            #(#cfgs)*
+            pub fn #finalize_rust(qobject: core::pin::Pin<&mut #qobject_name_rust_qualified>) {
+                <#qobject_name_rust_qualified as cxx_qt::Finalize>::finalize(qobject);
+            }
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::tests::assert_tokens_eq;
+    use quote::quote;
+
+    #[test]
+    fn test_generate_rust_finalize() {
+        let qobject_names = create_qobjectname();
+        let namespace =
+            NamespaceName::from_namespace_and_ident("cxx_qt", &format_ident!("MyObject"));
+
+        let generated = generate(&qobject_names, &namespace, &TypeNames::mock(), &[]).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[namespace = "cxx_qt::cxx_qt_MyObject"]
+                    #[cxx_name = "finalize"]
+                    fn finalize_MyObject(qobject: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                #[doc(hidden)]
+                pub fn finalize_MyObject(qobject: core::pin::Pin<&mut qobject::MyObject>) {
+                    <qobject::MyObject as cxx_qt::Finalize>::finalize(qobject);
+                }
+            },
+        );
+    }
+}
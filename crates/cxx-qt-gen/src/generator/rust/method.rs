@@ -3,20 +3,23 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use crate::generator::naming::method::{default_overload_ident, throwing_cxx_name};
 use crate::generator::rust::{
     get_call_params_tokens, get_params_tokens, get_params_tokens_qualified,
+    get_static_params_tokens,
 };
 use crate::naming::rust::syn_type_cxx_bridge_to_qualified;
 use crate::naming::TypeNames;
 use crate::{
     generator::{naming::qobject::QObjectNames, rust::fragment::GeneratedRustFragment},
-    parser::method::ParsedMethod,
+    parser::{method::ParsedMethod, parameter::ParsedFunctionParameter},
 };
 use quote::quote;
 use syn::{parse_quote_spanned, spanned::Spanned, Item, Result, ReturnType};
 
 pub fn generate_rust_methods(
     invokables: &[&ParsedMethod],
+    qml_element: bool,
     qobject_names: &QObjectNames,
     type_names: &TypeNames,
 ) -> Result<GeneratedRustFragment> {
@@ -27,14 +30,29 @@ pub fn generate_rust_methods(
         .map(|invokable| {
             // TODO: once we aren't using qobject::T in the extern "RustQt"
             // we can just pass through the original ExternFn block and add the attribute?
-            let invokable_ident_cpp = invokable.name.cxx_unqualified();
+            // Errors of the Rust implementation are thrown from a separate C++ method,
+            // which is called by the Q_INVOKABLE that throws them to QML
+            let invokable_ident_cpp = if qml_element && invokable.throws_to_qml() {
+                throwing_cxx_name(&invokable.name.cxx_unqualified())
+            } else {
+                invokable.name.cxx_unqualified()
+            };
             let invokable_ident_rust = invokable.name.rust_unqualified();
 
-            let parameter_signatures = get_params_tokens(
-                invokable.mutable,
-                &invokable.parameters,
-                cpp_class_name_rust,
-            );
+            // Static methods have no receiver, instead CXX associates them with the QObject
+            let self_type = if invokable.is_static {
+                let self_type = cpp_class_name_rust.to_string();
+                Some(quote! { #[Self = #self_type] })
+            } else {
+                None
+            };
+            let parameter_signatures = |parameters: &[ParsedFunctionParameter]| {
+                if invokable.is_static {
+                    get_static_params_tokens(parameters)
+                } else {
+                    get_params_tokens(invokable.mutable, parameters, cpp_class_name_rust)
+                }
+            };
 
             let return_type = &invokable.method.sig.output;
 
@@ -60,32 +78,71 @@ pub fn generate_rust_methods(
                 Some(quote! { unsafe })
             };
 
-            let wrapper_fn = if invokable.wrap {
+            let mut cxx_qt_mod_contents = if invokable.wrap {
                 vec![generate_auto_wrap_fn(type_names, qobject_names, invokable)?]
             } else {
                 vec![]
             };
 
+            let full_parameter_signatures = parameter_signatures(&invokable.parameters);
+            let mut cxx_mod_contents = vec![parse_quote_spanned! {
+                invokable.method.span() =>
+                // Note: extern "Rust" block does not need to be unsafe
+                #block_safety extern #block_type {
+                    // Note that we are exposing a Rust method on the C++ type to C++
+                    //
+                    // CXX ends up generating the source, then we generate the matching header.
+                    #[cxx_name = #invokable_ident_cpp]
+                    // Needed for QObjects to have a namespace on their type or extern block
+                    //
+                    // A Namespace from cxx_qt::bridge would be automatically applied to all children
+                    // but to apply it to only certain types, it is needed here too
+                    #cxx_namespace
+                    #self_type
+                    #(#cfgs)*
+                    #[doc(hidden)]
+                    #unsafe_call fn #invokable_ident_rust(#full_parameter_signatures) #return_type;
+                }
+            }];
+
+            // Each parameter with a default value results in an overload with the same C++ name,
+            // which is implemented in Rust by calling the method with the default value
+            if let Some(first_default) = ParsedFunctionParameter::first_default(&invokable.parameters)
+            {
+                // The overloads are always in an extern "Rust" block
+                let unsafe_call = if !invokable.safe || invokable.unsafe_block {
+                    Some(quote! { unsafe })
+                } else {
+                    None
+                };
+                for parameter_count in first_default..invokable.parameters.len() {
+                    let overload_ident =
+                        default_overload_ident(invokable_ident_rust, parameter_count);
+                    let overload_parameter_signatures =
+                        parameter_signatures(&invokable.parameters[..parameter_count]);
+                    cxx_mod_contents.push(parse_quote_spanned! {
+                        invokable.method.span() =>
+                        extern "Rust" {
+                            #[cxx_name = #invokable_ident_cpp]
+                            #cxx_namespace
+                            #self_type
+                            #(#cfgs)*
+                            #[doc(hidden)]
+                            #unsafe_call fn #overload_ident(#overload_parameter_signatures) #return_type;
+                        }
+                    });
+                    cxx_qt_mod_contents.push(generate_default_overload_fn(
+                        type_names,
+                        qobject_names,
+                        invokable,
+                        parameter_count,
+                    )?);
+                }
+            }
+
             Ok(GeneratedRustFragment {
-                cxx_mod_contents: vec![parse_quote_spanned! {
-                    invokable.method.span() =>
-                    // Note: extern "Rust" block does not need to be unsafe
-                    #block_safety extern #block_type {
-                        // Note that we are exposing a Rust method on the C++ type to C++
-                        //
-                        // CXX ends up generating the source, then we generate the matching header.
-                        #[cxx_name = #invokable_ident_cpp]
-                        // Needed for QObjects to have a namespace on their type or extern block
-                        //
-                        // A Namespace from cxx_qt::bridge would be automatically applied to all children
-                        // but to apply it to only certain types, it is needed here too
-                        #cxx_namespace
-                        #(#cfgs)*
-                        #[doc(hidden)]
-                        #unsafe_call fn #invokable_ident_rust(#parameter_signatures) #return_type;
-                    }
-                }],
-                cxx_qt_mod_contents: wrapper_fn,
+                cxx_mod_contents,
+                cxx_qt_mod_contents,
             })
         })
         .collect::<Result<Vec<_>>>()?;
@@ -93,6 +150,73 @@ pub fn generate_rust_methods(
     Ok(GeneratedRustFragment::flatten(generated))
 }
 
+/// Generate the Rust implementation of the overload of a method which takes the given number of
+/// parameters, it calls the method with the default values of the remaining parameters
+fn generate_default_overload_fn(
+    type_names: &TypeNames,
+    qobject_names: &QObjectNames,
+    invokable: &ParsedMethod,
+    parameter_count: usize,
+) -> Result<Item> {
+    let cfgs = &invokable.cfgs;
+    let qualified_impl = type_names.rust_qualified(&invokable.qobject_ident)?;
+    let invokable_ident_rust = invokable.name.rust_unqualified();
+    let overload_ident = default_overload_ident(invokable_ident_rust, parameter_count);
+
+    let (parameters, defaults) = invokable.parameters.split_at(parameter_count);
+    let qualified_return_type = match &invokable.method.sig.output {
+        ReturnType::Default => ReturnType::Default,
+        ReturnType::Type(arrow, boxed_type) => {
+            let qualified_type = syn_type_cxx_bridge_to_qualified(boxed_type, type_names)?;
+            ReturnType::Type(*arrow, Box::new(qualified_type))
+        }
+    };
+
+    let (parameter_signatures_qualified, receiver) = if invokable.is_static {
+        let parameters = parameters
+            .iter()
+            .map(|parameter| {
+                let ident = &parameter.ident;
+                let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+                Ok(quote! { #ident: #ty })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        (quote! { #(#parameters),* }, None)
+    } else {
+        (
+            get_params_tokens_qualified(
+                invokable.mutable,
+                parameters,
+                &qobject_names.name.rust_qualified(),
+                type_names,
+            )?,
+            Some(quote! { self, }),
+        )
+    };
+    let parameter_idents = parameters.iter().map(|parameter| &parameter.ident);
+    let default_values = defaults.iter().map(|parameter| &parameter.default);
+
+    let call = quote! {
+        Self::#invokable_ident_rust(#receiver #(#parameter_idents,)* #(#default_values),*)
+    };
+    let (method_safety, call) = if invokable.safe {
+        (None, call)
+    } else {
+        (Some(quote! { unsafe }), quote! { unsafe { #call } })
+    };
+
+    Ok(parse_quote_spanned! {
+        invokable.method.span() =>
+        #(#cfgs)*
+        impl #qualified_impl {
+            #[doc(hidden)]
+            pub #method_safety fn #overload_ident(#parameter_signatures_qualified) #qualified_return_type {
+                #call
+            }
+        }
+    })
+}
+
 pub fn generate_auto_wrap_fn(
     type_names: &TypeNames,
     qobject_names: &QObjectNames,
@@ -192,6 +316,7 @@ mod tests {
 
         let generated = generate_rust_methods(
             &invokables.iter().collect::<Vec<_>>(),
+            false,
             &qobject_names,
             &type_names,
         )
@@ -274,4 +399,84 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_invokables_static() {
+        let method: ForeignItemFn = parse_quote! {
+            #[cxx_name = "staticInvokable"]
+            #[Self = "MyObject"]
+            fn static_invokable(param: i32) -> i32;
+        };
+        let invokables = [ParsedMethod::mock_qinvokable(&method)];
+        let qobject_names = create_qobjectname_with_qcolor();
+
+        let generated = generate_rust_methods(
+            &invokables.iter().collect::<Vec<_>>(),
+            false,
+            &qobject_names,
+            &TypeNames::mock(),
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 0);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "staticInvokable"]
+                    #[Self = "MyObject"]
+                    #[doc(hidden)]
+                    fn static_invokable(param: i32) -> i32;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_invokables_defaults() {
+        let method: ForeignItemFn = parse_quote! {
+            #[cxx_name = "defaultInvokable"]
+            fn default_invokable(self: &MyObject, first: i32, #[default = first_default()] second: QColor) -> i32;
+        };
+        let invokables = [ParsedMethod::mock_qinvokable(&method)];
+        let qobject_names = create_qobjectname_with_qcolor();
+
+        let mut type_names = TypeNames::mock();
+        type_names.mock_insert("QColor", Some(format_ident!("qobject")), None, None);
+
+        let generated = generate_rust_methods(
+            &invokables.iter().collect::<Vec<_>>(),
+            false,
+            &qobject_names,
+            &type_names,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "defaultInvokable"]
+                    #[doc(hidden)]
+                    fn default_invokable_cxx_qt_default_1(self: &MyObject, first: i32) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl qobject::MyObject {
+                    #[doc(hidden)]
+                    pub fn default_invokable_cxx_qt_default_1(self: &qobject::MyObject, first: i32) -> i32 {
+                        Self::default_invokable(self, first, first_default())
+                    }
+                }
+            },
+        );
+    }
 }
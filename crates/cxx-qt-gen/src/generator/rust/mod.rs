@@ -6,13 +6,17 @@
 pub mod constructor;
 pub mod cxxqttype;
 pub mod externcxxqt;
+pub mod finalize;
 pub mod fragment;
 pub mod inherit;
 pub mod method;
 pub mod property;
 pub mod qenum;
+pub mod qinterface;
+pub mod qnamespace;
 pub mod qobject;
 pub mod signals;
+pub mod template;
 pub mod threading;
 
 use crate::generator::{rust::fragment::GeneratedRustFragment, structuring};
@@ -39,7 +43,12 @@ impl GeneratedRustBlocks {
     pub fn from(parser: &Parser) -> Result<GeneratedRustBlocks> {
         let structures = structuring::Structures::new(&parser.cxx_qt_data)?;
 
-        let mut fragments = vec![];
+        let mut fragments = parser
+            .cxx_qt_data
+            .templates
+            .iter()
+            .map(|template| template::generate(template, &parser.type_names))
+            .collect::<Result<Vec<GeneratedRustFragment>>>()?;
         fragments.extend(
             structures
                 .qobjects
@@ -47,6 +56,15 @@ impl GeneratedRustBlocks {
                 .map(|qobject| GeneratedRustFragment::from_qobject(qobject, &parser.type_names))
                 .collect::<Result<Vec<GeneratedRustFragment>>>()?,
         );
+        fragments.extend(structures.qnamespaces.iter().map(qnamespace::generate));
+        fragments.extend(
+            parser
+                .cxx_qt_data
+                .qinterfaces
+                .iter()
+                .map(|interface| qinterface::generate(interface, &parser.type_names))
+                .collect::<Result<Vec<GeneratedRustFragment>>>()?,
+        );
         fragments.extend(
             parser
                 .cxx_qt_data
@@ -111,6 +129,7 @@ fn add_qobject_import(cxx_qt_data: &ParsedCxxQtData) -> Option<GeneratedRustFrag
         .iter()
         .any(|obj| obj.has_qobject_macro && obj.base_class.is_none());
     if includes
+        || !cxx_qt_data.qinterfaces.is_empty()
         || cxx_qt_data
             .extern_cxxqt_blocks
             .iter()
@@ -150,6 +169,16 @@ pub fn get_params_tokens(
     }
 }
 
+/// Return the [TokenStream] of the parsed parameters for a function without a receiver
+pub fn get_static_params_tokens(parameters: &[ParsedFunctionParameter]) -> TokenStream {
+    let parameters = parameters.iter().map(|parameter| {
+        let ident = &parameter.ident;
+        let ty = &parameter.ty;
+        quote! { #ident: #ty }
+    });
+    quote! { #(#parameters),* }
+}
+
 /// Return the [TokenStream] of the parsed parameters, with self type qualified for use in generation
 pub fn get_params_tokens_qualified(
     mutable: bool,
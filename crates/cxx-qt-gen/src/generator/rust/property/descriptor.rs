@@ -0,0 +1,320 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::rust::fragment::GeneratedRustFragment;
+use crate::generator::structuring::StructuredQObject;
+use crate::{
+    generator::naming::{
+        property::{NameState, QPropertyNames},
+        qobject::QObjectNames,
+    },
+    naming::rust::syn_type_cxx_bridge_to_qualified,
+    naming::TypeNames,
+    parser::{method::MethodFields, property::ParsedQProperty},
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Result};
+
+/// Find the fields of a custom method, as we need to know if it is safe to call
+fn custom_method<'a>(
+    structured_qobject: &'a StructuredQObject,
+    ident: &Ident,
+) -> Option<&'a MethodFields> {
+    structured_qobject
+        .methods
+        .iter()
+        .map(|method| &method.method_fields)
+        .chain(
+            structured_qobject
+                .inherited_methods
+                .iter()
+                .map(|method| &method.method_fields),
+        )
+        .find(|fields| fields.name.rust_unqualified() == ident)
+}
+
+/// Returns false if the given name refers to a user provided function which is unsafe to call
+fn is_safe_to_call(name: &NameState, structured_qobject: &StructuredQObject) -> bool {
+    match name {
+        NameState::Auto(_) => true,
+        NameState::Custom(name) => custom_method(structured_qobject, name.rust_unqualified())
+            .is_none_or(|fields| fields.safe),
+    }
+}
+
+/// The number of parameters of the notify signal, which are ignored by the descriptor
+fn notify_parameter_count(name: &NameState, structured_qobject: &StructuredQObject) -> usize {
+    match name {
+        NameState::Auto(_) => 0,
+        NameState::Custom(name) => structured_qobject
+            .signals
+            .iter()
+            .find(|signal| signal.name.rust_unqualified() == name.rust_unqualified())
+            .map_or(0, |signal| signal.parameters.len()),
+    }
+}
+
+pub fn generate(
+    properties: &[(&ParsedQProperty, QPropertyNames)],
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+    structured_qobject: &StructuredQObject,
+) -> Result<GeneratedRustFragment> {
+    let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+    let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+    let cfgs = &structured_qobject.declaration.cfgs;
+
+    let (meta_type_fn, meta_type_fn_attrs, meta_type_fn_qualified) = qobject_names
+        .cxx_qt_ffi_method("propertyMetaTypeId")
+        .into_cxx_parts();
+
+    let mut descriptors = vec![];
+    for (property, idents) in properties {
+        // A descriptor must always be able to read the property
+        if !is_safe_to_call(&idents.getter, structured_qobject) {
+            continue;
+        }
+
+        let name_cpp = idents.name.cxx_unqualified();
+        let name_rust = idents.name.rust_unqualified().to_string();
+        let qualified_ty = syn_type_cxx_bridge_to_qualified(&property.ty, type_names)?;
+
+        let getter_rust = idents.getter.rust_unqualified();
+        let read = quote! {
+            |qobject, f| {
+                let value = qobject.#getter_rust();
+                f(::core::borrow::Borrow::<#qualified_ty>::borrow(&value))
+            }
+        };
+
+        let write = match &idents.setter {
+            Some(setter) if is_safe_to_call(setter, structured_qobject) => {
+                let setter_rust = setter.rust_unqualified();
+                quote! {
+                    Some(|qobject, value| {
+                        qobject.#setter_rust(*value.downcast::<#qualified_ty>()?);
+                        Ok(())
+                    })
+                }
+            }
+            _ => quote! { None },
+        };
+
+        let notify = if let Some(notify) = &idents.notify {
+            let connect_rust = format_ident!("connect_{}", notify.rust_unqualified());
+            let ignored_parameters: Vec<TokenStream> =
+                (0..notify_parameter_count(notify, structured_qobject))
+                    .map(|_| quote! { _ })
+                    .collect();
+            quote! {
+                Some(|qobject, mut closure, conn_type| {
+                    qobject.#connect_rust(move |qobject #(, #ignored_parameters)*| closure(qobject), conn_type)
+                })
+            }
+        } else {
+            quote! { None }
+        };
+
+        descriptors.push(quote! {
+            ::cxx_qt::QPropertyDescriptor::new(
+                #name_cpp,
+                #name_rust,
+                || unsafe { #meta_type_fn_qualified(::core::ptr::null(), #name_cpp) },
+                #read,
+                #write,
+                #notify,
+            )
+        });
+    }
+
+    let mut generated = GeneratedRustFragment::default();
+    if !descriptors.is_empty() {
+        generated.cxx_mod_contents.push(parse_quote! {
+            #(#cfgs)*
+            unsafe extern "C++" {
+                include!(<cxx-qt/property.h>);
+
+                #[doc(hidden)]
+                #(#meta_type_fn_attrs)*
+                unsafe fn #meta_type_fn(thiz: *const #cpp_class_name_rust, name: &str) -> i32;
+            }
+        });
+    }
+
+    generated.cxx_qt_mod_contents.push(parse_quote! {
+        #(#cfgs)*
+        impl ::cxx_qt::QProperties for #qualified_impl {
+            const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[
+                #(#descriptors),*
+            ];
+        }
+    });
+
+    Ok(generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::generator::naming::qobject::tests::create_qobjectname;
+    use crate::generator::structuring::Structures;
+    use crate::parser::property::mock_property;
+    use crate::parser::qobject::ParsedQObject;
+    use crate::parser::Parser;
+    use crate::tests::assert_tokens_eq;
+    use syn::ItemStruct;
+
+    #[test]
+    fn test_generate_rust_property_descriptors() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(i32, trivial_property, cxx_name = "trivialProperty")]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+
+        let obj = ParsedQObject::mock();
+        let structured_qobject = StructuredQObject::mock(&obj);
+        let idents = QPropertyNames::try_from_property(&property, &structured_qobject).unwrap();
+
+        let generated = generate(
+            &[(&property, idents)],
+            &create_qobjectname(),
+            &TypeNames::mock(),
+            &structured_qobject,
+        )
+        .unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 1);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    include!(<cxx-qt/property.h>);
+
+                    #[doc(hidden)]
+                    #[cxx_name = "propertyMetaTypeId"]
+                    #[namespace = "rust::cxxqt1"]
+                    unsafe fn cxx_qt_ffi_MyObject_propertyMetaTypeId(thiz: *const MyObject, name: &str) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl ::cxx_qt::QProperties for qobject::MyObject {
+                    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[
+                        ::cxx_qt::QPropertyDescriptor::new(
+                            "trivialProperty",
+                            "trivial_property",
+                            | | unsafe { qobject::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "trivialProperty") },
+                            |qobject, f| {
+                                let value = qobject.trivial_property();
+                                f(::core::borrow::Borrow::<i32>::borrow(&value))
+                            },
+                            Some(|qobject, value| {
+                                qobject.set_trivial_property(*value.downcast::<i32>()?);
+                                Ok(())
+                            }),
+                            Some(|qobject, mut closure, conn_type| {
+                                qobject.connect_trivial_property_changed(move |qobject| closure(qobject), conn_type)
+                            }),
+                        )
+                    ];
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_property_descriptors_custom() {
+        let module = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qsignal]
+                    fn value_updated(self: Pin<&mut MyObject>, value: i32);
+                }
+
+                unsafe extern "RustQt" {
+                    fn get_value(self: &MyObject) -> i32;
+
+                    unsafe fn set_value(self: Pin<&mut MyObject>, value: i32);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let structured_qobject = structures.qobjects.first().unwrap();
+
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(i32, value, READ = get_value, WRITE = set_value, NOTIFY = value_updated)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        let idents = QPropertyNames::try_from_property(&property, structured_qobject).unwrap();
+
+        let generated = generate(
+            &[(&property, idents)],
+            &create_qobjectname(),
+            &TypeNames::mock(),
+            structured_qobject,
+        )
+        .unwrap();
+
+        // Unsafe setters are not exposed, and signal parameters are ignored
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl ::cxx_qt::QProperties for qobject::MyObject {
+                    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[
+                        ::cxx_qt::QPropertyDescriptor::new(
+                            "value",
+                            "value",
+                            | | unsafe { qobject::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "value") },
+                            |qobject, f| {
+                                let value = qobject.get_value();
+                                f(::core::borrow::Borrow::<i32>::borrow(&value))
+                            },
+                            None,
+                            Some(|qobject, mut closure, conn_type| {
+                                qobject.connect_value_updated(move |qobject, _| closure(qobject), conn_type)
+                            }),
+                        )
+                    ];
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_property_descriptors_empty() {
+        let obj = ParsedQObject::mock();
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let generated = generate(
+            &[],
+            &create_qobjectname(),
+            &TypeNames::mock(),
+            &structured_qobject,
+        )
+        .unwrap();
+
+        assert!(generated.cxx_mod_contents.is_empty());
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl ::cxx_qt::QProperties for qobject::MyObject {
+                    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+                }
+            },
+        );
+    }
+}
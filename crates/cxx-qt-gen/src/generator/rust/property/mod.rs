@@ -3,7 +3,9 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+pub mod descriptor;
 pub mod getter;
+pub mod reset;
 pub mod setter;
 pub mod signal;
 
@@ -27,6 +29,7 @@ pub fn generate_rust_properties(
 ) -> Result<GeneratedRustFragment> {
     let mut generated = GeneratedRustFragment::default();
     let mut signals = vec![];
+    let mut descriptors = vec![];
 
     for property in properties {
         let idents = QPropertyNames::try_from_property(property, structured_qobject)?;
@@ -35,19 +38,39 @@ pub fn generate_rust_properties(
             generated.append(getter);
         };
 
-        if let Some(setter) = setter::generate(&idents, qobject_names, &property.ty, type_names)? {
+        if let Some(setter) = setter::generate(
+            &idents,
+            qobject_names,
+            &property.ty,
+            &property.flags,
+            type_names,
+        )? {
             generated.append(setter);
         }
 
+        if let Some(reset) = reset::generate(&idents, qobject_names, property, type_names)? {
+            generated.append(reset);
+        }
+
         if let Some(notify) = signal::generate(&idents, qobject_names) {
             signals.push(notify)
         }
+
+        descriptors.push((property, idents));
     }
 
     generated.append(generate_rust_signals(
         &signals.iter().collect::<Vec<_>>(),
+        &structured_qobject.signal_names(),
+        qobject_names,
+        type_names,
+    )?);
+
+    generated.append(descriptor::generate(
+        &descriptors,
         qobject_names,
         type_names,
+        structured_qobject,
     )?);
 
     Ok(generated)
@@ -57,9 +80,10 @@ pub fn generate_rust_properties(
 mod tests {
     use super::*;
     use crate::naming::Name;
-    use crate::parser::property::QPropertyFlags;
+    use crate::parser::property::{FlagState, QPropertyComparison, QPropertyFlags};
     use crate::parser::qobject::ParsedQObject;
     use crate::{generator::naming::qobject::tests::create_qobjectname, tests::assert_tokens_eq};
+    use quote::format_ident;
     use syn::parse_quote;
     #[test]
     fn test_generate_rust_properties() {
@@ -68,16 +92,19 @@ mod tests {
                 name: Name::mock_name_with_cxx("trivial_property", "trivialProperty"),
                 ty: parse_quote! { i32 },
                 flags: QPropertyFlags::default(),
+                docs: vec![],
             },
             ParsedQProperty {
                 name: Name::mock_name_with_cxx("opaque_property", "opaqueProperty"),
                 ty: parse_quote! { UniquePtr<QColor> },
                 flags: QPropertyFlags::default(),
+                docs: vec![],
             },
             ParsedQProperty {
                 name: Name::mock_name_with_cxx("unsafe_property", "unsafeProperty"),
                 ty: parse_quote! { *mut T },
                 flags: QPropertyFlags::default(),
+                docs: vec![],
             },
         ];
         let qobject_names = create_qobjectname();
@@ -98,8 +125,8 @@ mod tests {
         .unwrap();
 
         // Check that we have the expected number of blocks
-        assert_eq!(generated.cxx_mod_contents.len(), 15);
-        assert_eq!(generated.cxx_qt_mod_contents.len(), 30);
+        assert_eq!(generated.cxx_mod_contents.len(), 16);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 31);
 
         // Trivial Property
 
@@ -643,4 +670,244 @@ mod tests {
             },
         );
     }
+
+    #[test]
+    fn test_generate_rust_properties_reset() {
+        let properties = vec![
+            ParsedQProperty {
+                name: Name::mock_name_with_cxx("trivial_property", "trivialProperty"),
+                ty: parse_quote! { i32 },
+                flags: QPropertyFlags {
+                    reset: Some(FlagState::Auto),
+                    ..QPropertyFlags::default()
+                },
+                docs: vec![],
+            },
+            ParsedQProperty {
+                name: Name::mock_name_with_cxx("valued_property", "valuedProperty"),
+                ty: parse_quote! { i32 },
+                flags: QPropertyFlags {
+                    write: None,
+                    notify: None,
+                    reset: Some(FlagState::Auto),
+                    reset_value: Some(parse_quote! { 42 }),
+                    ..QPropertyFlags::default()
+                },
+                docs: vec![],
+            },
+        ];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let type_names = TypeNames::mock();
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        // Reset from the Default of the Rust struct
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = "resetTrivialProperty"]
+                    fn reset_trivial_property(self: Pin<&mut MyObject>);
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[2],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Reset for the Q_PROPERTY "]
+                    #[doc = "trivial_property"]
+                    pub fn reset_trivial_property(mut self: core::pin::Pin<&mut Self>) {
+                        use cxx_qt::CxxQtType;
+                        let value: i32 = <MyObjectRust as Default>::default().trivial_property;
+                        if self.trivial_property == value {
+                            // don't want to set the value again and reemit the signal,
+                            // as this can cause binding loops
+                            return;
+                        }
+                        self.as_mut().rust_mut().trivial_property = value;
+                        self.as_mut().trivial_property_changed();
+                    }
+                }
+            },
+        );
+
+        // Reset from the given default value, without a notify signal
+        let reset_index = generated
+            .cxx_qt_mod_contents
+            .iter()
+            .position(|item| {
+                quote::quote! { #item }
+                    .to_string()
+                    .contains("reset_valued_property")
+            })
+            .unwrap();
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[reset_index],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Reset for the Q_PROPERTY "]
+                    #[doc = "valued_property"]
+                    pub fn reset_valued_property(mut self: core::pin::Pin<&mut Self>) {
+                        use cxx_qt::CxxQtType;
+                        let value: i32 = 42;
+                        if self.valued_property == value {
+                            // don't want to set the value again and reemit the signal,
+                            // as this can cause binding loops
+                            return;
+                        }
+                        self.as_mut().rust_mut().valued_property = value;
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_hooks() {
+        let properties = vec![ParsedQProperty {
+            name: Name::mock_name_with_cxx("trivial_property", "trivialProperty"),
+            ty: parse_quote! { i32 },
+            flags: QPropertyFlags {
+                validate: Some(format_ident!("validate_trivial")),
+                on_changed: Some(format_ident!("trivial_updated")),
+                ..QPropertyFlags::default()
+            },
+            docs: vec![],
+        }];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let type_names = TypeNames::mock();
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "trivial_property"]
+                    pub fn set_trivial_property(mut self: core::pin::Pin<&mut Self>, value: i32) {
+                        use cxx_qt::CxxQtType;
+                        let Some(value) = self.validate_trivial(value) else {
+                            return;
+                        };
+                        if self.trivial_property == value {
+                            // don't want to set the value again and reemit the signal,
+                            // as this can cause binding loops
+                            return;
+                        }
+                        self.as_mut().rust_mut().trivial_property = value;
+                        self.as_mut().trivial_updated();
+                        self.as_mut().trivial_property_changed();
+                    }
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_properties_comparison() {
+        let property = |name: &str, comparison: QPropertyComparison| ParsedQProperty {
+            name: Name::mock_name_with_cxx(name, name),
+            ty: parse_quote! { f64 },
+            flags: QPropertyFlags {
+                notify: None,
+                comparison,
+                ..QPropertyFlags::default()
+            },
+            docs: vec![],
+        };
+        let properties = vec![
+            property("always", QPropertyComparison::AlwaysNotify),
+            property("fuzzy", QPropertyComparison::Fuzzy),
+            property(
+                "custom",
+                QPropertyComparison::Custom(format_ident!("my_compare")),
+            ),
+        ];
+        let qobject_names = create_qobjectname();
+
+        let obj = ParsedQObject::mock();
+
+        let structured_qobject = StructuredQObject::mock(&obj);
+
+        let type_names = TypeNames::mock();
+        let generated = generate_rust_properties(
+            &properties,
+            &qobject_names,
+            &type_names,
+            &structured_qobject,
+        )
+        .unwrap();
+
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "always"]
+                    pub fn set_always(mut self: core::pin::Pin<&mut Self>, value: f64) {
+                        use cxx_qt::CxxQtType;
+                        self.as_mut().rust_mut().always = value;
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[3],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "fuzzy"]
+                    pub fn set_fuzzy(mut self: core::pin::Pin<&mut Self>, value: f64) {
+                        use cxx_qt::CxxQtType;
+                        if ::cxx_qt::FuzzyCompare::fuzzy_compare(&self.fuzzy, &value) {
+                            // don't want to set the value again and reemit the signal,
+                            // as this can cause binding loops
+                            return;
+                        }
+                        self.as_mut().rust_mut().fuzzy = value;
+                    }
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[5],
+            parse_quote! {
+                impl qobject::MyObject {
+                    #[doc = "Setter for the Q_PROPERTY "]
+                    #[doc = "custom"]
+                    pub fn set_custom(mut self: core::pin::Pin<&mut Self>, value: f64) {
+                        use cxx_qt::CxxQtType;
+                        if self.my_compare(&value) {
+                            // don't want to set the value again and reemit the signal,
+                            // as this can cause binding loops
+                            return;
+                        }
+                        self.as_mut().rust_mut().custom = value;
+                    }
+                }
+            },
+        );
+    }
 }
@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use super::setter::generate_unchanged_check;
+use crate::generator::rust::fragment::GeneratedRustFragment;
+use crate::{
+    generator::naming::{
+        property::{NameState, QPropertyNames},
+        qobject::QObjectNames,
+    },
+    naming::rust::syn_type_cxx_bridge_to_qualified,
+    naming::TypeNames,
+    parser::property::ParsedQProperty,
+};
+use quote::quote;
+use syn::{parse_quote, Result};
+
+pub fn generate(
+    idents: &QPropertyNames,
+    qobject_names: &QObjectNames,
+    property: &ParsedQProperty,
+    type_names: &TypeNames,
+) -> Result<Option<GeneratedRustFragment>> {
+    let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
+    let rust_struct_ident = &qobject_names.rust_struct.rust_unqualified();
+
+    if let Some(NameState::Auto(reset)) = &idents.reset {
+        let reset_cpp = reset.cxx_unqualified();
+
+        let reset_rust = reset.rust_unqualified();
+        let ident = &idents.name.rust_unqualified();
+        let ident_str = ident.to_string();
+
+        // Generate a notify name if it was provided, otherwise return empty
+        let notify_binding = match &idents.notify {
+            Some(notify) => {
+                let notify_ident = notify.rust_unqualified();
+                quote! {self.as_mut().#notify_ident();}
+            }
+            None => quote! {},
+        };
+
+        let on_changed_binding = match &property.flags.on_changed {
+            Some(on_changed) => quote! {self.as_mut().#on_changed();},
+            None => quote! {},
+        };
+
+        // Use the value given by the user, otherwise the value of the field in the Default of the struct
+        let default_value = if let Some(expr) = &property.flags.reset_value {
+            quote! { #expr }
+        } else {
+            quote! { <#rust_struct_ident as Default>::default().#ident }
+        };
+
+        let unchanged_check = generate_unchanged_check(ident, &property.flags.comparison);
+
+        let qualified_ty = syn_type_cxx_bridge_to_qualified(&property.ty, type_names)?;
+        let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
+
+        let cxx_namespace = qobject_names.namespace_tokens();
+
+        Ok(Some(GeneratedRustFragment {
+            cxx_mod_contents: vec![parse_quote! {
+                extern "Rust" {
+                    #[cxx_name = #reset_cpp]
+                    // Needed for QObjects to have a namespace on their type or extern block
+                    //
+                    // A Namespace from cxx_qt::bridge would be automatically applied to all children
+                    // but to apply it to only certain types, it is needed here too
+                    #cxx_namespace
+                    fn #reset_rust(self: Pin<&mut #cpp_class_name_rust>);
+                }
+            }],
+            cxx_qt_mod_contents: vec![parse_quote! {
+                impl #qualified_impl {
+                    #[doc = "Reset for the Q_PROPERTY "]
+                    #[doc = #ident_str]
+                    pub fn #reset_rust(mut self: core::pin::Pin<&mut Self>) {
+                        use cxx_qt::CxxQtType;
+                        let value: #qualified_ty = #default_value;
+                        #unchanged_check
+                        self.as_mut().rust_mut().#ident = value;
+                        #on_changed_binding
+                        #notify_binding
+                    }
+                }
+            }],
+        }))
+    } else {
+        Ok(None)
+    }
+}
@@ -11,14 +11,37 @@ use crate::{
     },
     naming::rust::{syn_type_cxx_bridge_to_qualified, syn_type_is_cxx_bridge_unsafe},
     naming::TypeNames,
+    parser::property::{QPropertyComparison, QPropertyFlags},
 };
+use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use syn::{parse_quote, Result, Type};
 
+/// Generate the check which returns early if the new `value` is the same as the current value of the field
+pub fn generate_unchanged_check(ident: &Ident, comparison: &QPropertyComparison) -> TokenStream {
+    let is_unchanged = match comparison {
+        QPropertyComparison::PartialEq => quote! { self.#ident == value },
+        QPropertyComparison::AlwaysNotify => return quote! {},
+        QPropertyComparison::Fuzzy => {
+            quote! { ::cxx_qt::FuzzyCompare::fuzzy_compare(&self.#ident, &value) }
+        }
+        QPropertyComparison::Custom(compare) => quote! { self.#compare(&value) },
+    };
+
+    quote! {
+        if #is_unchanged {
+            // don't want to set the value again and reemit the signal,
+            // as this can cause binding loops
+            return;
+        }
+    }
+}
+
 pub fn generate(
     idents: &QPropertyNames,
     qobject_names: &QObjectNames,
     cxx_ty: &Type,
+    flags: &QPropertyFlags,
     type_names: &TypeNames,
 ) -> Result<Option<GeneratedRustFragment>> {
     let cpp_class_name_rust = &qobject_names.name.rust_unqualified();
@@ -39,6 +62,23 @@ pub fn generate(
             None => quote! {},
         };
 
+        // Let the user validate or adjust the new value before it is compared
+        let validate_binding = match &flags.validate {
+            Some(validate) => quote! {
+                let Some(value) = self.#validate(value) else {
+                    return;
+                };
+            },
+            None => quote! {},
+        };
+
+        let on_changed_binding = match &flags.on_changed {
+            Some(on_changed) => quote! {self.as_mut().#on_changed();},
+            None => quote! {},
+        };
+
+        let unchanged_check = generate_unchanged_check(ident, &flags.comparison);
+
         let qualified_ty = syn_type_cxx_bridge_to_qualified(cxx_ty, type_names)?;
         let qualified_impl = type_names.rust_qualified(cpp_class_name_rust)?;
 
@@ -69,12 +109,10 @@ pub fn generate(
                     #[doc = #ident_str]
                     pub fn #setter_rust(mut self: core::pin::Pin<&mut Self>, value: #qualified_ty) {
                         use cxx_qt::CxxQtType;
-                        if self.#ident == value {
-                            // don't want to set the value again and reemit the signal,
-                            // as this can cause binding loops
-                            return;
-                        }
+                        #validate_binding
+                        #unchanged_check
                         self.as_mut().rust_mut().#ident = value;
+                        #on_changed_binding
                         #notify_binding
                     }
                 }
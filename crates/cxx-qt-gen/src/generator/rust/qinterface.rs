@@ -0,0 +1,298 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::{
+        naming::qobject::QObjectNames,
+        rust::{
+            fragment::GeneratedRustFragment, method::generate_rust_methods,
+            signals::generate_rust_signals,
+        },
+    },
+    naming::{rust::syn_type_cxx_bridge_to_qualified, TypeNames},
+    parser::{method::ParsedMethod, qinterface::ParsedQInterface},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, parse_quote_spanned, spanned::Spanned, Result, ReturnType};
+
+/// Generate the C++ type, the pure virtual methods and the Rust trait of a #[qinterface]
+pub fn generate(
+    interface: &ParsedQInterface,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let qobject_names = QObjectNames::from_name_and_ident(
+        &interface.name,
+        interface.name.rust_unqualified(),
+        type_names,
+    )?;
+
+    let (ident, cxx_attrs, qualified) = interface.name.clone().into_cxx_parts();
+    let vis = &interface.declaration.vis;
+    let docs = &interface.docs;
+
+    let mut generated = vec![
+        GeneratedRustFragment::from_cxx_item(parse_quote! {
+            unsafe extern "C++" {
+                #(#cxx_attrs)*
+                #(#docs)*
+                #vis type #ident;
+            }
+        }),
+        // The interface always derives from QObject
+        GeneratedRustFragment::generate_casting_impl(
+            &qobject_names,
+            type_names,
+            &interface.name,
+            &None,
+        )?,
+        generate_rust_methods(
+            &interface.methods.iter().collect::<Vec<_>>(),
+            false,
+            &qobject_names,
+            type_names,
+        )?,
+        generate_rust_signals(
+            &interface.signals.iter().collect::<Vec<_>>(),
+            &interface
+                .signals
+                .iter()
+                .map(|signal| &signal.name)
+                .collect::<Vec<_>>(),
+            &qobject_names,
+            type_names,
+        )?,
+    ];
+
+    let trait_fns = interface
+        .methods
+        .iter()
+        .map(|method| {
+            let docs = &method.docs;
+            let signature = trait_fn_signature(method, type_names)?;
+            Ok(quote! {
+                #(#docs)*
+                #signature;
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    generated.push(GeneratedRustFragment {
+        cxx_qt_mod_contents: vec![parse_quote! {
+            #(#docs)*
+            #vis trait #ident: ::cxx_qt::casting::Upcast<#qualified> {
+                #(#trait_fns)*
+            }
+        }],
+        ..Default::default()
+    });
+
+    Ok(GeneratedRustFragment::flatten(generated))
+}
+
+/// Generate the overrides of the interface methods for a QObject, which forward to the Rust trait
+pub fn generate_implementation(
+    interface: &ParsedQInterface,
+    qobject_names: &QObjectNames,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let implementation_methods =
+        interface.implementation_methods(qobject_names.name.rust_unqualified());
+    let mut generated = generate_rust_methods(
+        &implementation_methods.iter().collect::<Vec<_>>(),
+        false,
+        qobject_names,
+        type_names,
+    )?;
+
+    let trait_ident = interface.name.rust_unqualified();
+    let qobject_qualified = qobject_names.name.rust_qualified();
+    let forwarding_fns = interface
+        .methods
+        .iter()
+        .map(|method| {
+            let method_ident = method.name.rust_unqualified();
+            let implementation_ident = interface.implementation_ident(method);
+            let signature = trait_fn_signature(method, type_names)?;
+            let call_parameters = method.parameters.iter().map(|parameter| &parameter.ident);
+            // Replace the name of the trait method with the name called by CXX
+            let mut signature = signature;
+            signature.ident = implementation_ident;
+            let call =
+                quote! { <Self as #trait_ident>::#method_ident(self #(, #call_parameters)*) };
+            let body = if method.safe {
+                call
+            } else {
+                quote! { unsafe { #call } }
+            };
+            Ok(quote! {
+                #[doc(hidden)]
+                #signature {
+                    #body
+                }
+            })
+        })
+        .collect::<Result<Vec<TokenStream>>>()?;
+
+    generated.cxx_qt_mod_contents.push(parse_quote! {
+        impl #qobject_qualified {
+            #(#forwarding_fns)*
+        }
+    });
+
+    Ok(generated)
+}
+
+/// The signature of the trait method, with all types qualified for use outside of the bridge
+fn trait_fn_signature(method: &ParsedMethod, type_names: &TypeNames) -> Result<syn::Signature> {
+    let ident = method.name.rust_unqualified();
+    let self_ty = if method.mutable {
+        quote! { core::pin::Pin<&mut Self> }
+    } else {
+        quote! { &Self }
+    };
+    let parameters = method
+        .parameters
+        .iter()
+        .map(|parameter| {
+            let ident = &parameter.ident;
+            let ty = syn_type_cxx_bridge_to_qualified(&parameter.ty, type_names)?;
+            Ok(quote! { #ident: #ty })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let return_type = match &method.method.sig.output {
+        ReturnType::Default => ReturnType::Default,
+        ReturnType::Type(arrow, ty) => ReturnType::Type(
+            *arrow,
+            Box::new(syn_type_cxx_bridge_to_qualified(ty, type_names)?),
+        ),
+    };
+    let unsafety = &method.method.sig.unsafety;
+
+    Ok(parse_quote_spanned! {
+        method.method.span() =>
+        #unsafety fn #ident(self: #self_ty #(, #parameters)*) #return_type
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::Parser;
+    use crate::tests::assert_tokens_eq;
+    use quote::format_ident;
+    use syn::ItemMod;
+
+    fn parse_bridge() -> Parser {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type Printer = super::PrinterRust;
+                }
+
+                /// A device
+                #[qinterface]
+                trait Device {
+                    #[qinvokable]
+                    fn connect(self: Pin<&mut Self>, port: i32) -> bool;
+
+                    unsafe fn name(&self) -> *const i32;
+                }
+
+                impl Device for Printer {}
+            }
+        };
+        Parser::from(module).unwrap()
+    }
+
+    #[test]
+    fn test_generate_rust_qinterface() {
+        let parser = parse_bridge();
+        let interface = &parser.cxx_qt_data.qinterfaces[0];
+
+        let generated = generate(interface, &parser.type_names).unwrap();
+
+        // Type, casting, two methods
+        assert_eq!(generated.cxx_mod_contents.len(), 4);
+        // Casting and the trait
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 2);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                unsafe extern "C++" {
+                    /// A device
+                    type Device;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[2],
+            quote! {
+                unsafe extern "C++" {
+                    #[cxx_name = "connect"]
+                    #[doc(hidden)]
+                    fn connect(self: Pin<&mut Device>, port: i32) -> bool;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                /// A device
+                trait Device: ::cxx_qt::casting::Upcast<ffi::Device> {
+                    fn connect(self: core::pin::Pin<&mut Self>, port: i32) -> bool;
+                    unsafe fn name(self: &Self) -> *const i32;
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_generate_rust_qinterface_implementation() {
+        let parser = parse_bridge();
+        let interface = &parser.cxx_qt_data.qinterfaces[0];
+        let qobject_names = QObjectNames::from_name_and_ident(
+            parser.type_names.lookup(&format_ident!("Printer")).unwrap(),
+            &format_ident!("PrinterRust"),
+            &parser.type_names,
+        )
+        .unwrap();
+
+        let generated =
+            generate_implementation(interface, &qobject_names, &parser.type_names).unwrap();
+
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 1);
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "connect"]
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_Device_connect(self: Pin<&mut Printer>, port: i32) -> bool;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                impl ffi::Printer {
+                    #[doc(hidden)]
+                    fn cxx_qt_ffi_Device_connect(self: core::pin::Pin<&mut Self>, port: i32) -> bool {
+                        <Self as Device>::connect(self, port)
+                    }
+
+                    #[doc(hidden)]
+                    unsafe fn cxx_qt_ffi_Device_name(self: &Self) -> *const i32 {
+                        unsafe { <Self as Device>::name(self) }
+                    }
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,107 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::generator::{
+    rust::{fragment::GeneratedRustFragment, get_static_params_tokens},
+    structuring::StructuredQNamespace,
+};
+use quote::quote;
+use syn::{parse_quote_spanned, spanned::Spanned};
+
+/// Generate the extern "Rust" declarations of the free functions of a qnamespace!
+///
+/// CXX generates the C++ definitions, which call the functions of the same name in the parent module.
+pub fn generate(qnamespace: &StructuredQNamespace) -> GeneratedRustFragment {
+    let cxx_mod_contents = qnamespace
+        .functions
+        .iter()
+        .map(|function| {
+            let cxx_name = function.name.cxx_unqualified();
+            let rust_ident = function.name.rust_unqualified();
+            let namespace = function.name.namespace().unwrap_or_default();
+            let parameter_signatures = get_static_params_tokens(&function.parameters);
+            let return_type = &function.method.sig.output;
+            let cfgs = &function.cfgs;
+
+            // As with methods, forward the block unsafe to the fn
+            let unsafe_call = if !function.safe || function.unsafe_block {
+                Some(quote! { unsafe })
+            } else {
+                None
+            };
+
+            parse_quote_spanned! {
+                function.method.span() =>
+                extern "Rust" {
+                    #[cxx_name = #cxx_name]
+                    #[namespace = #namespace]
+                    #(#cfgs)*
+                    #[doc(hidden)]
+                    #unsafe_call fn #rust_ident(#parameter_signatures) #return_type;
+                }
+            }
+        })
+        .collect();
+
+    GeneratedRustFragment {
+        cxx_mod_contents,
+        cxx_qt_mod_contents: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{generator::structuring::Structures, tests::assert_tokens_eq, Parser};
+    use syn::parse_quote;
+
+    #[test]
+    fn test_generate_rust_qnamespace_functions() {
+        let parser = Parser::from(parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                qnamespace!("utils");
+
+                #[namespace = "utils"]
+                extern "RustQt" {
+                    #[qinvokable]
+                    #[cxx_name = "clampValue"]
+                    fn clamp_value(value: i32, min: i32, max: i32) -> i32;
+
+                    #[qinvokable]
+                    unsafe fn raw_value(value: *mut i32) -> i32;
+                }
+            }
+        })
+        .unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+
+        let generated = generate(&structures.qnamespaces[0]);
+        assert_eq!(generated.cxx_mod_contents.len(), 2);
+        assert!(generated.cxx_qt_mod_contents.is_empty());
+
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[0],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "clampValue"]
+                    #[namespace = "utils"]
+                    #[doc(hidden)]
+                    fn clamp_value(value: i32, min: i32, max: i32) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_mod_contents[1],
+            quote! {
+                extern "Rust" {
+                    #[cxx_name = "raw_value"]
+                    #[namespace = "utils"]
+                    #[doc(hidden)]
+                    unsafe fn raw_value(value: *mut i32) -> i32;
+                }
+            },
+        );
+    }
+}
@@ -7,8 +7,8 @@ use crate::{
     generator::{
         naming::{namespace::NamespaceName, qobject::QObjectNames},
         rust::{
-            constructor, cxxqttype, fragment::GeneratedRustFragment, inherit,
-            method::generate_rust_methods, property::generate_rust_properties,
+            constructor, cxxqttype, finalize, fragment::GeneratedRustFragment, inherit,
+            method::generate_rust_methods, property::generate_rust_properties, qinterface,
             signals::generate_rust_signals, threading,
         },
     },
@@ -35,11 +35,30 @@ impl GeneratedRustFragment {
                 type_names,
                 structured_qobject,
             )?,
-            generate_rust_methods(&structured_qobject.methods, &qobject_names, type_names)?,
+            generate_rust_methods(
+                &structured_qobject.methods,
+                qobject.qml_metadata.is_some(),
+                &qobject_names,
+                type_names,
+            )?,
             inherit::generate(&qobject_names, &structured_qobject.inherited_methods)?,
-            generate_rust_signals(&structured_qobject.signals, &qobject_names, type_names)?,
+            generate_rust_signals(
+                &structured_qobject.signals,
+                &structured_qobject.signal_names(),
+                &qobject_names,
+                type_names,
+            )?,
         ];
 
+        // If this type implements an interface then override its methods
+        if let Some(interface) = structured_qobject.interface {
+            generated.push(qinterface::generate_implementation(
+                interface,
+                &qobject_names,
+                type_names,
+            )?);
+        }
+
         // If this type is using QML declarative macros then ensure we have the right include
         if qobject.qml_metadata.is_some() {
             generated.push(GeneratedRustFragment::from_cxx_item(parse_quote! {
@@ -59,14 +78,30 @@ impl GeneratedRustFragment {
             )?);
         }
 
+        // If this type has a finalize hook then call it from the destructor
+        if structured_qobject.finalize {
+            generated.push(finalize::generate(
+                &qobject_names,
+                &namespace_idents,
+                type_names,
+                &qobject.cfgs,
+            )?);
+        }
+
         // Generate casting impl
         generated.push(GeneratedRustFragment::generate_casting_impl(
             &qobject_names,
             type_names,
             &structured_qobject.declaration.name,
-            &structured_qobject.declaration.base_class,
+            &structured_qobject.base_class().cloned(),
         )?);
 
+        // C++ can only construct QObjects which are not abstract
+        let instantiable = qobject.has_qobject_macro
+            && !structured_qobject
+                .methods
+                .iter()
+                .any(|method| method.is_pure);
         generated.extend(vec![
             constructor::generate(
                 &structured_qobject.constructors,
@@ -74,6 +109,7 @@ impl GeneratedRustFragment {
                 &namespace_idents,
                 type_names,
                 &qobject.cfgs,
+                instantiable,
             )?,
             cxxqttype::generate(&qobject_names, type_names, &qobject.cfgs)?,
         ]);
@@ -8,7 +8,7 @@ use crate::{
     generator::{
         naming::{
             qobject::QObjectNames,
-            signals::{QSignalHelperNames, QSignalNames},
+            signals::{is_overloaded, QSignalHelperNames, QSignalNames},
         },
         rust::fragment::GeneratedRustFragment,
     },
@@ -22,12 +22,13 @@ use syn::{parse_quote, parse_quote_spanned, FnArg, Ident, Result, Type};
 
 pub fn generate_rust_signal(
     signal: &ParsedSignal,
+    overloaded: bool,
     qobject_name: &Name,
     type_names: &TypeNames,
     unsafety_block: Option<TokenStream>,
 ) -> Result<GeneratedRustFragment> {
     let span = signal.method.span();
-    let idents = QSignalNames::from(signal);
+    let idents = QSignalNames::new(signal, overloaded);
     let idents_helper = QSignalHelperNames::new(&idents, qobject_name)?;
 
     let qobject_name_rust = qobject_name.rust_unqualified();
@@ -248,8 +249,11 @@ pub fn generate_rust_signal(
     })
 }
 
+/// Generate the given signals of a QObject, `signal_names` are the names of all of its signals
+/// including the NOTIFY signals of properties, so that overloads can be detected
 pub fn generate_rust_signals(
     signals: &[&ParsedSignal],
+    signal_names: &[&Name],
     qobject_names: &QObjectNames,
     type_names: &TypeNames,
 ) -> Result<GeneratedRustFragment> {
@@ -258,6 +262,7 @@ pub fn generate_rust_signals(
         .map(|signal| {
             generate_rust_signal(
                 signal,
+                is_overloaded(&signal.name, signal_names.iter().copied()),
                 &qobject_names.name,
                 type_names,
                 // When generating from a RustQt block we use the opposite of the signal safety
@@ -414,11 +419,13 @@ mod tests {
         let type_names = TypeNames::mock();
 
         let qobject_names = create_qobjectname();
-        let generated = generate_rust_signals(&[&qsignal], &qobject_names, &type_names).unwrap();
+        let generated =
+            generate_rust_signals(&[&qsignal], &[], &qobject_names, &type_names).unwrap();
 
         let qobject_name = type_names.lookup(&qsignal.qobject_ident).unwrap().clone();
         let other_generated = generate_rust_signal(
             &qsignal,
+            false,
             &qobject_name,
             &type_names,
             Some(quote! { unsafe }),
@@ -454,7 +461,8 @@ mod tests {
 
         let mut type_names = TypeNames::mock();
         type_names.mock_insert("QColor", None, None, None);
-        let generated = generate_rust_signals(&[&qsignal], &qobject_names, &type_names).unwrap();
+        let generated =
+            generate_rust_signals(&[&qsignal], &[], &qobject_names, &type_names).unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 3);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 8);
@@ -597,7 +605,8 @@ mod tests {
 
         let mut type_names = TypeNames::mock();
         type_names.mock_insert("T", None, None, None);
-        let generated = generate_rust_signals(&[&qsignal], &qobject_names, &type_names).unwrap();
+        let generated =
+            generate_rust_signals(&[&qsignal], &[], &qobject_names, &type_names).unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 3);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 8);
@@ -742,7 +751,7 @@ mod tests {
         let qobject_names = create_qobjectname();
 
         let generated =
-            generate_rust_signals(&[&qsignal], &qobject_names, &TypeNames::mock()).unwrap();
+            generate_rust_signals(&[&qsignal], &[], &qobject_names, &TypeNames::mock()).unwrap();
 
         assert_eq!(generated.cxx_mod_contents.len(), 3);
         assert_eq!(generated.cxx_qt_mod_contents.len(), 8);
@@ -892,6 +901,7 @@ mod tests {
         let qobject_name = type_names.lookup(&qsignal.qobject_ident).unwrap().clone();
         let generated = generate_rust_signal(
             &qsignal,
+            false,
             &qobject_name,
             &type_names,
             Some(quote! { unsafe }),
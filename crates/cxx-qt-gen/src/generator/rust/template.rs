@@ -0,0 +1,230 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    generator::rust::fragment::GeneratedRustFragment,
+    naming::{
+        rust::{syn_type_cxx_bridge_to_qualified, syn_type_cxx_bridge_to_qualified_generic},
+        TypeNames,
+    },
+    parser::template::ParsedTemplate,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Error, FnArg, ForeignItemFn, Ident, Item, Pat,
+    Result, ReturnType, Signature,
+};
+
+/// Generate the aliases for the Rust structs of the instantiations, the trait for the methods
+/// of the template, and the methods of each instantiation which forward to the trait
+pub fn generate(
+    template: &ParsedTemplate,
+    type_names: &TypeNames,
+) -> Result<GeneratedRustFragment> {
+    let mut cxx_qt_mod_contents: Vec<Item> = template
+        .instances
+        .iter()
+        .map(|instance| Item::Type(instance.rust_type.clone()))
+        .collect();
+
+    let ident = &template.ident;
+    let vis = &template.vis;
+    let params = &template.params;
+    // The type parameters and the QObject itself are left unqualified in the trait
+    let generics = params
+        .iter()
+        .cloned()
+        .chain(std::iter::once(format_ident!("Self")))
+        .collect::<Vec<_>>();
+
+    let trait_fns = template
+        .methods
+        .iter()
+        .map(|method| {
+            let attrs = docs_and_cfgs(&method.attrs);
+            let signature = qualified_signature(&method.sig, type_names, &generics)?;
+            Ok(quote! {
+                #(#attrs)*
+                #signature;
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    cxx_qt_mod_contents.push(parse_quote! {
+        #[doc = "The methods of the QObject template, which are shared by all instantiations"]
+        #vis trait #ident<#(#params),*> {
+            #(#trait_fns)*
+        }
+    });
+
+    let self_generics = [format_ident!("Self")];
+    for instance in &template.instances {
+        let instance_ident = &instance.ident;
+        let instance_vis = &instance.vis;
+        let qobject_qualified = type_names.rust_qualified(instance_ident)?;
+        let arguments = instance
+            .arguments
+            .iter()
+            .map(|argument| syn_type_cxx_bridge_to_qualified(argument, type_names))
+            .collect::<Result<Vec<_>>>()?;
+
+        let forwarding_fns = instance
+            .methods
+            .iter()
+            .map(|method| {
+                let attrs = docs_and_cfgs(&method.attrs);
+                let signature = qualified_signature(&method.sig, type_names, &self_generics)?;
+                let method_ident = &method.sig.ident;
+                let call_parameters = call_parameters(method)?;
+                let call = quote! {
+                    <Self as #ident<#(#arguments),*>>::#method_ident(self #(, #call_parameters)*)
+                };
+                let body = if method.sig.unsafety.is_some() {
+                    quote! { unsafe { #call } }
+                } else {
+                    call
+                };
+                Ok(quote! {
+                    #(#attrs)*
+                    #instance_vis #signature {
+                        #body
+                    }
+                })
+            })
+            .collect::<Result<Vec<TokenStream>>>()?;
+
+        if !forwarding_fns.is_empty() {
+            cxx_qt_mod_contents.push(parse_quote! {
+                impl #qobject_qualified {
+                    #(#forwarding_fns)*
+                }
+            });
+        }
+    }
+
+    Ok(GeneratedRustFragment {
+        cxx_qt_mod_contents,
+        ..Default::default()
+    })
+}
+
+/// The doc comments and cfgs of a method, which are kept on the trait and forwarding methods
+fn docs_and_cfgs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc") || attr.path().is_ident("cfg"))
+        .collect()
+}
+
+/// The signature of a method, with all types qualified for use outside of the bridge
+fn qualified_signature(
+    sig: &Signature,
+    type_names: &TypeNames,
+    generics: &[Ident],
+) -> Result<Signature> {
+    let mut sig = sig.clone();
+    for input in sig.inputs.iter_mut() {
+        match input {
+            FnArg::Receiver(receiver) => {
+                *receiver.ty =
+                    syn_type_cxx_bridge_to_qualified_generic(&receiver.ty, type_names, generics)?;
+            }
+            FnArg::Typed(pat_type) => {
+                *pat_type.ty =
+                    syn_type_cxx_bridge_to_qualified_generic(&pat_type.ty, type_names, generics)?;
+            }
+        }
+    }
+    if let ReturnType::Type(_, ty) = &mut sig.output {
+        **ty = syn_type_cxx_bridge_to_qualified_generic(ty, type_names, generics)?;
+    }
+    Ok(sig)
+}
+
+/// The parameters which are passed from the forwarding method to the trait
+fn call_parameters(method: &ForeignItemFn) -> Result<Vec<&Ident>> {
+    method
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(pat_type) => Some(pat_type),
+            FnArg::Receiver(_) => None,
+        })
+        .map(|pat_type| match &*pat_type.pat {
+            Pat::Ident(pat_ident) => Ok(&pat_ident.ident),
+            _ => Err(Error::new(
+                pat_type.pat.span(),
+                "Methods of QObject templates must use identifiers as parameter names!",
+            )),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::parser::Parser;
+    use crate::tests::assert_tokens_eq;
+    use syn::ItemMod;
+
+    #[test]
+    fn test_generate_template() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    type QString = cxx_qt_lib::QString;
+                }
+
+                extern "RustQt" {
+                    #[qobject]
+                    type Model<T> = super::ModelRust<T>;
+
+                    /// Store a value
+                    #[qinvokable]
+                    fn store(self: Pin<&mut Model<T>>, value: T, name: &QString) -> bool;
+
+                    #[qsignal]
+                    fn stored(self: Pin<&mut Model<T>>, value: T);
+                }
+
+                type IntModel = Model<i32>;
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        let generated = generate(&parser.cxx_qt_data.templates[0], &parser.type_names).unwrap();
+
+        assert_eq!(generated.cxx_qt_mod_contents.len(), 3);
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[0],
+            quote! {
+                type IntModelRust = ModelRust<i32>;
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[1],
+            quote! {
+                #[doc = "The methods of the QObject template, which are shared by all instantiations"]
+                trait Model<T> {
+                    /// Store a value
+                    fn store(self: core::pin::Pin<&mut Self>, value: T, name: &ffi::QString) -> bool;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &generated.cxx_qt_mod_contents[2],
+            quote! {
+                impl ffi::IntModel {
+                    /// Store a value
+                    fn store(self: core::pin::Pin<&mut Self>, value: i32, name: &ffi::QString) -> bool {
+                        <Self as Model<i32> >::store(self, value, name)
+                    }
+                }
+            },
+        );
+    }
+}
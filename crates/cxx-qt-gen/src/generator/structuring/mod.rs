@@ -12,12 +12,15 @@
 /// This mostly means grouping QObjects with their QEnums, QSignals, etc...
 ///
 /// All resulting structures are listed in the `Structures` struct.
+pub mod qnamespace;
 pub mod qobject;
 
 use crate::parser::{
     cxxqtdata::ParsedCxxQtData,
+    qinterface::ParsedQInterface,
     trait_impl::{TraitImpl, TraitKind},
 };
+pub use qnamespace::StructuredQNamespace;
 pub use qobject::StructuredQObject;
 use syn::{Error, Ident, Result};
 
@@ -26,6 +29,8 @@ use syn::{Error, Ident, Result};
 pub struct Structures<'a> {
     /// The list of qobjects
     pub qobjects: Vec<StructuredQObject<'a>>,
+    /// The list of qnamespaces
+    pub qnamespaces: Vec<StructuredQNamespace<'a>>,
 }
 
 /// Error for looking up against a QObject which isn't registered in Structures
@@ -51,6 +56,7 @@ impl<'a> Structures<'a> {
     fn structure_trait_impls(
         qobjects: &mut [StructuredQObject<'a>],
         trait_impls: &'a [TraitImpl],
+        qinterfaces: &'a [ParsedQInterface],
     ) -> Result<()> {
         // Associate each trait impl with its appropriate qobject
         for imp in trait_impls {
@@ -68,8 +74,50 @@ impl<'a> Structures<'a> {
                     }
                     qobject.threading = true;
                 }
+                TraitKind::Finalize => {
+                    if qobject.finalize {
+                        return Err(Error::new_spanned(
+                            &imp.declaration,
+                            format!(
+                                "Finalize already declared on QObject {qobject}!",
+                                qobject = imp.qobject
+                            ),
+                        ));
+                    }
+                    qobject.finalize = true;
+                }
                 // TODO: Check for duplicate declarations?
                 TraitKind::Constructor(ref constructor) => qobject.constructors.push(constructor),
+                TraitKind::Interface(ref interface_ident) => {
+                    let interface = qinterfaces
+                        .iter()
+                        .find(|interface| interface.name.rust_unqualified() == interface_ident)
+                        .ok_or_else(|| not_found_error("#[qinterface]", interface_ident))?;
+                    // The interface becomes the C++ base class of the QObject, and as every
+                    // interface derives from QObject, it can only have one of them
+                    if let Some(other) = qobject.interface {
+                        return Err(Error::new_spanned(
+                            &imp.declaration,
+                            format!(
+                                "QObject {qobject} already implements the #[qinterface] {other}, a QObject can only implement one #[qinterface]!\n\
+                                 Each #[qinterface] is a C++ base class deriving from QObject, so implementing {interface_ident} as well is not supported.",
+                                qobject = imp.qobject,
+                                other = other.name.rust_unqualified(),
+                            ),
+                        ));
+                    }
+                    if let Some(base_class) = &qobject.declaration.base_class {
+                        return Err(Error::new_spanned(
+                            base_class,
+                            format!(
+                                "QObject {qobject} implements the #[qinterface] {interface_ident}, so it cannot have a #[base] attribute!\n\
+                                 The #[qinterface] becomes the C++ base class, which always derives from QObject, so it can not be combined with a base class such as QAbstractListModel.",
+                                qobject = imp.qobject
+                            ),
+                        ));
+                    }
+                    qobject.interface = Some(interface);
+                }
             }
         }
         Ok(())
@@ -109,9 +157,41 @@ impl<'a> Structures<'a> {
             qobject.signals.push(signal);
         }
 
-        Self::structure_trait_impls(&mut qobjects, &cxxqtdata.trait_impls)?;
+        Self::structure_trait_impls(
+            &mut qobjects,
+            &cxxqtdata.trait_impls,
+            &cxxqtdata.qinterfaces,
+        )?;
+
+        for qobject in &qobjects {
+            qobject.check_overloads()?;
+        }
+
+        let mut qnamespaces: Vec<_> = cxxqtdata
+            .qnamespaces
+            .iter()
+            .map(StructuredQNamespace::from_qnamespace)
+            .collect();
+
+        // Associate each free function with the qnamespace! of its namespace
+        for function in cxxqtdata.functions() {
+            let namespace = function.name.namespace().unwrap_or_default();
+            let qnamespace = qnamespaces
+                .iter_mut()
+                .find(|qnamespace| qnamespace.declaration.namespace == namespace)
+                .ok_or_else(|| {
+                    Error::new_spanned(
+                        &function.method.sig.ident,
+                        format!("The namespace `{namespace}` of a free #[qinvokable] function must be declared with qnamespace!"),
+                    )
+                })?;
+            qnamespace.functions.push(function);
+        }
 
-        Ok(Structures { qobjects })
+        Ok(Structures {
+            qobjects,
+            qnamespaces,
+        })
     }
 }
 
@@ -263,6 +343,98 @@ mod tests {
         assert!(Structures::new(&parser.cxx_qt_data).is_err());
     }
 
+    #[test]
+    fn test_duplicate_finalize_impl() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {impl cxx_qt::Finalize for MyObject {}},
+            parse_quote! {impl cxx_qt::Finalize for MyObject {}},
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        assert!(Structures::new(&parser.cxx_qt_data).is_err());
+    }
+
+    #[test]
+    fn test_qnamespace_functions() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! { qnamespace!("utils"); },
+            parse_quote! {
+                extern "RustQt" {
+                    #[qinvokable]
+                    #[namespace = "utils"]
+                    fn helper();
+
+                    #[qinvokable]
+                    #[Self = "MyObject"]
+                    fn create_default() -> i32;
+                }
+            },
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        assert_eq!(structures.qnamespaces.len(), 1);
+        assert_eq!(structures.qnamespaces[0].functions.len(), 1);
+        assert_eq!(
+            structures.qnamespaces[0].functions[0]
+                .name
+                .rust_unqualified(),
+            "helper"
+        );
+        assert_eq!(structures.qobjects[0].methods.len(), 1);
+        assert!(structures.qobjects[0].methods[0].is_static);
+    }
+
+    #[test]
+    fn test_overloads() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([parse_quote! {
+            extern "RustQt" {
+                #[qinvokable]
+                #[cxx_name = "setValue"]
+                fn set_value_int(self: Pin<&mut MyObject>, value: i32);
+
+                #[qinvokable]
+                #[cxx_name = "setValue"]
+                fn set_value_string(self: Pin<&mut MyObject>, value: &QString);
+
+                #[qsignal]
+                #[cxx_name = "valueChanged"]
+                fn value_changed_int(self: Pin<&mut MyObject>, value: i32);
+
+                #[qsignal]
+                #[cxx_name = "valueChanged"]
+                fn value_changed_string(self: Pin<&mut MyObject>, value: &QString);
+            }
+        }]);
+        let parser = Parser::from(bridge).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        assert_eq!(structures.qobjects[0].methods.len(), 2);
+        assert_eq!(structures.qobjects[0].signals.len(), 2);
+    }
+
+    #[test]
+    fn test_interface_impl() {
+        let mut bridge = mock_bridge();
+        bridge.content.as_mut().unwrap().1.extend([
+            parse_quote! {
+                #[qinterface]
+                trait Device {
+                    fn connect(self: Pin<&mut Self>);
+                }
+            },
+            parse_quote! {impl Device for MyObject {}},
+        ]);
+        let parser = Parser::from(bridge).unwrap();
+        let structures = Structures::new(&parser.cxx_qt_data).unwrap();
+        let my_object = &structures.qobjects[0];
+        assert_eq!(
+            my_object.interface.unwrap().name.rust_unqualified(),
+            "Device"
+        );
+        assert_eq!(my_object.base_class().unwrap(), "Device");
+    }
+
     #[test]
     fn test_create_invalid_structures() {
         assert_parse_errors! {
@@ -319,6 +491,43 @@ mod tests {
                 }
             }
 
+            {
+                // Multiple interfaces
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    #[qinterface]
+                    trait Device {}
+
+                    #[qinterface]
+                    trait Printer {}
+
+                    impl Device for MyObject {}
+                    impl Printer for MyObject {}
+                }
+            }
+
+            {
+                // Interface with a base class
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[base = QAbstractListModel]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    #[qinterface]
+                    trait Device {}
+
+                    impl Device for MyObject {}
+                }
+            }
+
             {
                 // Undeclared QObject for inherited method
                 #[cxx_qt::bridge]
@@ -329,6 +538,72 @@ mod tests {
                     }
                 }
             }
+
+            {
+                // Undeclared qnamespace for free function
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qinvokable]
+                        #[namespace = "utils"]
+                        fn helper();
+                    }
+                }
+            }
+
+            {
+                // Overloads with the same parameter types
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        #[cxx_name = "setValue"]
+                        fn set_value_int(self: Pin<&mut MyObject>, value: i32);
+
+                        #[qinvokable]
+                        #[cxx_name = "setValue"]
+                        fn set_value_other(self: Pin<&mut MyObject>, other: i32);
+                    }
+                }
+            }
+
+            {
+                // Overload of a NOTIFY signal with the same parameter types
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        #[qproperty(i32, value)]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qsignal]
+                        #[cxx_name = "valueChanged"]
+                        fn value_updated(self: Pin<&mut MyObject>);
+                    }
+                }
+            }
+
+            {
+                // Overload which is generated for a parameter with a default value
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+
+                        #[qinvokable]
+                        #[cxx_name = "reset"]
+                        fn reset(self: Pin<&mut MyObject>);
+
+                        #[qinvokable]
+                        #[cxx_name = "reset"]
+                        fn reset_to(self: Pin<&mut MyObject>, #[default = 0] value: i32);
+                    }
+                }
+            }
         }
     }
 }
@@ -0,0 +1,22 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::parser::{function::ParsedFunction, qnamespace::ParsedQNamespace};
+
+/// The StructuredQNamespace contains the parsed qnamespace! and the free functions
+/// which were declared in its namespace.
+pub struct StructuredQNamespace<'a> {
+    pub declaration: &'a ParsedQNamespace,
+    pub functions: Vec<&'a ParsedFunction>,
+}
+
+impl<'a> StructuredQNamespace<'a> {
+    /// Creates a [StructuredQNamespace] from a [ParsedQNamespace] without any functions
+    pub fn from_qnamespace(qnamespace: &'a ParsedQNamespace) -> Self {
+        Self {
+            declaration: qnamespace,
+            functions: vec![],
+        }
+    }
+}
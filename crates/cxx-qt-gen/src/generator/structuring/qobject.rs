@@ -7,11 +7,15 @@ use crate::generator::structuring::not_found_error;
 use crate::naming::Name;
 use crate::parser::constructor::Constructor;
 use crate::parser::inherit::ParsedInheritedMethod;
+use crate::parser::method::MethodFields;
 use crate::parser::method::ParsedMethod;
+use crate::parser::parameter::ParsedFunctionParameter;
+use crate::parser::qinterface::ParsedQInterface;
 use crate::parser::signals::ParsedSignal;
 use crate::parser::{qenum::ParsedQEnum, qobject::ParsedQObject};
 use proc_macro2::Ident;
-use syn::Result;
+use quote::ToTokens;
+use syn::{Error, Result};
 
 /// The StructuredQObject contains the parsed QObject and all members.
 /// This includes QEnums, QSignals, methods, etc.
@@ -25,6 +29,8 @@ pub struct StructuredQObject<'a> {
     pub pending_methods: Vec<Name>,
     pub pending_signals: Vec<Name>,
     pub threading: bool,
+    pub finalize: bool,
+    pub interface: Option<&'a ParsedQInterface>,
 }
 
 fn lookup<T>(invokables: &[T], id: &Ident, name_getter: impl Fn(&T) -> &Name) -> Option<Name> {
@@ -64,9 +70,20 @@ impl<'a> StructuredQObject<'a> {
             pending_methods,
             pending_signals,
             threading: false,
+            finalize: false,
+            interface: None,
         }
     }
 
+    /// Returns the ident of the class the QObject derives from, which is the interface if it implements one
+    ///
+    /// If this is `None` the QObject derives from QObject
+    pub fn base_class(&self) -> Option<&Ident> {
+        self.interface
+            .map(|interface| interface.name.rust_unqualified())
+            .or(self.declaration.base_class.as_ref())
+    }
+
     /// Returns the name of the method with the provided Rust ident if it exists, or an error
     pub fn method_lookup(&self, id: &Ident) -> Result<Name> {
         lookup(&self.methods, id, |method| &method.name)
@@ -82,6 +99,63 @@ impl<'a> StructuredQObject<'a> {
             .ok_or_else(|| not_found_error("Signal", id))
     }
 
+    /// The names of all signals of the QObject, including the NOTIFY signals generated for properties
+    pub fn signal_names(&self) -> Vec<&Name> {
+        self.signals
+            .iter()
+            .map(|signal| &signal.name)
+            .chain(self.pending_signals.iter())
+            .collect()
+    }
+
+    /// Methods and signals may share a C++ name to overload each other,
+    /// as long as the C++ compiler can tell them apart by their parameter types
+    pub fn check_overloads(&self) -> Result<()> {
+        // Every trailing parameter with a default value adds an overload without it
+        fn signatures(fields: &MethodFields) -> Vec<(&Name, Vec<String>)> {
+            let types: Vec<String> = fields
+                .parameters
+                .iter()
+                .map(|parameter| parameter.ty.to_token_stream().to_string())
+                .collect();
+            let shortest =
+                ParsedFunctionParameter::first_default(&fields.parameters).unwrap_or(types.len());
+            (shortest..=types.len())
+                .map(|len| (&fields.name, types[..len].to_vec()))
+                .collect()
+        }
+        // The NOTIFY signals generated for properties have no parameters
+        let overloads: Vec<(&Name, Vec<String>)> = self
+            .pending_signals
+            .iter()
+            .map(|name| (name, vec![]))
+            .chain(
+                self.methods
+                    .iter()
+                    .map(|method| &method.method_fields)
+                    .chain(self.signals.iter().map(|signal| &signal.method_fields))
+                    .flat_map(signatures),
+            )
+            .collect();
+
+        for (index, (overload, types)) in overloads.iter().enumerate() {
+            let name = overload.cxx_unqualified();
+            if overloads[..index]
+                .iter()
+                .any(|(other, other_types)| other.cxx_unqualified() == name && other_types == types)
+            {
+                return Err(Error::new_spanned(
+                    overload.rust_unqualified(),
+                    format!(
+                        "Overloads of `{name}` on QObject {qobject} must have different parameter types!",
+                        qobject = self.declaration.name.rust_unqualified()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     #[cfg(test)]
     pub fn mock(obj: &'a ParsedQObject) -> Self {
         Self::from_qobject(obj)
@@ -9,20 +9,32 @@
 //! The cxx-qt-gen crate provides methods for generated C++ and Rust code from a TokenStream.
 
 mod generator;
+mod lint;
+mod metadata;
+mod metatypes;
 mod naming;
 mod parser;
 mod shorthand;
 mod syntax;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 mod writer;
 
 pub use generator::{
+    cfg::eval_cfg_attributes,
     cpp::{fragment::CppFragment, GeneratedCppBlocks},
     rust::GeneratedRustBlocks,
     GeneratedOpt,
 };
+pub use lint::{lint, Lint, LintConfig, LintDiagnostic, LintLevel, LINT_CONFIG_ENV_VAR};
+pub use metadata::{
+    metadata_env_var, BridgeMetadata, MetadataPaths, MethodMetadata, ParameterMetadata,
+    PropertyMetadata, QObjectMetadata,
+};
+pub use metatypes::{metatypes_json, write_qmltypes};
 pub use parser::Parser;
 pub use shorthand::self_inlining;
-pub use syntax::{parse_qt_file, CxxQtFile, CxxQtItem};
+pub use syntax::{parse_qt_file, suggestion::Suggestion, CxxQtFile, CxxQtItem};
 pub use writer::{cpp::write_cpp, rust::write_rust};
 
 pub use syn::{Error, Result};
@@ -186,6 +198,7 @@ mod tests {
 
         let opt = GeneratedOpt {
             cfg_evaluator: Box::new(cfg_evaluator),
+            file_ident: "directory/file_ident".to_owned(),
         };
         let generated_cpp = GeneratedCppBlocks::from(&parser, &opt).unwrap();
         let (mut header, mut source) =
@@ -256,6 +269,26 @@ mod tests {
         test_code_generation!("qenum");
     }
 
+    #[test]
+    fn generates_qinterface() {
+        test_code_generation!("qinterface");
+    }
+
+    #[test]
+    fn generates_templates() {
+        test_code_generation!("templates");
+    }
+
+    #[test]
+    fn generates_instantiate() {
+        test_code_generation!("instantiate");
+    }
+
+    #[test]
+    fn generates_overloads() {
+        test_code_generation!("overloads");
+    }
+
     #[test]
     #[should_panic]
     fn fail_token_assert() {
@@ -0,0 +1,454 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Lints for bridges which are valid, but are likely to misbehave at runtime.
+//!
+//! Like rustc lints, each [Lint] has a [LintLevel] which can be configured with a [LintConfig].
+//! Lints are opt-in, every lint is allowed unless it is enabled in the [LintConfig].
+//! `cxx-qt-build` reports the lints of a bridge as warnings of the build script,
+//! the `#[cxx_qt::bridge]` macro can only report lints which are denied, as compile errors.
+
+use crate::{
+    generator::structuring::{StructuredQObject, Structures},
+    parser::{method::ParsedMethod, property::FlagState, Parser},
+};
+use proc_macro2::Span;
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+use syn::{spanned::Spanned, Error, ReturnType, Type};
+
+/// The name of the environment variable through which `cxx-qt-build` passes the [LintConfig]
+/// of a crate to the `#[cxx_qt::bridge]` macro
+pub const LINT_CONFIG_ENV_VAR: &str = "CXX_QT_LINTS";
+
+/// A check for a common mistake in a bridge
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Lint {
+    /// A `#[qinvokable]` with a `Pin<&mut Self>` receiver which returns a value
+    ///
+    /// The method is generated as a non-const C++ method, so it can not be called on a const
+    /// object or used as the READ function of a property. Getters should take `&self` instead.
+    MutableInvokable,
+    /// A property with a raw pointer type and without a NOTIFY signal
+    ///
+    /// QML can not know when the pointed to object is replaced, so bindings keep using the old one.
+    RawPointerProperty,
+    /// An `unsafe fn` which is a `#[qinvokable]`
+    ///
+    /// QML and C++ callers can call it without upholding its safety requirements.
+    UnsafeInvokable,
+    /// A creatable `#[qml_element]` without a default constructor
+    ///
+    /// The QML engine can only create objects with a default constructor.
+    QmlElementWithoutDefaultConstructor,
+}
+
+impl Lint {
+    /// All lints which are checked by [lint]
+    pub const ALL: [Lint; 4] = [
+        Lint::MutableInvokable,
+        Lint::RawPointerProperty,
+        Lint::UnsafeInvokable,
+        Lint::QmlElementWithoutDefaultConstructor,
+    ];
+
+    /// The name of the lint, as used in a [LintConfig]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::MutableInvokable => "mutable_invokable",
+            Lint::RawPointerProperty => "raw_pointer_property",
+            Lint::UnsafeInvokable => "unsafe_invokable",
+            Lint::QmlElementWithoutDefaultConstructor => "qml_element_without_default_constructor",
+        }
+    }
+
+    /// Find a lint by its name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// How a [Lint] is reported
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LintLevel {
+    /// The lint is not checked, this is the default
+    #[default]
+    Allow,
+    /// The lint is reported as a warning
+    Warn,
+    /// The lint is reported as an error
+    Deny,
+}
+
+impl LintLevel {
+    /// The name of the level, as used in a [LintConfig]
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+        }
+    }
+}
+
+/// The [LintLevel] of each [Lint], every lint is allowed unless configured otherwise
+///
+/// ```
+/// use cxx_qt_gen::{Lint, LintConfig, LintLevel};
+///
+/// let config = LintConfig::default()
+///     .warn(Lint::RawPointerProperty)
+///     .deny(Lint::UnsafeInvokable);
+/// assert_eq!(config.level(Lint::UnsafeInvokable), LintLevel::Deny);
+/// assert_eq!(config.level(Lint::MutableInvokable), LintLevel::Allow);
+/// assert_eq!(config.to_string(), "raw_pointer_property=warn,unsafe_invokable=deny");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LintConfig {
+    levels: BTreeMap<Lint, LintLevel>,
+}
+
+impl LintConfig {
+    /// Set the level of a lint
+    pub fn set(mut self, lint: Lint, level: LintLevel) -> Self {
+        self.levels.insert(lint, level);
+        self
+    }
+
+    /// Do not check the given lint
+    pub fn allow(self, lint: Lint) -> Self {
+        self.set(lint, LintLevel::Allow)
+    }
+
+    /// Report the given lint as a warning
+    pub fn warn(self, lint: Lint) -> Self {
+        self.set(lint, LintLevel::Warn)
+    }
+
+    /// Report the given lint as an error
+    pub fn deny(self, lint: Lint) -> Self {
+        self.set(lint, LintLevel::Deny)
+    }
+
+    /// The level of the given lint
+    pub fn level(&self, lint: Lint) -> LintLevel {
+        self.levels.get(&lint).copied().unwrap_or_default()
+    }
+
+    /// Read the config passed by `cxx-qt-build` in the [LINT_CONFIG_ENV_VAR] environment variable
+    ///
+    /// Without the variable, or if it is invalid, the default config is used.
+    pub fn from_env() -> Self {
+        std::env::var(LINT_CONFIG_ENV_VAR)
+            .ok()
+            .and_then(|config| config.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl Display for LintConfig {
+    /// Write the config as a comma separated list of `lint=level`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let levels = self
+            .levels
+            .iter()
+            .map(|(lint, level)| format!("{lint}={}", level.name()))
+            .collect::<Vec<_>>();
+        write!(f, "{}", levels.join(","))
+    }
+}
+
+impl FromStr for LintConfig {
+    type Err = String;
+
+    /// Parse a comma separated list of `lint=level`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|entry| !entry.is_empty())
+            .try_fold(Self::default(), |config, entry| {
+                let (name, level) = entry
+                    .split_once('=')
+                    .ok_or_else(|| format!("Expected `lint=level`, found `{entry}`"))?;
+                let lint = Lint::from_name(name).ok_or_else(|| format!("Unknown lint `{name}`"))?;
+                let level = match level {
+                    "allow" => LintLevel::Allow,
+                    "warn" => LintLevel::Warn,
+                    "deny" => LintLevel::Deny,
+                    _ => return Err(format!("Unknown lint level `{level}`")),
+                };
+                Ok(config.set(lint, level))
+            })
+    }
+}
+
+/// A [Lint] found in a bridge
+#[derive(Clone, Debug)]
+pub struct LintDiagnostic {
+    /// The lint which was found
+    pub lint: Lint,
+    /// The configured level of the lint, this is never [LintLevel::Allow]
+    pub level: LintLevel,
+    /// The span of the code which the lint was found in
+    pub span: Span,
+    /// A description of the problem
+    pub message: String,
+}
+
+impl LintDiagnostic {
+    /// Whether the lint is denied and should fail the build
+    pub fn is_error(&self) -> bool {
+        self.level == LintLevel::Deny
+    }
+
+    /// Convert the diagnostic into an error, for reporting it from a proc-macro
+    pub fn to_error(&self) -> Error {
+        Error::new(
+            self.span,
+            format!("{} (cxx_qt lint `{}`)", self.message, self.lint),
+        )
+    }
+}
+
+/// Collects the diagnostics of the lints which are not allowed
+struct Linter<'a> {
+    config: &'a LintConfig,
+    diagnostics: Vec<LintDiagnostic>,
+}
+
+impl Linter<'_> {
+    fn report(&mut self, lint: Lint, span: Span, message: String) {
+        let level = self.config.level(lint);
+        if level != LintLevel::Allow {
+            self.diagnostics.push(LintDiagnostic {
+                lint,
+                level,
+                span,
+                message,
+            });
+        }
+    }
+
+    fn check_invokable(&mut self, method: &ParsedMethod) {
+        if !method.is_qinvokable {
+            return;
+        }
+
+        let sig = &method.method_fields.method.sig;
+        let name = &sig.ident;
+        if !method.method_fields.safe {
+            self.report(
+                Lint::UnsafeInvokable,
+                sig.unsafety.span(),
+                format!("Invokable `{name}` is unsafe, but it can be called from QML and C++ without an unsafe block!"),
+            );
+        }
+        if method.method_fields.mutable && !matches!(sig.output, ReturnType::Default) {
+            self.report(
+                Lint::MutableInvokable,
+                name.span(),
+                format!("Invokable `{name}` takes `Pin<&mut Self>` and returns a value, so it can not be called on a const object. Take `&self` if it does not modify the object!"),
+            );
+        }
+    }
+
+    fn check_qobject(&mut self, qobject: &StructuredQObject) {
+        let declaration = qobject.declaration;
+        let ident = declaration.name.rust_unqualified();
+
+        for property in &declaration.properties {
+            let name = property.name.rust_unqualified();
+            if matches!(property.ty, Type::Ptr(_))
+                && property.flags.notify.is_none()
+                && !property.flags.constant
+            {
+                self.report(
+                    Lint::RawPointerProperty,
+                    property.ty.span(),
+                    format!("Property `{name}` has a raw pointer type, but no NOTIFY signal. QML will not notice when the pointer changes!"),
+                );
+            }
+
+            // A custom getter is called by moc on the object, which should not need to be mutable
+            if let FlagState::Custom(getter) = &property.flags.read {
+                if let Some(method) = qobject
+                    .methods
+                    .iter()
+                    .find(|method| method.name.rust_unqualified() == getter)
+                    .filter(|method| method.method_fields.mutable)
+                {
+                    self.report(
+                        Lint::MutableInvokable,
+                        method.method_fields.method.sig.ident.span(),
+                        format!("Getter `{getter}` of property `{name}` takes `Pin<&mut Self>`, so it can not be called on a const object. Take `&self` instead!"),
+                    );
+                }
+            }
+        }
+
+        if let Some(qml_metadata) = &declaration.qml_metadata {
+            // Without any constructors a default constructor is generated
+            let has_default_constructor = qobject.constructors.is_empty()
+                || qobject
+                    .constructors
+                    .iter()
+                    .any(|constructor| constructor.arguments.is_empty());
+            if !qml_metadata.uncreatable && !has_default_constructor {
+                self.report(
+                    Lint::QmlElementWithoutDefaultConstructor,
+                    ident.span(),
+                    format!("QML element `{ident}` has no default constructor, so it can not be created in QML. Add `impl cxx_qt::Constructor<()> for {ident} {{}}` or mark it as #[qml_uncreatable]!"),
+                );
+            }
+        }
+    }
+}
+
+/// Check a parsed bridge for the lints which are not allowed in the config
+pub fn lint(parser: &Parser, config: &LintConfig) -> syn::Result<Vec<LintDiagnostic>> {
+    let structures = Structures::new(&parser.cxx_qt_data)?;
+    let mut linter = Linter {
+        config,
+        diagnostics: vec![],
+    };
+    for qobject in &structures.qobjects {
+        for method in &qobject.methods {
+            linter.check_invokable(method);
+        }
+        linter.check_qobject(qobject);
+    }
+    Ok(linter.diagnostics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::{parse_quote, ItemMod};
+
+    fn lint_names(module: ItemMod, config: &LintConfig) -> Vec<(Lint, LintLevel)> {
+        let parser = Parser::from(module).unwrap();
+        lint(&parser, config)
+            .unwrap()
+            .into_iter()
+            .map(|diagnostic| (diagnostic.lint, diagnostic.level))
+            .collect()
+    }
+
+    /// A config with every lint enabled as a warning
+    fn warn_all() -> LintConfig {
+        Lint::ALL
+            .into_iter()
+            .fold(LintConfig::default(), |config, lint| config.warn(lint))
+    }
+
+    #[test]
+    fn test_lints() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qproperty(*mut QObject, pointer, READ, WRITE)]
+                    #[qproperty(*mut QObject, notified)]
+                    #[qproperty(i32, number, READ = get_number)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn increment(self: Pin<&mut MyObject>) -> i32;
+
+                    #[qinvokable]
+                    fn reset(self: Pin<&mut MyObject>);
+
+                    #[qinvokable]
+                    unsafe fn raw(self: &MyObject, pointer: *mut QObject);
+
+                    fn get_number(self: Pin<&mut MyObject>) -> i32;
+                }
+
+                impl cxx_qt::Constructor<(i32,)> for MyObject {}
+            }
+        };
+        assert_eq!(
+            lint_names(module, &warn_all().deny(Lint::UnsafeInvokable)),
+            vec![
+                (Lint::MutableInvokable, LintLevel::Warn),
+                (Lint::UnsafeInvokable, LintLevel::Deny),
+                (Lint::RawPointerProperty, LintLevel::Warn),
+                (Lint::MutableInvokable, LintLevel::Warn),
+                (Lint::QmlElementWithoutDefaultConstructor, LintLevel::Warn),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lints_allowed() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    #[qml_uncreatable]
+                    #[qproperty(*mut QObject, pointer, READ, CONSTANT)]
+                    type MyObject = super::MyObjectRust;
+
+                    #[qinvokable]
+                    fn increment(self: Pin<&mut MyObject>) -> i32;
+
+                    #[qinvokable]
+                    fn value(self: &MyObject) -> i32;
+                }
+
+                impl cxx_qt::Constructor<(i32,)> for MyObject {}
+            }
+        };
+        assert!(lint_names(module.clone(), &LintConfig::default()).is_empty());
+        let config = warn_all().allow(Lint::MutableInvokable);
+        assert!(lint_names(module.clone(), &config).is_empty());
+        assert_eq!(
+            lint_names(module, &warn_all()),
+            vec![(Lint::MutableInvokable, LintLevel::Warn)]
+        );
+    }
+
+    #[test]
+    fn test_default_constructor() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qml_element]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                impl cxx_qt::Constructor<(i32,)> for MyObject {}
+                impl cxx_qt::Constructor<()> for MyObject {}
+            }
+        };
+        assert!(lint_names(module, &warn_all()).is_empty());
+    }
+
+    #[test]
+    fn test_lint_config() {
+        let config: LintConfig = "unsafe_invokable=deny,mutable_invokable=allow"
+            .parse()
+            .unwrap();
+        assert_eq!(config.level(Lint::UnsafeInvokable), LintLevel::Deny);
+        assert_eq!(config.level(Lint::MutableInvokable), LintLevel::Allow);
+        assert_eq!(config.level(Lint::RawPointerProperty), LintLevel::Allow);
+        assert_eq!(config.to_string().parse::<LintConfig>().unwrap(), config);
+        assert_eq!("".parse::<LintConfig>().unwrap(), LintConfig::default());
+
+        assert!("unsafe_invokable".parse::<LintConfig>().is_err());
+        assert!("unknown=deny".parse::<LintConfig>().is_err());
+        assert!("unsafe_invokable=forbid".parse::<LintConfig>().is_err());
+    }
+}
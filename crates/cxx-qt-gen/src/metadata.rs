@@ -0,0 +1,540 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Machine-readable metadata describing the QObjects of a bridge.
+//!
+//! The metadata is exported by cxx-qt-build for each crate and allows other crates to import the
+//! QObjects with `#[import = "crate"]` in an `extern "C++Qt"` block, without redeclaring them.
+
+use crate::{
+    generator::{
+        naming::property::{NameState, QPropertyNames},
+        structuring::{StructuredQObject, Structures},
+    },
+    parser::{method::MethodFields, Parser},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+use syn::{parse_quote, ForeignItemFn, Ident, Result, ReturnType, Type};
+
+/// The metadata of a single `#[cxx_qt::bridge]`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BridgeMetadata {
+    /// The path which is used to include the generated C++ header of the bridge
+    pub include: String,
+    /// The QObjects declared in the bridge
+    pub qobjects: Vec<QObjectMetadata>,
+}
+
+/// The metadata of a QObject declared in a bridge
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QObjectMetadata {
+    /// The name of the QObject in Rust
+    pub rust_name: String,
+    /// The name of the QObject in C++
+    pub cxx_name: String,
+    /// The C++ namespace of the QObject, empty for the global namespace
+    pub namespace: String,
+    /// The properties of the QObject
+    pub properties: Vec<PropertyMetadata>,
+    /// The methods of the QObject, including any generated property accessors
+    pub methods: Vec<MethodMetadata>,
+    /// The signals of the QObject, including any generated property notify signals
+    pub signals: Vec<MethodMetadata>,
+}
+
+/// The metadata of a Q_PROPERTY
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PropertyMetadata {
+    /// The name of the property in Rust
+    pub rust_name: String,
+    /// The name of the property in C++
+    pub cxx_name: String,
+    /// The Rust type of the property, as written in the bridge
+    pub ty: String,
+    /// The C++ name of the getter
+    pub read: String,
+    /// The C++ name of the setter, if the property is writable
+    pub write: Option<String>,
+    /// The C++ name of the notify signal, if there is one
+    pub notify: Option<String>,
+    /// The C++ name of the reset method, if there is one
+    pub reset: Option<String>,
+}
+
+/// The metadata of a method or signal
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MethodMetadata {
+    /// The name of the method in Rust
+    pub rust_name: String,
+    /// The name of the method in C++
+    pub cxx_name: String,
+    /// Whether the method takes `self: Pin<&mut T>` rather than `&T`
+    pub mutable: bool,
+    /// Whether the method is safe to call
+    pub safe: bool,
+    /// Whether the method is a `#[qinvokable]`
+    pub invokable: bool,
+    /// The parameters of the method, excluding self
+    pub parameters: Vec<ParameterMetadata>,
+    /// The Rust return type of the method, as written in the bridge
+    pub return_type: Option<String>,
+}
+
+/// The metadata of a parameter of a method or signal
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterMetadata {
+    /// The name of the parameter
+    pub name: String,
+    /// The Rust type of the parameter, as written in the bridge
+    pub ty: String,
+}
+
+/// The name of the environment variable which contains the path to the exported metadata of the
+/// given crate
+///
+/// This is set by cxx-qt-build for all dependencies which export an Interface.
+pub fn metadata_env_var(crate_name: &str) -> String {
+    format!(
+        "CXX_QT_METADATA_{}",
+        crate_name.to_ascii_uppercase().replace('-', "_")
+    )
+}
+
+/// The paths to the metadata exported by other crates, used to expand `#[import = "crate"]`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MetadataPaths {
+    paths: BTreeMap<String, PathBuf>,
+    from_env: bool,
+}
+
+impl MetadataPaths {
+    /// Find the metadata of crates in the environment variables set by cxx-qt-build,
+    /// see [metadata_env_var]
+    pub fn from_env() -> Self {
+        Self {
+            paths: BTreeMap::new(),
+            from_env: true,
+        }
+    }
+
+    /// Set the path to the metadata exported by the given crate
+    pub fn insert(&mut self, crate_name: impl Into<String>, path: impl Into<PathBuf>) {
+        self.paths.insert(crate_name.into(), path.into());
+    }
+
+    /// The path to the metadata exported by the given crate, if it is known
+    pub fn get(&self, crate_name: &str) -> Option<PathBuf> {
+        self.paths.get(crate_name).cloned().or_else(|| {
+            self.from_env
+                .then(|| std::env::var_os(metadata_env_var(crate_name)))
+                .flatten()
+                .map(PathBuf::from)
+        })
+    }
+
+    /// The paths which have been set with [Self::insert]
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.paths.values().map(PathBuf::as_path)
+    }
+}
+
+fn type_to_string(ty: &Type) -> String {
+    ty.to_token_stream().to_string()
+}
+
+fn parse_type(ty: &str) -> Result<Type> {
+    syn::parse_str(ty)
+}
+
+impl BridgeMetadata {
+    /// Create the metadata for a parsed bridge, whose C++ header is included with the given path
+    pub fn from_parser(parser: &Parser, include: &str) -> Result<Self> {
+        let structures = Structures::new(&parser.cxx_qt_data)?;
+        let qobjects = structures
+            .qobjects
+            .iter()
+            .map(QObjectMetadata::from_structured_qobject)
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            include: include.to_owned(),
+            qobjects,
+        })
+    }
+
+    /// Read the metadata of all bridges exported by a crate
+    pub fn load_exported(
+        crate_name: &str,
+        paths: &MetadataPaths,
+    ) -> std::result::Result<Vec<Self>, String> {
+        let path = paths.get(crate_name).ok_or_else(|| {
+            if paths.from_env {
+                format!("No metadata found for crate `{crate_name}`, ensure that it is a dependency which exports an Interface with cxx-qt-build ({} is not set)", metadata_env_var(crate_name))
+            } else {
+                format!("No metadata given for crate `{crate_name}`, the path to the metadata exported by the crate is required to import from it")
+            }
+        })?;
+        let contents = std::fs::read_to_string(&path).map_err(|err| {
+            format!(
+                "Could not read the metadata of crate `{crate_name}` at {}: {err}",
+                path.display()
+            )
+        })?;
+        serde_json::from_str(&contents)
+            .map_err(|err| format!("Could not parse the metadata of crate `{crate_name}`: {err}"))
+    }
+}
+
+impl QObjectMetadata {
+    fn from_structured_qobject(structured_qobject: &StructuredQObject) -> Result<Self> {
+        let name = &structured_qobject.declaration.name;
+        let qobject_ident = name.rust_unqualified();
+
+        let mut methods = structured_qobject
+            .methods
+            .iter()
+            // Static methods cannot be declared in an extern "C++Qt" block
+            .filter(|method| !method.is_static)
+            .map(|method| {
+                let mut metadata = MethodMetadata::from_fields(&method.method_fields);
+                metadata.invokable = method.is_qinvokable;
+                metadata
+            })
+            .collect::<Vec<_>>();
+        let mut signals = structured_qobject
+            .signals
+            .iter()
+            .filter(|signal| !signal.private)
+            .map(|signal| MethodMetadata::from_fields(&signal.method_fields))
+            .collect::<Vec<_>>();
+
+        let mut properties = vec![];
+        for property in &structured_qobject.declaration.properties {
+            let names = QPropertyNames::try_from_property(property, structured_qobject)?;
+            let ty = &property.ty;
+
+            // Custom accessors are methods of the QObject and are therefore already described,
+            // only the generated ones need to be added here
+            if let NameState::Auto(getter) = &names.getter {
+                methods.push(MethodMetadata::auto_accessor(
+                    getter.rust_unqualified(),
+                    getter.cxx_unqualified(),
+                    false,
+                    vec![],
+                    Some(parse_quote! { &#ty }),
+                ));
+            }
+            if let Some(NameState::Auto(setter)) = &names.setter {
+                methods.push(MethodMetadata::auto_accessor(
+                    setter.rust_unqualified(),
+                    setter.cxx_unqualified(),
+                    true,
+                    vec![ParameterMetadata {
+                        name: "value".to_owned(),
+                        ty: type_to_string(ty),
+                    }],
+                    None,
+                ));
+            }
+            if let Some(NameState::Auto(reset)) = &names.reset {
+                methods.push(MethodMetadata::auto_accessor(
+                    reset.rust_unqualified(),
+                    reset.cxx_unqualified(),
+                    true,
+                    vec![],
+                    None,
+                ));
+            }
+            if let Some(NameState::Auto(notify)) = &names.notify {
+                signals.push(MethodMetadata::auto_accessor(
+                    notify.rust_unqualified(),
+                    notify.cxx_unqualified(),
+                    true,
+                    vec![],
+                    None,
+                ));
+            }
+
+            properties.push(PropertyMetadata {
+                rust_name: names.name.rust_unqualified().to_string(),
+                cxx_name: names.name.cxx_unqualified(),
+                ty: type_to_string(ty),
+                read: names.getter.cxx_unqualified(),
+                write: names.setter.as_ref().map(|setter| setter.cxx_unqualified()),
+                notify: names.notify.as_ref().map(|notify| notify.cxx_unqualified()),
+                reset: names.reset.as_ref().map(|reset| reset.cxx_unqualified()),
+            });
+        }
+
+        Ok(Self {
+            rust_name: qobject_ident.to_string(),
+            cxx_name: name.cxx_unqualified(),
+            namespace: name.namespace().unwrap_or_default().to_owned(),
+            properties,
+            methods,
+            signals,
+        })
+    }
+
+    /// The attributes which need to be added to the imported type to match the C++ name
+    pub(crate) fn type_attrs(&self) -> Vec<syn::Attribute> {
+        let cxx_name = &self.cxx_name;
+        let namespace = &self.namespace;
+        vec![
+            parse_quote! { #[cxx_name = #cxx_name] },
+            parse_quote! { #[namespace = #namespace] },
+        ]
+    }
+
+    /// The items of an `extern "C++Qt"` block which declare the methods and signals of the QObject
+    /// for the type with the given ident
+    pub(crate) fn foreign_items(&self, qobject_ident: &Ident) -> Result<Vec<ForeignItemFn>> {
+        let methods = self
+            .methods
+            .iter()
+            .map(|method| method.to_foreign_item(qobject_ident, quote! {}));
+        let signals = self
+            .signals
+            .iter()
+            .map(|signal| signal.to_foreign_item(qobject_ident, quote! { #[qsignal] }));
+        methods.chain(signals).collect()
+    }
+}
+
+impl MethodMetadata {
+    fn from_fields(fields: &MethodFields) -> Self {
+        Self {
+            rust_name: fields.name.rust_unqualified().to_string(),
+            cxx_name: fields.name.cxx_unqualified(),
+            mutable: fields.mutable,
+            safe: fields.safe,
+            invokable: false,
+            parameters: fields
+                .parameters
+                .iter()
+                .map(|parameter| ParameterMetadata {
+                    name: parameter.ident.to_string(),
+                    ty: type_to_string(&parameter.ty),
+                })
+                .collect(),
+            return_type: match &fields.method.sig.output {
+                ReturnType::Default => None,
+                ReturnType::Type(_, ty) => Some(type_to_string(ty)),
+            },
+        }
+    }
+
+    fn auto_accessor(
+        rust_name: &Ident,
+        cxx_name: String,
+        mutable: bool,
+        parameters: Vec<ParameterMetadata>,
+        return_type: Option<Type>,
+    ) -> Self {
+        Self {
+            rust_name: rust_name.to_string(),
+            cxx_name,
+            mutable,
+            safe: true,
+            invokable: false,
+            parameters,
+            return_type: return_type.as_ref().map(type_to_string),
+        }
+    }
+
+    fn to_foreign_item(&self, qobject_ident: &Ident, attrs: TokenStream) -> Result<ForeignItemFn> {
+        let rust_name = format_ident!("{}", self.rust_name);
+        let cxx_name = &self.cxx_name;
+        let unsafety = if self.safe {
+            quote! {}
+        } else {
+            quote! { unsafe }
+        };
+        let self_ty = if self.mutable {
+            quote! { Pin<&mut #qobject_ident> }
+        } else {
+            quote! { &#qobject_ident }
+        };
+        let parameters = self
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let name = format_ident!("{}", parameter.name);
+                let ty = parse_type(&parameter.ty)?;
+                Ok(quote! { #name: #ty })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let return_type = self
+            .return_type
+            .as_deref()
+            .map(|ty| -> Result<TokenStream> {
+                let ty = parse_type(ty)?;
+                Ok(quote! { -> #ty })
+            })
+            .transpose()?;
+
+        syn::parse2(quote! {
+            #attrs
+            #[cxx_name = #cxx_name]
+            #unsafety fn #rust_name(self: #self_ty #(, #parameters)*) #return_type;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_tokens_eq;
+    use syn::ItemMod;
+
+    fn metadata() -> BridgeMetadata {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "sub1")]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, counter)]
+                    #[qproperty(i32, limit, READ)]
+                    type Sub1Object = super::Sub1ObjectRust;
+
+                    #[qinvokable]
+                    #[cxx_name = "incrementBy"]
+                    fn increment_by(self: Pin<&mut Sub1Object>, amount: i32) -> i32;
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut Sub1Object>);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        BridgeMetadata::from_parser(&parser, "sub1/src/sub1_object.cxxqt.h").unwrap()
+    }
+
+    #[test]
+    fn test_bridge_metadata() {
+        let metadata = metadata();
+        assert_eq!(metadata.include, "sub1/src/sub1_object.cxxqt.h");
+        assert_eq!(metadata.qobjects.len(), 1);
+
+        let qobject = &metadata.qobjects[0];
+        assert_eq!(qobject.rust_name, "Sub1Object");
+        assert_eq!(qobject.cxx_name, "Sub1Object");
+        assert_eq!(qobject.namespace, "sub1");
+
+        assert_eq!(qobject.properties.len(), 2);
+        assert_eq!(
+            qobject.properties[0],
+            PropertyMetadata {
+                rust_name: "counter".to_owned(),
+                cxx_name: "counter".to_owned(),
+                ty: "i32".to_owned(),
+                read: "getCounter".to_owned(),
+                write: Some("setCounter".to_owned()),
+                notify: Some("counterChanged".to_owned()),
+                reset: None,
+            }
+        );
+        assert_eq!(qobject.properties[1].write, None);
+        assert_eq!(qobject.properties[1].notify, None);
+
+        // The invokable, then the getter and setter of counter and the getter of limit
+        assert_eq!(qobject.methods.len(), 4);
+        assert_eq!(
+            qobject.methods[0],
+            MethodMetadata {
+                rust_name: "increment_by".to_owned(),
+                cxx_name: "incrementBy".to_owned(),
+                mutable: true,
+                safe: true,
+                invokable: true,
+                parameters: vec![ParameterMetadata {
+                    name: "amount".to_owned(),
+                    ty: "i32".to_owned(),
+                }],
+                return_type: Some("i32".to_owned()),
+            }
+        );
+        assert_eq!(qobject.methods[1].cxx_name, "getCounter");
+        assert_eq!(qobject.methods[2].rust_name, "set_counter");
+
+        // The signal and the notify signal of counter
+        assert_eq!(qobject.signals.len(), 2);
+        assert_eq!(qobject.signals[1].rust_name, "counter_changed");
+    }
+
+    #[test]
+    fn test_bridge_metadata_json_roundtrip() {
+        let metadata = metadata();
+        let json = serde_json::to_string(&metadata).unwrap();
+        assert_eq!(
+            serde_json::from_str::<BridgeMetadata>(&json).unwrap(),
+            metadata
+        );
+    }
+
+    #[test]
+    fn test_foreign_items() {
+        let metadata = metadata();
+        let qobject = &metadata.qobjects[0];
+        let items = qobject.foreign_items(&format_ident!("Sub1Object")).unwrap();
+        assert_eq!(items.len(), 6);
+
+        assert_tokens_eq(
+            &items[0],
+            quote! {
+                #[cxx_name = "incrementBy"]
+                fn increment_by(self: Pin<&mut Sub1Object>, amount: i32) -> i32;
+            },
+        );
+        assert_tokens_eq(
+            &items[1],
+            quote! {
+                #[cxx_name = "getCounter"]
+                fn counter(self: &Sub1Object) -> &i32;
+            },
+        );
+        assert_tokens_eq(
+            &items[4],
+            quote! {
+                #[qsignal]
+                #[cxx_name = "ready"]
+                fn ready(self: Pin<&mut Sub1Object>);
+            },
+        );
+    }
+
+    #[test]
+    fn test_metadata_paths() {
+        let mut paths = MetadataPaths::default();
+        paths.insert("sub1", "/tmp/sub1/metadata.json");
+        assert_eq!(
+            paths.get("sub1"),
+            Some(PathBuf::from("/tmp/sub1/metadata.json"))
+        );
+        assert_eq!(paths.get("sub2"), None);
+        assert!(BridgeMetadata::load_exported("sub2", &paths)
+            .unwrap_err()
+            .contains("No metadata given for crate `sub2`"));
+        assert!(
+            BridgeMetadata::load_exported("sub2", &MetadataPaths::from_env())
+                .unwrap_err()
+                .contains("CXX_QT_METADATA_SUB2")
+        );
+    }
+
+    #[test]
+    fn test_metadata_env_var() {
+        assert_eq!(
+            metadata_env_var("qml-multi-crates"),
+            "CXX_QT_METADATA_QML_MULTI_CRATES"
+        );
+    }
+}
@@ -0,0 +1,697 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Type information for QML tooling, generated directly from a bridge.
+//!
+//! The metatypes JSON has the same layout as the output of `moc --output-json`, and the qmltypes
+//! are generated from it similar to `qmltyperegistrar`. This allows qmllint and qmlls to know
+//! about the QObjects of a bridge without running moc or compiling any C++.
+
+use crate::{
+    generator::{
+        naming::property::QPropertyNames,
+        structuring::{StructuredQObject, Structures},
+    },
+    naming::{
+        cpp::{syn_type_to_cpp_return_type, syn_type_to_cpp_type},
+        TypeNames,
+    },
+    parser::{doc_lines, method::MethodFields, parameter::ParsedFunctionParameter, Parser},
+};
+use serde_json::{json, Value};
+use std::fmt::Write;
+use syn::{Attribute, Result};
+
+/// The revision of the moc output format that the metatypes JSON corresponds to
+const MOC_OUTPUT_REVISION: u32 = 68;
+
+/// Convert a C++ type as generated for the bridge into the normalized name used by the Qt meta type system
+fn meta_type_name(cpp_type: &str) -> String {
+    const INTEGERS: [(&str, &str); 8] = [
+        ("::std::int8_t", "qint8"),
+        ("::std::int16_t", "short"),
+        ("::std::int32_t", "int"),
+        ("::std::int64_t", "qlonglong"),
+        ("::std::uint8_t", "quint8"),
+        ("::std::uint16_t", "ushort"),
+        ("::std::uint32_t", "uint"),
+        ("::std::uint64_t", "qulonglong"),
+    ];
+
+    // References are passed by value in the meta type system
+    let mut name = cpp_type.trim_end_matches('&');
+    name = name.strip_suffix(" const").unwrap_or(name);
+
+    let mut name = name.to_owned();
+    for (cpp, qt) in INTEGERS {
+        name = name.replace(cpp, qt);
+    }
+    name.replace("<::", "<")
+        .replace(", ::", ", ")
+        .trim_start_matches("::")
+        .to_owned()
+}
+
+/// Add the doc comments as a `documentation` entry, which moc does not generate itself
+/// but which is ignored by qmltyperegistrar
+fn with_documentation(mut json: Value, docs: &[Attribute]) -> Value {
+    let lines = doc_lines(docs);
+    if !lines.is_empty() {
+        json["documentation"] = lines.join("\n").into();
+    }
+    json
+}
+
+fn arguments(fields: &MethodFields, type_names: &TypeNames) -> Result<Vec<Value>> {
+    fields
+        .parameters
+        .iter()
+        .map(|parameter| {
+            Ok(json!({
+                "name": parameter.ident.to_string(),
+                "type": meta_type_name(&syn_type_to_cpp_type(&parameter.ty, type_names)?),
+            }))
+        })
+        .collect()
+}
+
+fn class_infos(structured_qobject: &StructuredQObject) -> Vec<Value> {
+    let Some(qml_metadata) = &structured_qobject.declaration.qml_metadata else {
+        return vec![];
+    };
+
+    // These match the Q_CLASSINFO entries of the QML_NAMED_ELEMENT, QML_UNCREATABLE and
+    // QML_SINGLETON macros in the generated header
+    let mut class_infos = vec![json!({ "name": "QML.Element", "value": qml_metadata.name })];
+    if qml_metadata.uncreatable {
+        class_infos.push(json!({ "name": "QML.Creatable", "value": "false" }));
+        class_infos.push(
+            json!({ "name": "QML.UncreatableReason", "value": "Type cannot be created in QML." }),
+        );
+    }
+    if qml_metadata.singleton {
+        class_infos.push(json!({ "name": "QML.Singleton", "value": "true" }));
+    }
+    class_infos
+}
+
+fn class(structured_qobject: &StructuredQObject, type_names: &TypeNames) -> Result<Value> {
+    let name = &structured_qobject.declaration.name;
+    let super_class = match structured_qobject.base_class() {
+        Some(base) => meta_type_name(
+            &type_names
+                .cxx_qualified(base)
+                .unwrap_or_else(|_| base.to_string()),
+        ),
+        None => "QObject".to_owned(),
+    };
+
+    // Signals are numbered before the methods, like moc does
+    let mut signals = structured_qobject
+        .signals
+        .iter()
+        .filter(|signal| !signal.inherit)
+        .map(|signal| {
+            Ok(with_documentation(
+                json!({
+                    "access": "public",
+                    "name": signal.name.cxx_unqualified(),
+                    "arguments": arguments(&signal.method_fields, type_names)?,
+                    "returnType": "void",
+                }),
+                &signal.docs,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut properties = vec![];
+    for (index, property) in structured_qobject.declaration.properties.iter().enumerate() {
+        let names = QPropertyNames::try_from_property(property, structured_qobject)?;
+        let flags = &property.flags;
+        let mut json = with_documentation(
+            json!({
+                "constant": flags.constant,
+                "designable": true,
+                "final": flags.is_final,
+                "index": index,
+                "name": names.name.cxx_unqualified(),
+                "read": names.getter.cxx_unqualified(),
+                "required": flags.required,
+                "scriptable": true,
+                "stored": true,
+                "type": meta_type_name(&syn_type_to_cpp_type(&property.ty, type_names)?),
+                "user": false,
+            }),
+            &property.docs,
+        );
+        if let Some(setter) = &names.setter {
+            json["write"] = setter.cxx_unqualified().into();
+        }
+        if let Some(reset) = &names.reset {
+            json["reset"] = reset.cxx_unqualified().into();
+        }
+        if let Some(notify) = &names.notify {
+            json["notify"] = notify.cxx_unqualified().into();
+            // Generated notify signals are not part of the signals of the bridge
+            if !structured_qobject
+                .signals
+                .iter()
+                .any(|signal| signal.name.cxx_unqualified() == notify.cxx_unqualified())
+            {
+                signals.push(json!({
+                    "access": "public",
+                    "name": notify.cxx_unqualified(),
+                    "arguments": [],
+                    "returnType": "void",
+                }));
+            }
+        }
+        properties.push(json);
+    }
+
+    let mut methods = vec![];
+    for method in structured_qobject
+        .methods
+        .iter()
+        .filter(|method| method.is_qinvokable)
+    {
+        let return_type =
+            syn_type_to_cpp_return_type(&method.method_fields.method.sig.output, type_names)?;
+        let arguments = arguments(&method.method_fields, type_names)?;
+        let json = with_documentation(
+            json!({
+                "access": "public",
+                "name": method.name.cxx_unqualified(),
+                "arguments": arguments,
+                "returnType": return_type
+                    .as_deref()
+                    .map(meta_type_name)
+                    .unwrap_or_else(|| "void".to_owned()),
+            }),
+            &method.docs,
+        );
+
+        // Like moc, each overload generated for a default value is a clone with fewer arguments
+        let shortest = ParsedFunctionParameter::first_default(&method.method_fields.parameters)
+            .unwrap_or(arguments.len());
+        let clones: Vec<Value> = (shortest..arguments.len())
+            .rev()
+            .map(|len| {
+                let mut clone = json.clone();
+                clone["arguments"] = arguments[..len].into();
+                clone["isCloned"] = true.into();
+                clone
+            })
+            .collect();
+        methods.push(json);
+        methods.extend(clones);
+    }
+
+    for (index, method) in signals.iter_mut().chain(methods.iter_mut()).enumerate() {
+        method["index"] = index.into();
+    }
+
+    let enums = structured_qobject
+        .qenums
+        .iter()
+        .map(|qenum| {
+            json!({
+                "isClass": true,
+                "isFlag": false,
+                "name": qenum.name.cxx_unqualified(),
+                "values": qenum.variants.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let qualified_class_name = match name.namespace() {
+        Some(namespace) if !namespace.is_empty() => {
+            format!(
+                "{}::{}",
+                namespace.trim_start_matches("::"),
+                name.cxx_unqualified()
+            )
+        }
+        _ => name.cxx_unqualified(),
+    };
+
+    Ok(with_documentation(
+        json!({
+            "className": name.cxx_unqualified(),
+            "qualifiedClassName": qualified_class_name,
+            "object": true,
+            "classInfos": class_infos(structured_qobject),
+            "enums": enums,
+            "properties": properties,
+            "signals": signals,
+            "methods": methods,
+            "superClasses": [{ "access": "public", "name": super_class }],
+        }),
+        &structured_qobject.declaration.docs,
+    ))
+}
+
+/// Generate the metatypes JSON for the QObjects of a parsed bridge, in the format of
+/// `moc --output-json` for the given generated header file
+pub fn metatypes_json(parser: &Parser, input_file: &str) -> Result<Value> {
+    let structures = Structures::new(&parser.cxx_qt_data)?;
+    let classes = structures
+        .qobjects
+        .iter()
+        .map(|qobject| class(qobject, &parser.type_names))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(json!({
+        "classes": classes,
+        "inputFile": input_file,
+        "outputRevision": MOC_OUTPUT_REVISION,
+    }))
+}
+
+/// Write a qmltypes string property, which uses the same escaping as JSON
+fn qmltypes_string(value: &str) -> String {
+    Value::from(value).to_string()
+}
+
+/// Write the `type` of a qmltypes object, splitting off any pointer
+fn write_qmltypes_type(out: &mut String, indent: &str, ty: &str) {
+    match ty.strip_suffix('*') {
+        Some(pointee) => {
+            let _ = writeln!(out, "{indent}type: {}", qmltypes_string(pointee));
+            let _ = writeln!(out, "{indent}isPointer: true");
+        }
+        None => {
+            let _ = writeln!(out, "{indent}type: {}", qmltypes_string(ty));
+        }
+    }
+}
+
+/// Write the documentation of a metatypes entry as `//` comments
+///
+/// The `.qmltypes` format has no field for documentation and QML tooling ignores comments,
+/// so they are only meant for a reader of the file.
+fn write_qmltypes_documentation(out: &mut String, indent: &str, value: &Value) {
+    for line in value["documentation"]
+        .as_str()
+        .into_iter()
+        .flat_map(str::lines)
+    {
+        if line.is_empty() {
+            let _ = writeln!(out, "{indent}//");
+        } else {
+            let _ = writeln!(out, "{indent}// {line}");
+        }
+    }
+}
+
+fn write_qmltypes_method(out: &mut String, kind: &str, method: &Value) {
+    write_qmltypes_documentation(out, "        ", method);
+    let _ = writeln!(out, "        {kind} {{");
+    let _ = writeln!(
+        out,
+        "            name: {}",
+        qmltypes_string(method["name"].as_str().unwrap_or_default())
+    );
+    if let Some(return_type) = method["returnType"].as_str() {
+        if return_type != "void" {
+            write_qmltypes_type(out, "            ", return_type);
+        }
+    }
+    if method["isCloned"] == true {
+        let _ = writeln!(out, "            isCloned: true");
+    }
+    for argument in method["arguments"].as_array().into_iter().flatten() {
+        let _ = writeln!(out, "            Parameter {{");
+        let _ = writeln!(
+            out,
+            "                name: {}",
+            qmltypes_string(argument["name"].as_str().unwrap_or_default())
+        );
+        write_qmltypes_type(
+            out,
+            "                ",
+            argument["type"].as_str().unwrap_or_default(),
+        );
+        let _ = writeln!(out, "            }}");
+    }
+    let _ = writeln!(out, "        }}");
+}
+
+/// Generate the contents of a `.qmltypes` file for the QML elements in the given metatypes JSON,
+/// which are exported in the QML module with the given URI and version
+pub fn write_qmltypes(
+    metatypes: &[Value],
+    uri: &str,
+    version_major: usize,
+    version_minor: usize,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "import QtQuick.tooling 1.2");
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "// This file was automatically generated by CXX-Qt from the bridges of the module."
+    );
+    let _ = writeln!(out);
+    let _ = writeln!(out, "Module {{");
+
+    for metatype in metatypes {
+        let file = metatype["inputFile"].as_str().unwrap_or_default();
+        for class in metatype["classes"].as_array().into_iter().flatten() {
+            let class_info = |name: &str| {
+                class["classInfos"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .find(|info| info["name"] == name)
+                    .and_then(|info| info["value"].as_str())
+            };
+            let Some(element) = class_info("QML.Element") else {
+                continue;
+            };
+            let element = if element == "auto" {
+                class["className"].as_str().unwrap_or_default()
+            } else {
+                element
+            };
+
+            write_qmltypes_documentation(&mut out, "    ", class);
+            let _ = writeln!(out, "    Component {{");
+            let _ = writeln!(out, "        file: {}", qmltypes_string(file));
+            let _ = writeln!(
+                out,
+                "        name: {}",
+                qmltypes_string(class["qualifiedClassName"].as_str().unwrap_or_default())
+            );
+            let _ = writeln!(out, "        accessSemantics: \"reference\"");
+            if let Some(super_class) = class["superClasses"][0]["name"].as_str() {
+                let _ = writeln!(out, "        prototype: {}", qmltypes_string(super_class));
+            }
+            let _ = writeln!(
+                out,
+                "        exports: [{}]",
+                qmltypes_string(&format!("{uri}/{element} {version_major}.{version_minor}"))
+            );
+            let _ = writeln!(
+                out,
+                "        exportMetaObjectRevisions: [{}]",
+                (version_major << 8) | version_minor
+            );
+            if class_info("QML.Creatable") == Some("false") {
+                let _ = writeln!(out, "        isCreatable: false");
+            }
+            if class_info("QML.Singleton") == Some("true") {
+                let _ = writeln!(out, "        isSingleton: true");
+            }
+
+            for qenum in class["enums"].as_array().into_iter().flatten() {
+                let values = qenum["values"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .map(qmltypes_string)
+                    .collect::<Vec<_>>();
+                let _ = writeln!(out, "        Enum {{");
+                let _ = writeln!(
+                    out,
+                    "            name: {}",
+                    qmltypes_string(qenum["name"].as_str().unwrap_or_default())
+                );
+                let _ = writeln!(out, "            isScoped: true");
+                let _ = writeln!(out, "            values: [{}]", values.join(", "));
+                let _ = writeln!(out, "        }}");
+            }
+
+            for property in class["properties"].as_array().into_iter().flatten() {
+                write_qmltypes_documentation(&mut out, "        ", property);
+                let _ = writeln!(out, "        Property {{");
+                let _ = writeln!(
+                    out,
+                    "            name: {}",
+                    qmltypes_string(property["name"].as_str().unwrap_or_default())
+                );
+                write_qmltypes_type(
+                    &mut out,
+                    "            ",
+                    property["type"].as_str().unwrap_or_default(),
+                );
+                for accessor in ["read", "write", "reset", "notify"] {
+                    if let Some(name) = property[accessor].as_str() {
+                        let _ = writeln!(out, "            {accessor}: {}", qmltypes_string(name));
+                    }
+                }
+                let _ = writeln!(out, "            index: {}", property["index"]);
+                if property["write"].is_null() {
+                    let _ = writeln!(out, "            isReadonly: true");
+                }
+                if property["final"] == true {
+                    let _ = writeln!(out, "            isFinal: true");
+                }
+                if property["constant"] == true {
+                    let _ = writeln!(out, "            isPropertyConstant: true");
+                }
+                if property["required"] == true {
+                    let _ = writeln!(out, "            isRequired: true");
+                }
+                let _ = writeln!(out, "        }}");
+            }
+
+            for signal in class["signals"].as_array().into_iter().flatten() {
+                write_qmltypes_method(&mut out, "Signal", signal);
+            }
+            for method in class["methods"].as_array().into_iter().flatten() {
+                write_qmltypes_method(&mut out, "Method", method);
+            }
+            let _ = writeln!(out, "    }}");
+        }
+    }
+
+    let _ = writeln!(out, "}}");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use syn::{parse_quote, ItemMod};
+
+    fn metatypes() -> Value {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge(namespace = "cxx_qt::my_object")]
+            mod ffi {
+                #[namespace = ""]
+                unsafe extern "C++" {
+                    include!("cxx-qt-lib/qstring.h");
+                    type QString = cxx_qt_lib::QString;
+                }
+
+                #[qenum(MyObject)]
+                enum Mode {
+                    Fast,
+                    Slow,
+                }
+
+                extern "RustQt" {
+                    /// An object for QML
+                    #[qobject]
+                    #[qml_element]
+                    /// A number
+                    #[qproperty(i32, number)]
+                    #[qproperty(QString, name, READ, CONSTANT)]
+                    type MyObject = super::MyObjectRust;
+
+                    /// Say hi
+                    ///
+                    /// Returns the greeting
+                    #[qinvokable]
+                    #[cxx_name = "sayHi"]
+                    fn say_hi(self: &MyObject, string: &QString, number: i32) -> QString;
+
+                    fn not_invokable(self: &MyObject);
+
+                    #[qinvokable]
+                    #[Self = "MyObject"]
+                    fn square(value: i32) -> i32;
+
+                    #[qinvokable]
+                    fn reset(self: Pin<&mut MyObject>, #[default = 0] value: i32, #[default = true] notify: bool);
+
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>, value: u64);
+                }
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        metatypes_json(&parser, "my_object.cxxqt.h").unwrap()
+    }
+
+    #[test]
+    fn test_meta_type_name() {
+        assert_eq!(meta_type_name("::std::int32_t"), "int");
+        assert_eq!(meta_type_name("::QString const&"), "QString");
+        assert_eq!(
+            meta_type_name("::QList<::std::uint64_t>"),
+            "QList<qulonglong>"
+        );
+        assert_eq!(
+            meta_type_name("::cxx_qt::my_object::MyObject*"),
+            "cxx_qt::my_object::MyObject*"
+        );
+        assert_eq!(meta_type_name("double"), "double");
+    }
+
+    #[test]
+    fn test_metatypes_json() {
+        let metatypes = metatypes();
+        assert_eq!(metatypes["inputFile"], "my_object.cxxqt.h");
+        assert_eq!(metatypes["outputRevision"], MOC_OUTPUT_REVISION);
+
+        let class = &metatypes["classes"][0];
+        assert_eq!(class["className"], "MyObject");
+        assert_eq!(class["documentation"], "An object for QML");
+        assert_eq!(class["qualifiedClassName"], "cxx_qt::my_object::MyObject");
+        assert_eq!(class["superClasses"][0]["name"], "QObject");
+        assert_eq!(
+            class["classInfos"],
+            json!([{ "name": "QML.Element", "value": "MyObject" }])
+        );
+        assert_eq!(
+            class["enums"],
+            json!([{ "isClass": true, "isFlag": false, "name": "Mode", "values": ["Fast", "Slow"] }])
+        );
+
+        let properties = class["properties"].as_array().unwrap();
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties[0]["name"], "number");
+        assert_eq!(properties[0]["type"], "int");
+        assert_eq!(properties[0]["read"], "getNumber");
+        assert_eq!(properties[0]["write"], "setNumber");
+        assert_eq!(properties[0]["notify"], "numberChanged");
+        assert_eq!(properties[0]["documentation"], "A number");
+        assert!(properties[1]["documentation"].is_null());
+        assert_eq!(properties[1]["type"], "QString");
+        assert_eq!(properties[1]["constant"], true);
+        assert!(properties[1]["write"].is_null());
+
+        // The signal of the bridge, then the generated notify signal
+        let signals = class["signals"].as_array().unwrap();
+        assert_eq!(signals.len(), 2);
+        assert_eq!(
+            signals[0],
+            json!({
+                "access": "public",
+                "name": "ready",
+                "arguments": [{ "name": "value", "type": "qulonglong" }],
+                "returnType": "void",
+                "index": 0,
+            })
+        );
+        assert_eq!(signals[1]["name"], "numberChanged");
+
+        // Only the invokables, numbered after the signals
+        let methods = class["methods"].as_array().unwrap();
+        assert_eq!(methods.len(), 5);
+        assert_eq!(
+            methods[0],
+            json!({
+                "access": "public",
+                "name": "sayHi",
+                "arguments": [
+                    { "name": "string", "type": "QString" },
+                    { "name": "number", "type": "int" },
+                ],
+                "returnType": "QString",
+                "index": 2,
+                "documentation": "Say hi\n\nReturns the greeting",
+            })
+        );
+
+        // Static invokables are Q_INVOKABLE as well
+        assert_eq!(methods[1]["name"], "square");
+        assert_eq!(methods[1]["returnType"], "int");
+
+        // Each overload for a default value is a clone with fewer arguments
+        let overloads = methods[2..]
+            .iter()
+            .map(|method| {
+                (
+                    method["name"].as_str().unwrap(),
+                    method["arguments"].as_array().unwrap().len(),
+                    method["isCloned"] == true,
+                    method["index"].as_u64().unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            overloads,
+            [
+                ("reset", 2, false, 4),
+                ("reset", 1, true, 5),
+                ("reset", 0, true, 6)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_qmltypes() {
+        let qmltypes = write_qmltypes(&[metatypes()], "com.kdab.cxx_qt.demo", 1, 0);
+        assert!(qmltypes.starts_with("import QtQuick.tooling 1.2\n"));
+        assert!(qmltypes.contains(
+            r#"        name: "cxx_qt::my_object::MyObject"
+        accessSemantics: "reference"
+        prototype: "QObject"
+        exports: ["com.kdab.cxx_qt.demo/MyObject 1.0"]
+        exportMetaObjectRevisions: [256]
+"#
+        ));
+        assert!(qmltypes.contains(
+            r#"        Property {
+            name: "name"
+            type: "QString"
+            read: "getName"
+            index: 1
+            isReadonly: true
+            isPropertyConstant: true
+        }
+"#
+        ));
+        assert!(qmltypes.contains(
+            r#"        Method {
+            name: "sayHi"
+            type: "QString"
+            Parameter {
+                name: "string"
+                type: "QString"
+            }
+"#
+        ));
+        assert!(qmltypes.contains(
+            r#"        Method {
+            name: "reset"
+            isCloned: true
+            Parameter {
+                name: "value"
+                type: "int"
+            }
+        }
+"#
+        ));
+        assert!(qmltypes.contains("            values: [\"Fast\", \"Slow\"]\n"));
+        assert!(qmltypes.contains("    // An object for QML\n    Component {\n"));
+        assert!(qmltypes.contains("        // A number\n        Property {\n"));
+        assert!(qmltypes.contains(
+            "        // Say hi\n        //\n        // Returns the greeting\n        Method {\n"
+        ));
+    }
+
+    #[test]
+    fn test_write_qmltypes_skips_non_qml_elements() {
+        let mut metatypes = metatypes();
+        metatypes["classes"][0]["classInfos"] = json!([]);
+        let qmltypes = write_qmltypes(&[metatypes], "com.kdab.cxx_qt.demo", 1, 0);
+        assert!(!qmltypes.contains("Component"));
+    }
+}
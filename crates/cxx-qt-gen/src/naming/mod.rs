@@ -14,6 +14,7 @@
 pub(crate) mod cpp;
 mod name;
 pub(crate) mod rust;
+pub(crate) mod template;
 mod type_names;
 
 pub use name::Name;
@@ -7,19 +7,23 @@ use crate::naming::TypeNames;
 use crate::syntax::lifetimes::err_unsupported_type;
 use syn::spanned::Spanned;
 use syn::{
-    Error, GenericArgument, PathArguments, PathSegment, Result, ReturnType, Type, TypePath,
+    Error, GenericArgument, Ident, PathArguments, PathSegment, Result, ReturnType, Type, TypePath,
     TypeReference,
 };
 
 macro_rules! convert_elem {
-    ($id:ident, $variant:path, $type_names:ident) => {{
+    ($id:ident, $variant:path, $type_names:ident, $generics:ident) => {{
         let mut ty = $id.clone();
-        *ty.elem = syn_type_cxx_bridge_to_qualified(&ty.elem, $type_names)?;
+        *ty.elem = qualify_type(&ty.elem, $type_names, $generics)?;
         Ok($variant(ty))
     }};
 }
 
-fn qualify_type_path(ty_path: &TypePath, type_names: &TypeNames) -> Result<Type> {
+fn qualify_type_path(
+    ty_path: &TypePath,
+    type_names: &TypeNames,
+    generics: &[Ident],
+) -> Result<Type> {
     let mut ty_path = ty_path.clone();
 
     // Convert any generic arguments
@@ -27,7 +31,7 @@ fn qualify_type_path(ty_path: &TypePath, type_names: &TypeNames) -> Result<Type>
         if let PathArguments::AngleBracketed(angled) = &mut segment.arguments {
             for arg in angled.args.iter_mut() {
                 if let GenericArgument::Type(ty) = arg {
-                    *ty = syn_type_cxx_bridge_to_qualified(ty, type_names)?;
+                    *ty = qualify_type(ty, type_names, generics)?;
                 } else {
                     return Err(Error::new(arg.span(), "Unsupported GenericArgument type!"));
                 }
@@ -63,7 +67,9 @@ fn qualify_type_path(ty_path: &TypePath, type_names: &TypeNames) -> Result<Type>
 
     // If the path matches a known ident then used the qualified mapping
     if let Some(ident) = ty_path.path.get_ident() {
-        ty_path.path = type_names.rust_qualified(ident)?;
+        if !generics.contains(ident) {
+            ty_path.path = type_names.rust_qualified(ident)?;
+        }
     }
 
     Ok(Type::Path(ty_path))
@@ -77,32 +83,46 @@ fn qualify_type_path(ty_path: &TypePath, type_names: &TypeNames) -> Result<Type>
 ///
 /// Eg MyObject -> ffi::MyObject
 pub(crate) fn syn_type_cxx_bridge_to_qualified(ty: &Type, type_names: &TypeNames) -> Result<Type> {
+    qualify_type(ty, type_names, &[])
+}
+
+/// Return a qualified version of the type like [syn_type_cxx_bridge_to_qualified],
+/// but leave the given generic type parameters, eg `T` or `Self`, unchanged
+pub(crate) fn syn_type_cxx_bridge_to_qualified_generic(
+    ty: &Type,
+    type_names: &TypeNames,
+    generics: &[Ident],
+) -> Result<Type> {
+    qualify_type(ty, type_names, generics)
+}
+
+fn qualify_type(ty: &Type, type_names: &TypeNames, generics: &[Ident]) -> Result<Type> {
     match ty {
         Type::Array(ty_array) => {
             let mut ty_array = ty_array.clone();
-            *ty_array.elem = syn_type_cxx_bridge_to_qualified(&ty_array.elem, type_names)?;
+            *ty_array.elem = qualify_type(&ty_array.elem, type_names, generics)?;
             Ok(Type::Array(ty_array))
         }
         Type::BareFn(ty_bare_fn) => {
             let mut ty_bare_fn = ty_bare_fn.clone();
             if let ReturnType::Type(_, ty) = &mut ty_bare_fn.output {
-                **ty = syn_type_cxx_bridge_to_qualified(ty, type_names)?;
+                **ty = qualify_type(ty, type_names, generics)?;
             }
 
             for arg in ty_bare_fn.inputs.iter_mut() {
-                arg.ty = syn_type_cxx_bridge_to_qualified(&arg.ty, type_names)?;
+                arg.ty = qualify_type(&arg.ty, type_names, generics)?;
             }
 
             Ok(Type::BareFn(ty_bare_fn))
         }
-        Type::Path(ty_path) => qualify_type_path(ty_path, type_names),
-        Type::Ptr(ty_ptr) => convert_elem!(ty_ptr, Type::Ptr, type_names),
-        Type::Reference(ty_ref) => convert_elem!(ty_ref, Type::Reference, type_names),
-        Type::Slice(ty_slice) => convert_elem!(ty_slice, Type::Slice, type_names),
+        Type::Path(ty_path) => qualify_type_path(ty_path, type_names, generics),
+        Type::Ptr(ty_ptr) => convert_elem!(ty_ptr, Type::Ptr, type_names, generics),
+        Type::Reference(ty_ref) => convert_elem!(ty_ref, Type::Reference, type_names, generics),
+        Type::Slice(ty_slice) => convert_elem!(ty_slice, Type::Slice, type_names, generics),
         Type::Tuple(ty_tuple) => {
             let mut ty_tuple = ty_tuple.clone();
             for elem in ty_tuple.elems.iter_mut() {
-                *elem = syn_type_cxx_bridge_to_qualified(elem, type_names)?;
+                *elem = qualify_type(elem, type_names, generics)?;
             }
             Ok(Type::Tuple(ty_tuple))
         }
@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use quote::format_ident;
+use syn::Ident;
+
+/// For a given instantiation of a QObject template, eg `type IntRange = RangeModel<i32>`,
+/// generate the ident of the alias for the concrete Rust struct, eg `IntRangeRust`
+///
+/// The QObject itself uses the ident of the instantiation as its name, so each instantiation
+/// results in a distinct C++ class.
+pub fn template_instance_rust_ident(instance: &Ident) -> Ident {
+    format_ident!("{instance}Rust")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_instance_rust_ident() {
+        assert_eq!(
+            template_instance_rust_ident(&format_ident!("IntRange")),
+            "IntRangeRust"
+        );
+    }
+}
@@ -170,6 +170,11 @@ impl TypeNames {
             self.insert(qenum.name.clone())?;
         }
 
+        // Find and register the names of any #[qinterface] traits in the bridge
+        for qinterface in &cxx_qt_data.qinterfaces {
+            self.insert(qinterface.name.clone())?;
+        }
+
         for extern_cxxqt in &cxx_qt_data.extern_cxxqt_blocks {
             let namespace = if let Some(namespace) = &extern_cxxqt.namespace {
                 quote! { #[namespace = #namespace ] }
@@ -3,23 +3,28 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+use super::qinterface::ParsedQInterface;
 use super::qnamespace::ParsedQNamespace;
 use super::trait_impl::TraitImpl;
 use crate::parser::externrustqt::ParsedExternRustQt;
 use crate::{
+    metadata::MetadataPaths,
     parser::{
-        externcxxqt::ParsedExternCxxQt, inherit::ParsedInheritedMethod, method::ParsedMethod,
-        qenum::ParsedQEnum, qobject::ParsedQObject, signals::ParsedSignal,
+        externcxxqt::ParsedExternCxxQt, function::ParsedFunction, inherit::ParsedInheritedMethod,
+        method::ParsedMethod, qenum::ParsedQEnum, qobject::ParsedQObject, signals::ParsedSignal,
+        template::ParsedTemplate,
     },
     syntax::{attribute::attribute_get_path, path::path_compare_str},
 };
-use syn::{Ident, Item, ItemEnum, ItemForeignMod, ItemImpl, ItemMacro, Meta, Result};
+use syn::{Ident, Item, ItemEnum, ItemForeignMod, ItemImpl, ItemMacro, ItemTrait, Meta, Result};
 
 pub struct ParsedCxxQtData {
     /// List of QEnums defined in the module, that aren't associated with a QObject
     pub qenums: Vec<ParsedQEnum>,
     /// List of QNamespace declarations
     pub qnamespaces: Vec<ParsedQNamespace>,
+    /// List of #[qinterface] traits
+    pub qinterfaces: Vec<ParsedQInterface>,
     /// Blocks of extern "C++Qt"
     pub extern_cxxqt_blocks: Vec<ParsedExternCxxQt>,
     /// The namespace of the CXX-Qt module
@@ -30,6 +35,12 @@ pub struct ParsedCxxQtData {
     pub module_ident: Ident,
     /// All the `extern "RustQt"` blocks
     pub extern_rustqt_blocks: Vec<ParsedExternRustQt>,
+    /// The QObject templates and their instantiations
+    pub templates: Vec<ParsedTemplate>,
+    /// The names of all #[qinterface] traits in the module, as trait impls can precede them
+    qinterface_idents: Vec<Ident>,
+    /// The paths to the metadata of other crates, for QObjects which are imported
+    pub metadata_paths: MetadataPaths,
 }
 
 impl ParsedCxxQtData {
@@ -40,12 +51,32 @@ impl ParsedCxxQtData {
             namespace,
             qenums: vec![],
             qnamespaces: vec![],
+            qinterfaces: vec![],
             trait_impls: vec![],
             extern_cxxqt_blocks: vec![],
             extern_rustqt_blocks: vec![],
+            templates: vec![],
+            qinterface_idents: vec![],
+            metadata_paths: MetadataPaths::default(),
         }
     }
 
+    /// Find the names of the #[qinterface] traits in the given items,
+    /// this needs to happen before any trait impls are parsed
+    pub fn find_qinterface_idents(&mut self, items: &[Item]) {
+        self.qinterface_idents = items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Trait(trait_item)
+                    if attribute_get_path(&trait_item.attrs, &["qinterface"]).is_some() =>
+                {
+                    Some(trait_item.ident.clone())
+                }
+                _ => None,
+            })
+            .collect();
+    }
+
     /// Flatten a vector from each rust block into one larger vector,
     /// e.g. all the methods in every block.
     fn flatten_rust_blocks<T>(&self, accessor: fn(&ParsedExternRustQt) -> &Vec<T>) -> Vec<&T> {
@@ -67,6 +98,10 @@ impl ParsedCxxQtData {
         self.flatten_rust_blocks(|block| &block.inherited_methods)
     }
 
+    pub fn functions(&self) -> Vec<&ParsedFunction> {
+        self.flatten_rust_blocks(|block| &block.functions)
+    }
+
     pub fn qobjects(&self) -> Vec<&ParsedQObject> {
         self.flatten_rust_blocks(|block| &block.qobjects)
     }
@@ -80,6 +115,7 @@ impl ParsedCxxQtData {
             Item::ForeignMod(foreign_mod) => self.parse_foreign_mod(foreign_mod),
             Item::Enum(enum_item) => self.parse_enum(enum_item),
             Item::Macro(mac) => self.parse_macro(mac),
+            Item::Trait(trait_item) => self.parse_trait(trait_item),
             _ => Ok(Some(item)),
         }
     }
@@ -104,6 +140,17 @@ impl ParsedCxxQtData {
         }
     }
 
+    fn parse_trait(&mut self, item: ItemTrait) -> Result<Option<Item>> {
+        if attribute_get_path(&item.attrs, &["qinterface"]).is_some() {
+            let qinterface =
+                ParsedQInterface::parse(item, &self.module_ident, self.namespace.as_deref())?;
+            self.qinterfaces.push(qinterface);
+            Ok(None)
+        } else {
+            Ok(Some(Item::Trait(item)))
+        }
+    }
+
     fn parse_macro(&mut self, item: ItemMacro) -> Result<Option<Item>> {
         if path_compare_str(&item.mac.path, &["qnamespace"]) {
             let qnamespace = ParsedQNamespace::parse(item)?;
@@ -130,6 +177,7 @@ impl ParsedCxxQtData {
                         foreign_mod,
                         &self.module_ident,
                         self.namespace.as_deref(),
+                        &self.metadata_paths,
                     )?);
                     return Ok(None);
                 }
@@ -146,7 +194,8 @@ impl ParsedCxxQtData {
         // If it is a trait impl compared to a regular impl block
         // This allows the cxx shim trait feature
         if imp.trait_.is_some() {
-            self.trait_impls.push(TraitImpl::parse(imp)?);
+            self.trait_impls
+                .push(TraitImpl::parse(imp, &self.qinterface_idents)?);
             Ok(None)
         } else {
             Ok(Some(Item::Impl(imp)))
@@ -179,6 +228,7 @@ mod tests {
             methods: vec![],
             signals: vec![],
             inherited_methods: vec![],
+            functions: vec![],
         });
         cxx_qt_data
     }
@@ -5,14 +5,17 @@
 
 use crate::parser::signals::ImmutabilityConstraint;
 use crate::{
+    metadata::{BridgeMetadata, MetadataPaths},
+    naming::Name,
     parser::{
         externqobject::ParsedExternQObject, require_attributes, signals::ParsedSignal,
         CaseConversion,
     },
-    syntax::{attribute::attribute_get_path, expr::expr_to_string},
+    syntax::{attribute::attribute_get_path, expr::expr_to_string, path::path_compare_str},
 };
 use syn::{
-    spanned::Spanned, Error, ForeignItem, ForeignItemFn, Ident, ItemForeignMod, Result, Token,
+    parse_quote, spanned::Spanned, Error, ForeignItem, ForeignItemFn, ForeignItemType, Ident,
+    ItemForeignMod, Result, Token,
 };
 
 /// Representation of an extern "C++Qt" block
@@ -35,6 +38,7 @@ impl ParsedExternCxxQt {
         mut foreign_mod: ItemForeignMod,
         module_ident: &Ident,
         parent_namespace: Option<&str>,
+        metadata_paths: &MetadataPaths,
     ) -> Result<Self> {
         // TODO: support cfg on foreign mod blocks
         let attrs = require_attributes(
@@ -65,11 +69,13 @@ impl ParsedExternCxxQt {
                 ForeignItem::Fn(foreign_fn) => {
                     extern_cxx_block.parse_invokable(foreign_fn, auto_case)?;
                 }
-                ForeignItem::Type(foreign_ty) => {
+                ForeignItem::Type(mut foreign_ty) => {
                     // Test that there is a #[qobject] attribute on any type
                     //
                     // TODO: what happens to any docs here?
                     if attribute_get_path(&foreign_ty.attrs, &["qobject"]).is_some() {
+                        extern_cxx_block.import_qobject(&mut foreign_ty, metadata_paths)?;
+
                         let extern_ty =
                             ParsedExternQObject::parse(foreign_ty, module_ident, parent_namespace)?;
                         // Pass through types separately for generation
@@ -92,6 +98,73 @@ impl ParsedExternCxxQt {
         Ok(extern_cxx_block)
     }
 
+    /// The names of the signals in this block which belong to the given QObject
+    pub fn signal_names<'a>(&'a self, qobject_ident: &'a Ident) -> impl Iterator<Item = &'a Name> {
+        self.signals
+            .iter()
+            .filter(move |signal| &signal.qobject_ident == qobject_ident)
+            .map(|signal| &signal.name)
+    }
+
+    /// Expand a QObject tagged with `#[import = "crate"]` from the metadata exported by that crate
+    ///
+    /// This adds the C++ name and namespace to the type and declares all of its methods and signals.
+    fn import_qobject(
+        &mut self,
+        foreign_ty: &mut ForeignItemType,
+        metadata_paths: &MetadataPaths,
+    ) -> Result<()> {
+        let Some(index) = foreign_ty
+            .attrs
+            .iter()
+            .position(|attr| path_compare_str(attr.meta.path(), &["import"]))
+        else {
+            return Ok(());
+        };
+        let attr = foreign_ty.attrs.remove(index);
+        let crate_name = expr_to_string(&attr.meta.require_name_value()?.value)?;
+
+        let bridges = BridgeMetadata::load_exported(&crate_name, metadata_paths)
+            .map_err(|message| Error::new(attr.span(), message))?;
+        let (include, qobject) = bridges
+            .iter()
+            .find_map(|bridge| {
+                bridge
+                    .qobjects
+                    .iter()
+                    .find(|qobject| foreign_ty.ident == qobject.rust_name)
+                    .map(|qobject| (&bridge.include, qobject))
+            })
+            .ok_or_else(|| {
+                Error::new(
+                    foreign_ty.ident.span(),
+                    format!(
+                        "Crate `{crate_name}` does not export a QObject named {}!",
+                        foreign_ty.ident
+                    ),
+                )
+            })?;
+
+        if foreign_ty.attrs.iter().any(|attr| {
+            path_compare_str(attr.meta.path(), &["cxx_name"])
+                || path_compare_str(attr.meta.path(), &["namespace"])
+        }) {
+            return Err(Error::new(
+                foreign_ty.span(),
+                "Imported QObjects cannot have a cxx_name or namespace, these are taken from the exporting crate",
+            ));
+        }
+        foreign_ty.attrs.extend(qobject.type_attrs());
+
+        self.passthrough_items
+            .push(parse_quote! { include!(#include); });
+        for foreign_fn in qobject.foreign_items(&foreign_ty.ident)? {
+            self.parse_invokable(foreign_fn, CaseConversion::none())?;
+        }
+
+        Ok(())
+    }
+
     fn parse_invokable(
         &mut self,
         foreign_fn: ForeignItemFn,
@@ -131,8 +204,8 @@ mod tests {
     use super::*;
     use quote::format_ident;
 
-    use crate::tests::assert_parse_errors;
-    use syn::parse_quote;
+    use crate::tests::{assert_parse_errors, assert_tokens_eq};
+    use quote::quote;
 
     #[test]
     fn test_find_and_merge_cxx_qt_item_extern_cxx_qt() {
@@ -151,6 +224,7 @@ mod tests {
             },
             &format_ident!("qobject"),
             None,
+            &MetadataPaths::default(),
         )
         .unwrap();
 
@@ -172,6 +246,7 @@ mod tests {
             },
             &format_ident!("qobject"),
             None,
+            &MetadataPaths::default(),
         )
         .unwrap();
 
@@ -192,6 +267,7 @@ mod tests {
             },
             &format_ident!("qobject"),
             None,
+            &MetadataPaths::default(),
         )
         .unwrap();
         assert!(extern_cxx_qt.qobjects.is_empty());
@@ -202,7 +278,7 @@ mod tests {
     #[test]
     fn test_parse_invalid() {
         assert_parse_errors!(
-            |item| ParsedExternCxxQt::parse(item, &format_ident!("qobject"), None) =>
+            |item| ParsedExternCxxQt::parse(item, &format_ident!("qobject"), None, &MetadataPaths::default()) =>
 
             // Inherit is not allowed in "C++Qt" blocks
             {
@@ -228,4 +304,115 @@ mod tests {
             }
         );
     }
+
+    fn export_metadata(crate_name: &str) -> MetadataPaths {
+        use crate::metadata::{MethodMetadata, QObjectMetadata};
+
+        let metadata = vec![BridgeMetadata {
+            include: format!("{crate_name}/src/lib.cxxqt.h"),
+            qobjects: vec![QObjectMetadata {
+                rust_name: "Sub1Object".to_owned(),
+                cxx_name: "Sub1Object".to_owned(),
+                namespace: "sub1".to_owned(),
+                properties: vec![],
+                methods: vec![MethodMetadata {
+                    rust_name: "increment".to_owned(),
+                    cxx_name: "increment".to_owned(),
+                    mutable: true,
+                    safe: true,
+                    invokable: true,
+                    parameters: vec![],
+                    return_type: None,
+                }],
+                signals: vec![MethodMetadata {
+                    rust_name: "ready".to_owned(),
+                    cxx_name: "ready".to_owned(),
+                    mutable: true,
+                    safe: true,
+                    ..Default::default()
+                }],
+            }],
+        }];
+        let path = std::env::temp_dir().join(format!("{crate_name}-metadata.json"));
+        std::fs::write(&path, serde_json::to_string(&metadata).unwrap()).unwrap();
+        let mut paths = MetadataPaths::default();
+        paths.insert(crate_name, path);
+        paths
+    }
+
+    #[test]
+    fn test_extern_cxxqt_import() {
+        let metadata_paths = export_metadata("cxx-qt-gen-test-import");
+        let extern_cxx_qt = ParsedExternCxxQt::parse(
+            parse_quote! {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[import = "cxx-qt-gen-test-import"]
+                    type Sub1Object;
+                }
+            },
+            &format_ident!("qobject"),
+            None,
+            &metadata_paths,
+        )
+        .unwrap();
+
+        assert_eq!(extern_cxx_qt.qobjects.len(), 1);
+        let qobject = &extern_cxx_qt.qobjects[0];
+        assert_eq!(qobject.name.cxx_unqualified(), "Sub1Object");
+        assert_eq!(qobject.name.namespace(), Some("sub1"));
+
+        assert_eq!(extern_cxx_qt.signals.len(), 1);
+        assert!(extern_cxx_qt.signals[0].inherit);
+
+        // The include and the invokable
+        assert_eq!(extern_cxx_qt.passthrough_items.len(), 2);
+        assert_tokens_eq(
+            &extern_cxx_qt.passthrough_items[0],
+            quote! { include!("cxx-qt-gen-test-import/src/lib.cxxqt.h"); },
+        );
+        assert_tokens_eq(
+            &extern_cxx_qt.passthrough_items[1],
+            quote! {
+                #[cxx_name = "increment"]
+                fn increment(self: Pin<&mut Sub1Object>);
+            },
+        );
+    }
+
+    #[test]
+    fn test_extern_cxxqt_import_invalid() {
+        let metadata_paths = export_metadata("cxx-qt-gen-test-import-invalid");
+        assert_parse_errors!(
+            |item| ParsedExternCxxQt::parse(item, &format_ident!("qobject"), None, &metadata_paths) =>
+
+            // The crate does not export any metadata
+            {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[import = "cxx-qt-gen-test-missing"]
+                    type Sub1Object;
+                }
+            }
+
+            // The crate does not export this QObject
+            {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[import = "cxx-qt-gen-test-import-invalid"]
+                    type Sub2Object;
+                }
+            }
+
+            // The C++ name is taken from the metadata
+            {
+                unsafe extern "C++Qt" {
+                    #[qobject]
+                    #[import = "cxx-qt-gen-test-import-invalid"]
+                    #[namespace = "other"]
+                    type Sub1Object;
+                }
+            }
+        );
+    }
 }
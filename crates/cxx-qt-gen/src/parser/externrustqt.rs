@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::naming::cpp::err_unsupported_item;
+use crate::parser::function::ParsedFunction;
 use crate::parser::inherit::ParsedInheritedMethod;
 use crate::parser::method::ParsedMethod;
 use crate::parser::qobject::ParsedQObject;
@@ -29,6 +30,8 @@ pub struct ParsedExternRustQt {
     pub signals: Vec<ParsedSignal>,
     /// List of the inherited methods found
     pub inherited_methods: Vec<ParsedInheritedMethod>,
+    /// List of free functions found, which belong to a qnamespace!
+    pub functions: Vec<ParsedFunction>,
 }
 
 impl ParsedExternRustQt {
@@ -59,7 +62,11 @@ impl ParsedExternRustQt {
         for item in foreign_mod.items.drain(..) {
             match item {
                 ForeignItem::Fn(foreign_fn) => {
-                    extern_rustqt_block.parse_invokable(foreign_fn, auto_case)?;
+                    extern_rustqt_block.parse_invokable(
+                        foreign_fn,
+                        namespace.as_deref(),
+                        auto_case,
+                    )?;
                 }
                 ForeignItem::Verbatim(tokens) => {
                     let foreign_alias: ForeignTypeIdentAlias = syn::parse2(tokens.clone())?;
@@ -87,6 +94,7 @@ impl ParsedExternRustQt {
     fn parse_invokable(
         &mut self,
         foreign_fn: ForeignItemFn,
+        namespace: Option<&str>,
         auto_case: CaseConversion,
     ) -> Result<()> {
         // Test if the function is a signal
@@ -116,6 +124,13 @@ impl ParsedExternRustQt {
             let parsed_inherited_method = ParsedInheritedMethod::parse(foreign_fn, auto_case)?;
 
             self.inherited_methods.push(parsed_inherited_method);
+            // Functions without a receiver, which aren't static methods, are free functions
+        } else if foreign_fn.sig.receiver().is_none()
+            && attribute_get_path(&foreign_fn.attrs, &["Self"]).is_none()
+        {
+            let parsed_function =
+                ParsedFunction::parse(foreign_fn, namespace, auto_case, self.unsafety.is_some())?;
+            self.functions.push(parsed_function);
             // Remaining methods are either C++ methods or invokables
         } else {
             let parsed_method =
@@ -211,6 +226,32 @@ mod tests {
         assert!(!methods[1].is_qinvokable);
     }
 
+    #[test]
+    fn test_parse_static_and_free_functions() {
+        let block: ItemForeignMod = parse_quote! {
+            #[namespace = "utils"]
+            extern "RustQt" {
+                #[qinvokable]
+                #[Self = "MyObject"]
+                fn create_default(value: i32) -> i32;
+
+                #[qinvokable]
+                fn helper(value: i32);
+            }
+        };
+        let parsed_rust_qt =
+            ParsedExternRustQt::parse(block, &format_ident!("qobject"), None).unwrap();
+
+        assert_eq!(parsed_rust_qt.methods.len(), 1);
+        assert!(parsed_rust_qt.methods[0].is_static);
+        assert!(!parsed_rust_qt.methods[0].mutable);
+        assert_eq!(parsed_rust_qt.methods[0].qobject_ident, "MyObject");
+        assert_eq!(parsed_rust_qt.methods[0].parameters.len(), 1);
+
+        assert_eq!(parsed_rust_qt.functions.len(), 1);
+        assert_eq!(parsed_rust_qt.functions[0].name.namespace(), Some("utils"));
+    }
+
     #[test]
     fn test_parse_invalid() {
         assert_parse_errors!(
@@ -250,6 +291,57 @@ mod tests {
                 }
             }
 
+            // Static methods cannot have a receiver
+            {
+                extern "RustQt" {
+                    #[Self = "MyObject"]
+                    fn invokable(self: &MyObject);
+                }
+            }
+
+            // Static methods cannot have specifiers
+            {
+                extern "RustQt" {
+                    #[Self = "MyObject"]
+                    #[cxx_virtual]
+                    fn invokable();
+                }
+            }
+
+            // Static methods cannot be wrapped
+            {
+                extern "RustQt" {
+                    #[Self = "MyObject"]
+                    #[auto_wrap]
+                    fn invokable();
+                }
+            }
+
+            // Static invokables cannot return a Result
+            {
+                extern "RustQt" {
+                    #[qinvokable]
+                    #[Self = "MyObject"]
+                    fn invokable() -> Result<i32>;
+                }
+            }
+
+            // Signals cannot have default values
+            {
+                extern "RustQt" {
+                    #[qsignal]
+                    fn ready(self: Pin<&mut MyObject>, #[default = 1] value: i32);
+                }
+            }
+
+            // Free functions must be qinvokable
+            {
+                #[namespace = "utils"]
+                extern "RustQt" {
+                    fn helper();
+                }
+            }
+
             // Unsupported Item
             {
                 extern "RustQt" {
@@ -0,0 +1,176 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    naming::Name,
+    parser::{
+        extract_cfgs, extract_docs, parameter::ParsedFunctionParameter, require_attributes,
+        CaseConversion,
+    },
+    syntax::types,
+};
+use syn::{spanned::Spanned, Attribute, Error, ForeignItemFn, Result};
+
+/// Describes a free `#[qinvokable]` function in an `extern "RustQt"` block,
+/// which belongs to a namespace declared with `qnamespace!`
+pub struct ParsedFunction {
+    /// The original [syn::ForeignItemFn] of the function
+    pub method: ForeignItemFn,
+    /// The name of the function, including the namespace it belongs to
+    pub name: Name,
+    /// The parameters of the function
+    pub parameters: Vec<ParsedFunctionParameter>,
+    /// Whether the function is safe to call
+    pub safe: bool,
+    /// Cfgs for the function
+    pub cfgs: Vec<Attribute>,
+    /// Docs for the function
+    pub docs: Vec<Attribute>,
+    /// Whether the block containing the function is safe or unsafe
+    pub unsafe_block: bool,
+}
+
+impl ParsedFunction {
+    const ALLOWED_ATTRS: [&'static str; 6] = [
+        "cxx_name",
+        "rust_name",
+        "namespace",
+        "qinvokable",
+        "doc",
+        "cfg",
+    ];
+
+    pub fn parse(
+        method: ForeignItemFn,
+        parent_namespace: Option<&str>,
+        auto_case: CaseConversion,
+        unsafe_block: bool,
+    ) -> Result<Self> {
+        let attrs = require_attributes(&method.attrs, &Self::ALLOWED_ATTRS)?;
+        if !attrs.contains_key("qinvokable") {
+            return Err(Error::new(
+                method.sig.span(),
+                "Functions without a `self` receiver must either be a #[qinvokable] free function or a static method with #[Self = \"T\"]!",
+            ));
+        }
+
+        if types::is_result(&method.sig.output) {
+            return Err(Error::new(
+                method.sig.output.span(),
+                "Free #[qinvokable] functions cannot return a Result, as there is no QML engine to throw the error to!",
+            ));
+        }
+
+        let parameters = ParsedFunctionParameter::parse_all_static(&method.sig)?;
+        ParsedFunctionParameter::require_no_defaults(&parameters)?;
+        let name = Name::from_ident_and_attrs(
+            &method.sig.ident,
+            &method.attrs,
+            parent_namespace,
+            None,
+            auto_case,
+        )?;
+        if name.namespace().is_none() {
+            return Err(Error::new(
+                method.sig.ident.span(),
+                "Free #[qinvokable] functions must be in a namespace declared with qnamespace!",
+            ));
+        }
+
+        Ok(Self {
+            parameters,
+            name,
+            safe: method.sig.unsafety.is_none(),
+            cfgs: extract_cfgs(&method.attrs),
+            docs: extract_docs(&method.attrs),
+            method,
+            unsafe_block,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse_errors;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_function() {
+        let function = ParsedFunction::parse(
+            parse_quote! {
+                #[qinvokable]
+                #[namespace = "utils"]
+                fn clamp_value(value: i32, min: i32, max: i32) -> i32;
+            },
+            None,
+            CaseConversion {
+                cxx: Some(convert_case::Case::Camel),
+                rust: None,
+            },
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(function.name.namespace(), Some("utils"));
+        assert_eq!(function.name.cxx_unqualified(), "clampValue");
+        assert_eq!(function.parameters.len(), 3);
+        assert!(function.safe);
+    }
+
+    #[test]
+    fn test_parse_function_parent_namespace() {
+        let function = ParsedFunction::parse(
+            parse_quote! {
+                #[qinvokable]
+                unsafe fn helper();
+            },
+            Some("utils"),
+            CaseConversion::none(),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(function.name.namespace(), Some("utils"));
+        assert!(!function.safe);
+        assert!(function.unsafe_block);
+    }
+
+    #[test]
+    fn test_parse_function_invalid() {
+        assert_parse_errors! {
+            |function| ParsedFunction::parse(function, Some("utils"), CaseConversion::none(), false) =>
+
+            // Not a qinvokable
+            { fn helper(); }
+            // Has a receiver
+            {
+                #[qinvokable]
+                fn helper(self: &MyObject);
+            }
+            // Without a namespace
+            {
+                #[qinvokable]
+                #[namespace = ""]
+                fn helper();
+            }
+            // Invalid attribute
+            {
+                #[qinvokable]
+                #[cxx_override]
+                fn helper();
+            }
+            // Default values
+            {
+                #[qinvokable]
+                fn helper(#[default = 1] value: i32);
+            }
+            // Returns a Result
+            {
+                #[qinvokable]
+                fn helper() -> Result<i32>;
+            }
+        }
+    }
+}
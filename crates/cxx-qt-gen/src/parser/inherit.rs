@@ -4,7 +4,8 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::parser::{
-    extract_cfgs, extract_docs, method::MethodFields, require_attributes, CaseConversion,
+    extract_cfgs, extract_docs, method::MethodFields, parameter::ParsedFunctionParameter,
+    require_attributes, CaseConversion,
 };
 use core::ops::Deref;
 use quote::format_ident;
@@ -36,8 +37,11 @@ impl ParsedInheritedMethod {
         let docs = extract_docs(&method.attrs);
         let cfgs = extract_cfgs(&method.attrs);
 
+        let method_fields = MethodFields::parse(method, auto_case)?;
+        ParsedFunctionParameter::require_no_defaults(&method_fields.parameters)?;
+
         Ok(Self {
-            method_fields: MethodFields::parse(method, auto_case)?,
+            method_fields,
             docs,
             cfgs,
         })
@@ -6,13 +6,13 @@ use crate::parser::{extract_docs, CaseConversion};
 use crate::{
     naming::Name,
     parser::{extract_cfgs, parameter::ParsedFunctionParameter, require_attributes},
-    syntax::{foreignmod, types},
+    syntax::{expr::expr_to_string, foreignmod, types},
 };
 use core::ops::Deref;
 use quote::format_ident;
 use std::collections::{BTreeMap, HashSet};
 use std::ops::DerefMut;
-use syn::{Attribute, ForeignItemFn, Ident, Result};
+use syn::{spanned::Spanned, Attribute, Error, ForeignItemFn, Ident, Result};
 
 /// Describes a C++ specifier for the Q_INVOKABLE
 #[derive(Eq, Hash, PartialEq)]
@@ -59,6 +59,8 @@ pub struct ParsedMethod {
     pub is_qinvokable: bool,
     /// Whether the method is a pure virtual method
     pub is_pure: bool,
+    /// Whether the method is a static method, declared with `#[Self = "T"]` instead of a receiver
+    pub is_static: bool,
     /// Whether to auto generate a wrapper for this method outside the bridge
     pub wrap: bool,
     /// Cfgs for the method
@@ -70,10 +72,11 @@ pub struct ParsedMethod {
 }
 
 impl ParsedMethod {
-    const ALLOWED_ATTRS: [&'static str; 10] = [
+    const ALLOWED_ATTRS: [&'static str; 11] = [
         "cxx_name",
         "rust_name",
         "qinvokable",
+        "Self",
         "cxx_final",
         "cxx_override",
         "cxx_virtual",
@@ -123,10 +126,18 @@ impl ParsedMethod {
         auto_case: CaseConversion,
         unsafe_block: bool,
     ) -> Result<Self> {
-        let fields = MethodFields::parse(method, auto_case)?;
-        let attrs = require_attributes(&fields.method.attrs, &Self::ALLOWED_ATTRS)?;
-        let cfgs = extract_cfgs(&fields.method.attrs);
-        let docs = extract_docs(&fields.method.attrs);
+        let attrs = require_attributes(&method.attrs, &Self::ALLOWED_ATTRS)?;
+        let self_type = attrs
+            .get("Self")
+            .map(|attr| -> Result<Ident> {
+                Ok(format_ident!(
+                    "{}",
+                    expr_to_string(&attr.meta.require_name_value()?.value)?,
+                    span = attr.span()
+                ))
+            })
+            .transpose()?;
+        let is_static = self_type.is_some();
 
         // Determine if the method is invokable
         let is_qinvokable = attrs.contains_key("qinvokable");
@@ -134,11 +145,39 @@ impl ParsedMethod {
         let wrap = attrs.contains_key("auto_wrap");
         let specifiers = ParsedQInvokableSpecifiers::from_attrs(attrs);
 
+        if is_static && !specifiers.is_empty() {
+            return Err(Error::new(
+                method.span(),
+                "Static methods cannot be final, override, virtual or pure!",
+            ));
+        }
+        if is_static && wrap {
+            return Err(Error::new(
+                method.span(),
+                "auto_wrap is not supported on static methods!",
+            ));
+        }
+        if is_static && is_qinvokable && types::is_result(&method.sig.output) {
+            return Err(Error::new(
+                method.sig.output.span(),
+                "Static #[qinvokable] methods cannot return a Result, as there is no QML engine to throw the error to!",
+            ));
+        }
+
+        let fields = if let Some(self_type) = self_type {
+            MethodFields::parse_static(method, self_type, auto_case)?
+        } else {
+            MethodFields::parse(method, auto_case)?
+        };
+        let cfgs = extract_cfgs(&fields.method.attrs);
+        let docs = extract_docs(&fields.method.attrs);
+
         Ok(Self {
             method_fields: fields,
             specifiers,
             is_qinvokable,
             is_pure,
+            is_static,
             wrap,
             cfgs,
             docs,
@@ -147,6 +186,20 @@ impl ParsedMethod {
     }
 }
 
+impl ParsedMethod {
+    /// Whether an error returned by the method can be thrown as a JavaScript error when it is called
+    /// from QML, which is the case for #[qinvokable] methods returning a Result.
+    ///
+    /// The error is only thrown to QML when the QObject is a #[qml_element],
+    /// as the QtQml module is not available otherwise.
+    pub fn throws_to_qml(&self) -> bool {
+        self.is_qinvokable
+            && !self.is_static
+            && self.specifiers.is_empty()
+            && types::is_result(&self.method.sig.output)
+    }
+}
+
 impl Deref for ParsedMethod {
     type Target = MethodFields;
 
@@ -194,6 +247,27 @@ impl MethodFields {
         })
     }
 
+    /// Parse a method without a receiver, which belongs to the given QObject as a static method
+    pub fn parse_static(
+        method: ForeignItemFn,
+        qobject_ident: Ident,
+        auto_case: CaseConversion,
+    ) -> Result<Self> {
+        let parameters = ParsedFunctionParameter::parse_all_static(&method.sig)?;
+        let safe = method.sig.unsafety.is_none();
+        let name =
+            Name::from_ident_and_attrs(&method.sig.ident, &method.attrs, None, None, auto_case)?;
+
+        Ok(MethodFields {
+            method,
+            qobject_ident,
+            mutable: false,
+            parameters,
+            safe,
+            name,
+        })
+    }
+
     pub(crate) fn self_unresolved(&self) -> bool {
         self.qobject_ident == format_ident!("Self")
     }
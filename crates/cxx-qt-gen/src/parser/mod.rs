@@ -8,19 +8,27 @@ pub mod cxxqtdata;
 pub mod externcxxqt;
 pub mod externqobject;
 mod externrustqt;
+pub mod function;
 pub mod inherit;
 pub mod method;
 pub mod parameter;
 pub mod property;
 pub mod qenum;
+pub mod qinterface;
 pub mod qnamespace;
 pub mod qobject;
 pub mod signals;
+pub mod template;
 pub mod trait_impl;
 
 use crate::{
+    metadata::MetadataPaths,
     naming::TypeNames,
-    syntax::{expr::expr_to_string, path::path_compare_str},
+    syntax::{
+        expr::expr_to_string,
+        path::{path_compare_str, path_to_string},
+        suggestion::with_suggestion,
+    },
 };
 use convert_case::Case;
 use cxxqtdata::ParsedCxxQtData;
@@ -105,6 +113,22 @@ pub fn extract_docs(attrs: &[Attribute]) -> Vec<Attribute> {
         .collect()
 }
 
+/// The lines of text in the given Doc attributes, without the single leading space of `///` comments
+pub fn doc_lines(docs: &[Attribute]) -> Vec<String> {
+    docs.iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => expr_to_string(&name_value.value).ok(),
+            _ => None,
+        })
+        .flat_map(|doc| {
+            // An empty `///` line is an empty string, which still needs to be a line
+            doc.split('\n')
+                .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end().to_owned())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 /// Splits a path by :: separators e.g. "cxx_qt::bridge" becomes ["cxx_qt", "bridge"]
 fn split_path(path_str: &str) -> Vec<&str> {
     let path = if path_str.contains("::") {
@@ -129,12 +153,16 @@ pub fn require_attributes<'a>(
         if let Some(index) = index {
             output.insert(allowed[index], attr); // Doesn't error on duplicates
         } else {
-            return Err(Error::new(
-                attr.span(),
+            let path = attr.meta.path();
+            return Err(with_suggestion(
+                attr,
                 format!(
                     "Unsupported attribute! The only attributes allowed on this item are\n{}",
                     allowed.join(", ")
                 ),
+                path.span(),
+                &path_to_string(path),
+                allowed.iter().copied(),
             ));
         }
     }
@@ -236,13 +264,20 @@ impl Parser {
     fn parse_module_contents(
         mut module: ItemMod,
         namespace: Option<String>,
+        metadata_paths: &MetadataPaths,
     ) -> Result<(ParsedCxxQtData, ItemMod)> {
         let mut others = vec![];
 
         let mut cxx_qt_data = ParsedCxxQtData::new(module.ident.clone(), namespace);
+        cxx_qt_data.metadata_paths = metadata_paths.clone();
 
         // Check that there are items in the module
         if let Some((_, items)) = module.content {
+            // Expand any QObject templates into concrete QObjects first
+            let (items, templates) = template::expand_templates(items)?;
+            cxx_qt_data.templates = templates;
+            cxx_qt_data.find_qinterface_idents(&items);
+
             // Loop through items and load into qobject or others and populate mappings
             for item in items.into_iter() {
                 // Try to find any CXX-Qt items, if found add them to the relevant
@@ -280,9 +315,19 @@ impl Parser {
     }
 
     /// Constructs a Parser object from a given [syn::ItemMod] block
-    pub fn from(mut module: ItemMod) -> Result<Self> {
+    ///
+    /// QObjects imported with `#[import = "crate"]` use the metadata in the environment variables
+    /// set by cxx-qt-build.
+    pub fn from(module: ItemMod) -> Result<Self> {
+        Self::from_with_metadata(module, &MetadataPaths::from_env())
+    }
+
+    /// Constructs a Parser object from a given [syn::ItemMod] block,
+    /// using the given paths to the metadata of crates which QObjects are imported from
+    pub fn from_with_metadata(mut module: ItemMod, metadata_paths: &MetadataPaths) -> Result<Self> {
         let namespace = Self::parse_mod_attributes(&mut module)?;
-        let (mut cxx_qt_data, module) = Self::parse_module_contents(module, namespace)?;
+        let (mut cxx_qt_data, module) =
+            Self::parse_module_contents(module, namespace, metadata_paths)?;
         let type_names = Self::naming_phase(
             &mut cxx_qt_data,
             module
@@ -306,6 +351,8 @@ impl Parser {
 mod tests {
     use super::*;
 
+    use crate::parser::trait_impl::TraitKind;
+    use crate::syntax::suggestion::Suggestion;
     use crate::tests::assert_parse_errors;
     use pretty_assertions::assert_eq;
     use quote::format_ident;
@@ -445,6 +492,54 @@ mod tests {
         assert_eq!(parser.cxx_qt_data.qobjects().len(), 1);
     }
 
+    #[test]
+    fn test_require_attributes_suggestion() {
+        let module: ItemMod = parse_quote! {
+            #[qinvokabel]
+            #[cxx_qt::brigde]
+            mod ffi {}
+        };
+        let suggestion = |attr: &Attribute| {
+            let (result, suggestions) = Suggestion::collect(|| {
+                require_attributes(
+                    std::slice::from_ref(attr),
+                    &["qinvokable", "cxx_name", "cxx_qt::bridge"],
+                )
+            });
+            let error = result.unwrap_err();
+            let suggestion = suggestions.into_iter().next()?;
+            assert!(suggestion.is_from(&error));
+            Some(suggestion.replacement)
+        };
+        assert_eq!(suggestion(&module.attrs[0]).as_deref(), Some("qinvokable"));
+        assert_eq!(
+            suggestion(&module.attrs[1]).as_deref(),
+            Some("cxx_qt::bridge")
+        );
+    }
+
+    #[test]
+    fn test_doc_lines() {
+        let module: ItemMod = parse_quote! {
+            /// First line
+            ///
+            ///  Indented line
+            #[doc = "Attribute\nover lines"]
+            #[cxx_qt::bridge]
+            mod ffi {}
+        };
+        assert_eq!(
+            doc_lines(&extract_docs(&module.attrs)),
+            vec![
+                "First line",
+                "",
+                " Indented line",
+                "Attribute",
+                "over lines",
+            ]
+        );
+    }
+
     #[test]
     fn test_parser_invalid() {
         assert_parse_errors! {
@@ -477,9 +572,45 @@ mod tests {
                     }
                 }
             }
+            {
+                // Trait which is not fully-qualified or a #[qinterface]
+                #[cxx_qt::bridge]
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+                    }
+
+                    impl Threading for MyObject {}
+                }
+            }
         }
     }
 
+    #[test]
+    fn test_parser_qinterface_impl_before_declaration() {
+        let module: ItemMod = parse_quote! {
+            #[cxx_qt::bridge]
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                impl Device for MyObject {}
+
+                #[qinterface]
+                trait Device {}
+            }
+        };
+        let parser = Parser::from(module).unwrap();
+        assert_eq!(parser.cxx_qt_data.qinterfaces.len(), 1);
+        assert_eq!(
+            parser.cxx_qt_data.trait_impls[0].kind,
+            TraitKind::Interface(format_ident!("Device"))
+        );
+    }
+
     #[test]
     fn test_cxx_qobject_namespace() {
         let module: ItemMod = parse_quote! {
@@ -3,10 +3,10 @@
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use crate::syntax::types;
+use crate::{parser::require_attributes, syntax::types};
 use syn::{
-    spanned::Spanned, Error, FnArg, Ident, Pat, PatIdent, PatType, Receiver, Result, Signature,
-    Type,
+    spanned::Spanned, Error, Expr, FnArg, Ident, Pat, PatIdent, PatType, Receiver, Result,
+    Signature, Type,
 };
 
 /// Describes a single parameter for a function
@@ -16,6 +16,8 @@ pub struct ParsedFunctionParameter {
     pub ident: Ident,
     /// The [syn::Type] of the parameter
     pub ty: Type,
+    /// The default value of the parameter, from a `#[default = expr]` attribute
+    pub default: Option<Expr>,
 }
 
 impl ParsedFunctionParameter {
@@ -35,6 +37,43 @@ impl ParsedFunctionParameter {
         })
         .filter_map(|result| result.map_or_else(|e| Some(Err(e)), |v| v.map(Ok)))
         .collect::<Result<Vec<ParsedFunctionParameter>>>()
+        .and_then(|parameters| {
+            // As in C++, only the trailing parameters can have a default value
+            if let Some(index) = parameters
+                .iter()
+                .position(|parameter| parameter.default.is_some())
+            {
+                if let Some(parameter) = parameters[index..]
+                    .iter()
+                    .find(|parameter| parameter.default.is_none())
+                {
+                    return Err(Error::new(
+                        parameter.ident.span(),
+                        "Parameters after a parameter with a default value must also have a default value!",
+                    ));
+                }
+            }
+            Ok(parameters)
+        })
+    }
+
+    /// The index of the first parameter with a default value, if any
+    pub fn first_default(parameters: &[ParsedFunctionParameter]) -> Option<usize> {
+        parameters
+            .iter()
+            .position(|parameter| parameter.default.is_some())
+    }
+
+    /// Return an error if any of the parameters has a default value,
+    /// for functions where default values are not supported
+    pub fn require_no_defaults(parameters: &[ParsedFunctionParameter]) -> Result<()> {
+        if let Some(index) = Self::first_default(parameters) {
+            return Err(Error::new(
+                parameters[index].ident.span(),
+                "Default values are only supported on the parameters of methods!",
+            ));
+        }
+        Ok(())
     }
 
     pub fn parse_all_ignoring_receiver(
@@ -52,6 +91,19 @@ impl ParsedFunctionParameter {
         Self::parse_remaining(iter)
     }
 
+    /// This function parses the list of arguments of a function without any receiver,
+    /// such as a static method or a free function
+    pub fn parse_all_static(signature: &Signature) -> Result<Vec<ParsedFunctionParameter>> {
+        if let Some(receiver) = signature.receiver() {
+            return Err(Error::new_spanned(
+                receiver,
+                "Unexpected self receiver on a static or free function!",
+            ));
+        }
+
+        Self::parse_remaining(signature.inputs.iter())
+    }
+
     /// This function parses the list of arguments
     pub fn parse_all_without_receiver(
         signature: &Signature,
@@ -86,9 +138,16 @@ impl ParsedFunctionParameter {
             ));
         };
 
+        let attrs = require_attributes(&type_pattern.attrs, &["default"])?;
+        let default = attrs
+            .get("default")
+            .map(|attr| -> Result<Expr> { Ok(attr.meta.require_name_value()?.value.clone()) })
+            .transpose()?;
+
         Ok(ParsedFunctionParameter {
             ident,
             ty: (*type_pattern.ty).clone(),
+            default,
         })
     }
 }
@@ -172,4 +231,38 @@ mod tests {
 
         assert!(ParsedFunctionParameter::parse_all_ignoring_receiver(&function.sig).is_err())
     }
+
+    #[test]
+    fn test_parse_default() {
+        let function: ForeignItemFn = syn::parse_quote! {
+            fn foo(self: &T, a: i32, #[default = 1] b: i32, #[default = String::new()] c: String);
+        };
+
+        let parameters =
+            ParsedFunctionParameter::parse_all_ignoring_receiver(&function.sig).unwrap();
+        assert_eq!(parameters.len(), 3);
+        assert!(parameters[0].default.is_none());
+        assert_eq!(parameters[1].default.to_token_stream().to_string(), "1");
+        assert_eq!(
+            parameters[2].default.to_token_stream().to_string(),
+            "String :: new ()"
+        );
+        assert_eq!(ParsedFunctionParameter::first_default(&parameters), Some(1));
+        assert!(ParsedFunctionParameter::require_no_defaults(&parameters).is_err());
+        assert!(ParsedFunctionParameter::require_no_defaults(&parameters[..1]).is_ok());
+    }
+
+    #[test]
+    fn test_parse_default_invalid() {
+        assert_parse_errors! {
+            |function: ForeignItemFn| ParsedFunctionParameter::parse_all_ignoring_receiver(&function.sig) =>
+
+            // Default values must be trailing
+            { fn foo(self: &T, #[default = 1] a: i32, b: i32); }
+            // Default value without a value
+            { fn foo(self: &T, #[default] a: i32); }
+            // Unknown attribute
+            { fn foo(self: &T, #[cxx_name = "b"] a: i32); }
+        }
+    }
 }
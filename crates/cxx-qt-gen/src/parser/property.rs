@@ -5,7 +5,7 @@
 
 use crate::naming::Name;
 use crate::parser::CaseConversion;
-use crate::syntax::expr::expr_to_string;
+use crate::syntax::{expr::expr_to_string, suggestion::with_suggestion};
 use syn::{
     parse::{Error, ParseStream},
     punctuated::Punctuated,
@@ -14,7 +14,8 @@ use syn::{
 };
 
 use crate::generator::naming::property::{
-    getter_name_from_property, notify_name_from_property, setter_name_from_property,
+    getter_name_from_property, notify_name_from_property, reset_name_from_property,
+    setter_name_from_property,
 };
 #[cfg(test)]
 use syn::ItemStruct;
@@ -28,13 +29,37 @@ pub enum FlagState {
     Custom(Ident),
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Enum representing how generated setters and resets decide whether the value has changed
+pub enum QPropertyComparison {
+    /// The values are compared with [PartialEq], this is the default
+    #[default]
+    PartialEq,
+    /// The value is always considered changed, passed as `always_notify`
+    AlwaysNotify,
+    /// The floating point values are compared like `qFuzzyCompare`, passed as `fuzzy_compare`
+    Fuzzy,
+    /// The values are compared with a user method on the QObject, passed as `compare = my_compare`
+    Custom(Ident),
+}
+
 /// Struct for storing the flags provided for a QProperty
 #[derive(Debug)]
 pub struct QPropertyFlags {
     pub(crate) read: FlagState,
     pub(crate) write: Option<FlagState>,
     pub(crate) notify: Option<FlagState>,
-    pub(crate) reset: Option<Ident>, // TODO: in future might be able to generate the function if T has a default
+    pub(crate) reset: Option<FlagState>,
+    /// The value an automatically generated reset uses, otherwise the value from the [Default] of the Rust struct
+    pub(crate) reset_value: Option<Expr>,
+    /// A function called by the automatically generated setter to validate or adjust a new value
+    pub(crate) validate: Option<Ident>,
+    /// A function called by the automatically generated setter after the value has changed
+    pub(crate) on_changed: Option<Ident>,
+    /// How the automatically generated setter and reset check if the value changed
+    pub(crate) comparison: QPropertyComparison,
+    /// The properties whose notify signals also emit the notify signal of this property
+    pub(crate) depends_on: Vec<Ident>,
     pub(crate) is_final: bool,
     pub(crate) constant: bool,
     pub(crate) required: bool,
@@ -48,6 +73,11 @@ impl Default for QPropertyFlags {
             write: Some(FlagState::Auto),
             notify: Some(FlagState::Auto),
             reset: None,
+            reset_value: None,
+            validate: None,
+            on_changed: None,
+            comparison: QPropertyComparison::default(),
+            depends_on: vec![],
             is_final: false,
             constant: false,
             required: false,
@@ -63,6 +93,8 @@ pub struct ParsedQProperty {
     pub ty: Type,
     /// Property flag collection
     pub flags: QPropertyFlags,
+    /// The doc comments directly before the #[qproperty] attribute
+    pub docs: Vec<Attribute>,
 }
 
 fn parse_meta_name_value(name_value: &MetaNameValue) -> Result<(Ident, Ident)> {
@@ -105,6 +137,26 @@ fn parse_meta(meta: Meta) -> Result<(Ident, Option<Ident>)> {
 }
 
 impl ParsedQProperty {
+    /// Every flag which can be passed to #[qproperty], used to suggest the intended flag for typos
+    const FLAGS: [&'static str; 16] = [
+        "READ",
+        "WRITE",
+        "NOTIFY",
+        "RESET",
+        "CONSTANT",
+        "REQUIRED",
+        "FINAL",
+        "cxx_name",
+        "rust_name",
+        "default",
+        "validate",
+        "on_changed",
+        "compare",
+        "always_notify",
+        "fuzzy_compare",
+        "depends_on",
+    ];
+
     pub fn parse(attr: &Attribute, auto_case: CaseConversion) -> Result<Self> {
         attr.parse_args_with(|input: ParseStream| -> Result<Self> {
             let ty = input.parse()?;
@@ -117,6 +169,7 @@ impl ParsedQProperty {
                     name: Name::new(ident).with_options(None, None, auto_case),
                     ty,
                     flags: QPropertyFlags::default(),
+                    docs: vec![],
                 })
             } else {
                 let _comma = input.parse::<Token![,]>()?; // Start of final identifiers
@@ -136,6 +189,11 @@ impl ParsedQProperty {
                 let mut required = false;
                 let mut is_final = false;
                 let mut reset = None;
+                let mut reset_value = None;
+                let mut validate = None;
+                let mut on_changed = None;
+                let mut comparison = None;
+                let mut depends_on = vec![];
                 let mut cxx_name = None;
                 let mut rust_name = None;
 
@@ -155,10 +213,34 @@ impl ParsedQProperty {
                         ).transpose()
                     };
 
+                    // Closure for the comparison flags, of which only one can be passed
+                    let mut set_comparison = |new: QPropertyComparison| -> Result<()> {
+                        if comparison.replace(new).is_some() {
+                            return Err(Error::new(
+                                ident.span(),
+                                "Only one of always_notify, fuzzy_compare or compare can be passed!",
+                            ));
+                        }
+                        Ok(())
+                    };
+
                     // Matches the flag, and if it is a QProperty flag instead of a naming flag set flag to ensure READ was passed
                     match ident.to_string().as_str() {
                         "cxx_name" => cxx_name = require_value("cxx_name", "cxx_name = \"myName\"")?,
                         "rust_name" => rust_name = require_value("rust_name", "rust_name = \"my_name\"")?,
+                        "validate" => validate = require_value("validate", "validate = my_validator")?,
+                        "on_changed" => on_changed = require_value("on_changed", "on_changed = my_on_changed")?,
+                        "compare" => {
+                            if let Some(compare) = require_value("compare", "compare = my_compare")? {
+                                set_comparison(QPropertyComparison::Custom(compare))?
+                            }
+                        }
+                        "always_notify" | "fuzzy_compare" if value.is_some() => return Err(Error::new(
+                            ident.span(),
+                            format!("{ident} does not take a value!"),
+                        )),
+                        "always_notify" => set_comparison(QPropertyComparison::AlwaysNotify)?,
+                        "fuzzy_compare" => set_comparison(QPropertyComparison::Fuzzy)?,
                         property_flag => {
                             read_required = true;
                             match property_flag {
@@ -168,10 +250,13 @@ impl ParsedQProperty {
                                 "CONSTANT" => constant = true,
                                 "REQUIRED" => required = true,
                                 "FINAL" => is_final = true,
-                                "RESET" => reset = require_value("RESET flag", "RESET = my_reset_fn")?,
-                                _ => return Err(Error::new(
+                                "RESET" => map_auto_or_custom(&mut reset, &value),
+                                _ => return Err(with_suggestion(
+                                    ident,
+                                    "Invalid flag passed!, must be one of\n  READ, WRITE, NOTIFY, RESET, CONSTANT, REQUIRED, FINAL or cxx_name / rust_name / default / validate / on_changed / compare / always_notify / fuzzy_compare / depends_on",
                                     ident.span(),
-                                    "Invalid flag passed!, must be one of\n  READ, WRITE, NOTIFY, RESET, CONSTANT, REQUIRED, FINAL or cxx_name / rust_name",
+                                    property_flag,
+                                    Self::FLAGS,
                                 ))
                             }
                         }
@@ -181,10 +266,70 @@ impl ParsedQProperty {
                 };
 
                 for flag in flags {
+                    // The default value is an expression rather than a function, so handle it separately
+                    if let Meta::NameValue(name_value) = &flag {
+                        if name_value.path.is_ident("default") {
+                            reset_value = Some(name_value.value.clone());
+                            continue;
+                        }
+                    }
+
+                    // The dependencies are a list of properties, so handle them separately
+                    if let Meta::List(list) = &flag {
+                        if list.path.is_ident("depends_on") {
+                            let dependencies = list.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+                            if dependencies.is_empty() {
+                                return Err(Error::new(
+                                    list.span(),
+                                    "depends_on needs a list of properties passed like `depends_on(first, last)`!",
+                                ));
+                            }
+                            depends_on.extend(dependencies);
+                            continue;
+                        }
+                    }
+
                     let (field, maybe_value) = parse_meta(flag)?;
                     update_fields(&field, maybe_value)?;
                 }
 
+                if reset_value.is_some() && reset != Some(FlagState::Auto) {
+                    return Err(Error::new(
+                        punctuated_flags.span(),
+                        "A default value can only be used with an automatically generated reset, use `RESET` without a function!",
+                    ))
+                }
+
+                // Hooks are called by the generated setter, so there must be one
+                let has_hooks = validate.is_some() || on_changed.is_some();
+                if has_hooks && read_required && write != Some(FlagState::Auto) {
+                    return Err(Error::new(
+                        punctuated_flags.span(),
+                        "validate and on_changed can only be used with an automatically generated setter, use `WRITE` without a function!",
+                    ))
+                }
+
+                // The comparison is only used by the generated setter and reset
+                if comparison.is_some()
+                    && read_required
+                    && write != Some(FlagState::Auto)
+                    && reset != Some(FlagState::Auto)
+                {
+                    return Err(Error::new(
+                        punctuated_flags.span(),
+                        "always_notify, fuzzy_compare and compare can only be used with an automatically generated setter or reset!",
+                    ))
+                }
+                let comparison = comparison.unwrap_or_default();
+
+                // The dependencies emit the notify signal of this property, so there must be one
+                if !depends_on.is_empty() && read_required && notify.is_none() {
+                    return Err(Error::new(
+                        punctuated_flags.span(),
+                        "depends_on can only be used with properties that have a NOTIFY signal!",
+                    ))
+                }
+
                 // Constance check
                 if constant && (write.is_some() || notify.is_some()) {
                     return Err(Error::new(
@@ -206,10 +351,16 @@ impl ParsedQProperty {
                                 write,
                                 notify,
                                 reset,
+                                reset_value,
+                                validate,
+                                on_changed,
+                                comparison,
+                                depends_on,
                                 is_final,
                                 constant,
                                 required,
                             },
+                            docs: vec![],
                         })
                     } else {
                         Err(Error::new(
@@ -221,7 +372,15 @@ impl ParsedQProperty {
                     Ok(Self {
                         name,
                         ty,
-                        flags: QPropertyFlags::default(), // This block is hit if no flags, or only cxx / rust name were passed
+                        // This block is hit if no flags, or only cxx / rust name or hooks were passed
+                        flags: QPropertyFlags {
+                            validate,
+                            on_changed,
+                            comparison,
+                            depends_on,
+                            ..QPropertyFlags::default()
+                        },
+                        docs: vec![],
                     })
                 }
             }
@@ -240,6 +399,10 @@ impl ParsedQProperty {
             pending.push(setter_name_from_property(&self.name));
         }
 
+        if let Some(FlagState::Auto) = &self.flags.reset {
+            pending.push(reset_name_from_property(&self.name));
+        }
+
         pending
     }
 
@@ -267,6 +430,7 @@ fn mock_property_case(mut input: ItemStruct, auto_case: CaseConversion) -> Parse
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::syntax::suggestion::Suggestion;
     use crate::tests::assert_parse_errors;
     use convert_case::Case;
     use quote::format_ident;
@@ -346,8 +510,30 @@ mod tests {
 
             // Non-constant property with constant flag
             { #[qproperty(T, name, READ, WRITE, NOTIFY, CONSTANT)] }
-            // Reset was not provided a function
-            { #[qproperty(T, name, READ, RESET)] }
+            // Default value without an automatic reset
+            { #[qproperty(T, name, READ, default = 1)] }
+            // Default value with a custom reset
+            { #[qproperty(T, name, READ, RESET = my_reset, default = 1)] }
+            // Hooks without a setter
+            { #[qproperty(T, name, READ, validate = my_validator)] }
+            // Hooks with a custom setter
+            { #[qproperty(T, name, READ, WRITE = my_setter, on_changed = my_on_changed)] }
+            // Hook without a function
+            { #[qproperty(T, name, READ, WRITE, on_changed)] }
+            // Multiple comparisons
+            { #[qproperty(T, name, always_notify, fuzzy_compare)] }
+            // Comparison without a setter or reset
+            { #[qproperty(T, name, READ, NOTIFY, always_notify)] }
+            // Comparison flag with a value
+            { #[qproperty(T, name, fuzzy_compare = my_compare)] }
+            // Custom comparison without a function
+            { #[qproperty(T, name, compare)] }
+            // Dependencies without a notify signal
+            { #[qproperty(T, name, READ, depends_on(first))] }
+            // Empty dependencies
+            { #[qproperty(T, name, READ, NOTIFY, depends_on())] }
+            // Dependencies which are not properties
+            { #[qproperty(T, name, READ, NOTIFY, depends_on(1, 2))] }
             // Unknown flag
             { #[qproperty(T, name, READ = blah, a, NOTIFY = blahblah)] }
             // Invalid function specification syntax
@@ -373,6 +559,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_invalid_flag_suggestion() {
+        let suggestion = |attr: Attribute| {
+            let (result, suggestions) =
+                Suggestion::collect(|| ParsedQProperty::parse(&attr, CaseConversion::none()));
+            let error = result.err()?;
+            let suggestion = suggestions.into_iter().next()?;
+            assert!(suggestion.is_from(&error));
+            Some(suggestion.replacement)
+        };
+        assert_eq!(
+            suggestion(parse_quote! { #[qproperty(T, name, read, WRTIE)] }).as_deref(),
+            Some("READ")
+        );
+        assert_eq!(
+            suggestion(parse_quote! { #[qproperty(T, name, READ, WRTIE)] }).as_deref(),
+            Some("WRITE")
+        );
+        assert_eq!(
+            suggestion(parse_quote! { #[qproperty(T, name, READ, fuzy_compare)] }).as_deref(),
+            Some("fuzzy_compare")
+        );
+        assert_eq!(
+            suggestion(parse_quote! { #[qproperty(T, name, READ, unrelated)] }),
+            None
+        );
+    }
+
     #[test]
     fn test_parse_constant() {
         let input: ItemStruct = parse_quote! {
@@ -424,9 +638,115 @@ mod tests {
         assert!(property.flags.required);
         assert!(property.flags.is_final);
 
-        assert_eq!(property.flags.reset, Some(format_ident!("my_reset")));
+        assert_eq!(
+            property.flags.reset,
+            Some(FlagState::Custom(format_ident!("my_reset")))
+        );
+        assert_eq!(property.flags.notify, Some(FlagState::Auto));
+        assert_eq!(property.flags.write, Some(FlagState::Auto));
+    }
+
+    #[test]
+    fn test_parse_flags_reset_auto() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, READ, WRITE, RESET)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.reset, Some(FlagState::Auto));
+        assert!(property.flags.reset_value.is_none());
+
+        let pending = property.pending_methods();
+        assert_eq!(pending.len(), 3);
+        assert_eq!(pending[2].rust_unqualified(), "reset_name");
+        assert_eq!(pending[2].cxx_unqualified(), "resetName");
+    }
+
+    #[test]
+    fn test_parse_flags_reset_default() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(i32, name, READ, WRITE, RESET, default = 42)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.reset, Some(FlagState::Auto));
+        assert_eq!(property.flags.reset_value, Some(parse_quote! { 42 }));
+    }
+
+    #[test]
+    fn test_parse_hooks() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(i32, name, validate = my_validator, on_changed = my_on_changed)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.write, Some(FlagState::Auto));
         assert_eq!(property.flags.notify, Some(FlagState::Auto));
+        assert_eq!(property.flags.validate, Some(format_ident!("my_validator")));
+        assert_eq!(
+            property.flags.on_changed,
+            Some(format_ident!("my_on_changed"))
+        );
+
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(i32, name, READ, WRITE, on_changed = my_on_changed)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert!(property.flags.notify.is_none());
+        assert!(property.flags.validate.is_none());
+        assert_eq!(
+            property.flags.on_changed,
+            Some(format_ident!("my_on_changed"))
+        );
+    }
+
+    #[test]
+    fn test_parse_comparison() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.comparison, QPropertyComparison::PartialEq);
+
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, always_notify)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
         assert_eq!(property.flags.write, Some(FlagState::Auto));
+        assert_eq!(property.flags.comparison, QPropertyComparison::AlwaysNotify);
+
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(f64, name, READ, WRITE, NOTIFY, fuzzy_compare)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(property.flags.comparison, QPropertyComparison::Fuzzy);
+
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(T, name, READ, RESET, compare = my_compare)]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(
+            property.flags.comparison,
+            QPropertyComparison::Custom(format_ident!("my_compare"))
+        );
+    }
+
+    #[test]
+    fn test_parse_depends_on() {
+        let input: ItemStruct = parse_quote! {
+            #[qproperty(QString, full_name, READ = full_name, NOTIFY, depends_on(first, last))]
+            struct MyStruct;
+        };
+        let property = mock_property(input);
+        assert_eq!(
+            property.flags.depends_on,
+            vec![format_ident!("first"), format_ident!("last")]
+        );
     }
 
     #[test]
@@ -0,0 +1,288 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    naming::Name,
+    parser::{
+        extract_docs,
+        method::{ParsedMethod, ParsedQInvokableSpecifiers},
+        parameter::ParsedFunctionParameter,
+        require_attributes,
+        signals::ParsedSignal,
+        CaseConversion,
+    },
+    syntax::attribute::attribute_get_path,
+};
+use quote::format_ident;
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Error, ForeignItemFn, Ident, ItemTrait, Result,
+    TraitItem, TraitItemFn,
+};
+
+/// A trait in the bridge which is exposed to C++ as an abstract QObject subclass
+pub struct ParsedQInterface {
+    /// The name of the interface, which is used for both the Rust trait and the C++ class
+    pub name: Name,
+    /// The original trait declared in the bridge
+    pub declaration: ItemTrait,
+    /// The pure virtual methods of the interface
+    pub methods: Vec<ParsedMethod>,
+    /// The signals declared by the interface
+    pub signals: Vec<ParsedSignal>,
+    /// Docs for the interface
+    pub docs: Vec<Attribute>,
+}
+
+impl ParsedQInterface {
+    const ALLOWED_ATTRS: [&'static str; 4] = ["qinterface", "cxx_name", "namespace", "doc"];
+    const ALLOWED_ITEM_ATTRS: [&'static str; 5] =
+        ["qinvokable", "qsignal", "cxx_name", "rust_name", "doc"];
+
+    pub fn parse(
+        declaration: ItemTrait,
+        module_ident: &Ident,
+        parent_namespace: Option<&str>,
+    ) -> Result<Self> {
+        require_attributes(&declaration.attrs, &Self::ALLOWED_ATTRS)?;
+
+        if let Some(unsafety) = &declaration.unsafety {
+            return Err(Error::new_spanned(
+                unsafety,
+                "#[qinterface] traits cannot be unsafe!",
+            ));
+        }
+        if !declaration.generics.params.is_empty() || declaration.generics.where_clause.is_some() {
+            return Err(Error::new_spanned(
+                &declaration.generics,
+                "#[qinterface] traits cannot be generic!",
+            ));
+        }
+        if !declaration.supertraits.is_empty() {
+            return Err(Error::new_spanned(
+                &declaration.supertraits,
+                "#[qinterface] traits cannot have supertraits, they always derive from QObject!",
+            ));
+        }
+
+        let name = Name::from_ident_and_attrs(
+            &declaration.ident,
+            &declaration.attrs,
+            parent_namespace,
+            Some(module_ident),
+            CaseConversion::none(),
+        )?;
+
+        let mut interface = Self {
+            name,
+            methods: vec![],
+            signals: vec![],
+            docs: extract_docs(&declaration.attrs),
+            declaration: declaration.clone(),
+        };
+
+        for item in declaration.items {
+            match item {
+                TraitItem::Fn(trait_fn) => interface.parse_fn(trait_fn)?,
+                other => {
+                    return Err(Error::new_spanned(
+                        other,
+                        "Only functions are supported in #[qinterface] traits!",
+                    ))
+                }
+            }
+        }
+
+        Ok(interface)
+    }
+
+    fn parse_fn(&mut self, trait_fn: TraitItemFn) -> Result<()> {
+        require_attributes(&trait_fn.attrs, &Self::ALLOWED_ITEM_ATTRS)?;
+
+        if let Some(default) = &trait_fn.default {
+            return Err(Error::new(
+                default.span(),
+                "#[qinterface] functions cannot have a default implementation!",
+            ));
+        }
+
+        let attrs = &trait_fn.attrs;
+        let sig = &trait_fn.sig;
+        let foreign_fn: ForeignItemFn = parse_quote! {
+            #(#attrs)*
+            #sig;
+        };
+
+        if attribute_get_path(&foreign_fn.attrs, &["qsignal"]).is_some() {
+            let mut signal =
+                ParsedSignal::parse_rust_qt_signal(foreign_fn, CaseConversion::none())?;
+            signal.qobject_ident = self.name.rust_unqualified().clone();
+            self.signals.push(signal);
+        } else {
+            let mut method = ParsedMethod::parse(foreign_fn, CaseConversion::none(), false)?;
+            // Overloads for default values cannot be pure virtual
+            ParsedFunctionParameter::require_no_defaults(&method.parameters)?;
+            method.qobject_ident = self.name.rust_unqualified().clone();
+            // Methods of the interface are pure virtual in C++ and implemented by each QObject
+            method.specifiers = [
+                ParsedQInvokableSpecifiers::Virtual,
+                ParsedQInvokableSpecifiers::Pure,
+            ]
+            .into();
+            method.is_pure = true;
+            self.methods.push(method);
+        }
+
+        Ok(())
+    }
+
+    /// Create the overriding methods of the interface for the QObject implementing it
+    ///
+    /// The Rust name of each method is mangled, as the generated method forwards to the trait.
+    pub fn implementation_methods(&self, qobject_ident: &Ident) -> Vec<ParsedMethod> {
+        self.methods
+            .iter()
+            .map(|method| {
+                let mut fields = method.method_fields.clone();
+                fields.qobject_ident = qobject_ident.clone();
+                fields.name = Name::new(self.implementation_ident(method))
+                    .with_cxx_name(method.name.cxx_unqualified());
+
+                ParsedMethod {
+                    method_fields: fields,
+                    specifiers: [ParsedQInvokableSpecifiers::Override].into(),
+                    is_qinvokable: method.is_qinvokable,
+                    is_pure: false,
+                    is_static: false,
+                    wrap: false,
+                    cfgs: vec![],
+                    docs: vec![],
+                    unsafe_block: false,
+                }
+            })
+            .collect()
+    }
+
+    /// The Rust ident of the method which forwards from the C++ override to the trait
+    pub fn implementation_ident(&self, method: &ParsedMethod) -> Ident {
+        format_ident!(
+            "cxx_qt_ffi_{interface}_{method}",
+            interface = self.name.rust_unqualified(),
+            method = method.name.rust_unqualified()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::assert_parse_errors;
+
+    fn parse(declaration: ItemTrait) -> Result<ParsedQInterface> {
+        ParsedQInterface::parse(declaration, &format_ident!("ffi"), Some("cxx_qt"))
+    }
+
+    #[test]
+    fn test_parse_qinterface() {
+        let interface = parse(parse_quote! {
+            /// A device
+            #[qinterface]
+            trait Device {
+                #[qinvokable]
+                fn connect(self: Pin<&mut Self>, address: &QString) -> bool;
+
+                #[cxx_name = "isConnected"]
+                fn is_connected(&self) -> bool;
+
+                #[qsignal]
+                #[cxx_name = "stateChanged"]
+                fn state_changed(self: Pin<&mut Self>);
+            }
+        })
+        .unwrap();
+
+        assert_eq!(interface.name.rust_unqualified(), "Device");
+        assert_eq!(interface.name.namespace(), Some("cxx_qt"));
+        assert_eq!(interface.docs.len(), 1);
+
+        assert_eq!(interface.methods.len(), 2);
+        assert!(interface.methods[0].is_qinvokable);
+        assert!(interface.methods[0].mutable);
+        assert_eq!(interface.methods[0].qobject_ident, "Device");
+        assert_eq!(interface.methods[0].parameters.len(), 1);
+        assert!(interface.methods[0].is_pure);
+        assert!(!interface.methods[1].is_qinvokable);
+        assert!(!interface.methods[1].mutable);
+        assert_eq!(interface.methods[1].name.cxx_unqualified(), "isConnected");
+
+        assert_eq!(interface.signals.len(), 1);
+        assert_eq!(interface.signals[0].qobject_ident, "Device");
+        assert_eq!(interface.signals[0].name.cxx_unqualified(), "stateChanged");
+    }
+
+    #[test]
+    fn test_implementation_methods() {
+        let interface = parse(parse_quote! {
+            #[qinterface]
+            trait Device {
+                #[qinvokable]
+                fn connect(self: Pin<&mut Self>);
+            }
+        })
+        .unwrap();
+
+        let methods = interface.implementation_methods(&format_ident!("Printer"));
+        assert_eq!(methods.len(), 1);
+        assert_eq!(methods[0].qobject_ident, "Printer");
+        assert_eq!(
+            methods[0].name.rust_unqualified(),
+            "cxx_qt_ffi_Device_connect"
+        );
+        assert_eq!(methods[0].name.cxx_unqualified(), "connect");
+        assert!(methods[0].is_qinvokable);
+        assert!(!methods[0].is_pure);
+        assert!(methods[0]
+            .specifiers
+            .contains(&ParsedQInvokableSpecifiers::Override));
+    }
+
+    #[test]
+    fn test_parse_qinterface_invalid() {
+        assert_parse_errors! {
+            parse =>
+
+            { #[qinterface] unsafe trait Device {} }
+            { #[qinterface] trait Device<T> {} }
+            { #[qinterface] trait Device: Clone {} }
+            { #[qinterface] #[qml_element] trait Device {} }
+            {
+                #[qinterface]
+                trait Device {
+                    const VALUE: i32;
+                }
+            }
+            {
+                #[qinterface]
+                trait Device {
+                    fn connect(self: Pin<&mut Self>) {}
+                }
+            }
+            {
+                #[qinterface]
+                trait Device {
+                    #[cxx_override]
+                    fn connect(self: Pin<&mut Self>);
+                }
+            }
+            {
+                // Signals must be mutable
+                #[qinterface]
+                trait Device {
+                    #[qsignal]
+                    fn ready(&self);
+                }
+            }
+        }
+    }
+}
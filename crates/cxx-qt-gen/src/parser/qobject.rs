@@ -44,6 +44,8 @@ pub struct ParsedQObject {
     pub declaration: ForeignTypeIdentAlias,
     /// Cfgs for the object
     pub cfgs: Vec<Attribute>,
+    /// Doc comments for the object, excluding the ones documenting a property
+    pub docs: Vec<Attribute>,
 }
 
 impl ParsedQObject {
@@ -75,6 +77,7 @@ impl ParsedQObject {
                 ident_right: format_ident!("MyObjectRust"),
             },
             cfgs: vec![],
+            docs: vec![],
         }
     }
 
@@ -86,7 +89,6 @@ impl ParsedQObject {
         auto_case: CaseConversion,
     ) -> Result<Self> {
         let attributes = require_attributes(&declaration.attrs, &Self::ALLOWED_ATTRS)?;
-        // TODO: handle docs through to generation
         let cfgs = extract_cfgs(&declaration.attrs);
 
         let has_qobject_macro = attributes.contains_key("qobject");
@@ -114,7 +116,7 @@ impl ParsedQObject {
 
         // Parse any properties in the type
         // and remove the #[qproperty] attribute
-        let properties = Self::parse_property_attributes(&declaration.attrs, auto_case)?;
+        let (properties, docs) = Self::parse_property_attributes(&declaration.attrs, auto_case)?;
         let inner = declaration.ident_right.clone();
 
         Ok(Self {
@@ -126,6 +128,7 @@ impl ParsedQObject {
             qml_metadata,
             has_qobject_macro,
             cfgs,
+            docs,
         })
     }
 
@@ -149,18 +152,30 @@ impl ParsedQObject {
         Ok(None)
     }
 
+    /// Parse the #[qproperty] attributes, returning the properties and the doc comments of the object
+    ///
+    /// Doc comments directly before a #[qproperty] attribute document that property,
+    /// any other doc comments document the object itself.
     fn parse_property_attributes(
         attrs: &[Attribute],
         auto_case: CaseConversion,
-    ) -> Result<Vec<ParsedQProperty>> {
-        // Once extract_if is stable, this would allow comparing all the elements using
-        // path_compare_str and building ParsedQProperty from the extracted elements.
-        // https://doc.rust-lang.org/nightly/std/vec/struct.Vec.html#method.extract_if
-        attrs
-            .iter()
-            .filter(|attr| path_compare_str(attr.meta.path(), &["qproperty"]))
-            .map(|attr| ParsedQProperty::parse(attr, auto_case))
-            .collect::<Result<Vec<_>>>()
+    ) -> Result<(Vec<ParsedQProperty>, Vec<Attribute>)> {
+        let mut properties = vec![];
+        let mut docs = vec![];
+        let mut pending_docs = vec![];
+        for attr in attrs {
+            if path_compare_str(attr.meta.path(), &["doc"]) {
+                pending_docs.push(attr.clone());
+            } else if path_compare_str(attr.meta.path(), &["qproperty"]) {
+                let mut property = ParsedQProperty::parse(attr, auto_case)?;
+                property.docs = std::mem::take(&mut pending_docs);
+                properties.push(property);
+            } else {
+                docs.append(&mut pending_docs);
+            }
+        }
+        docs.append(&mut pending_docs);
+        Ok((properties, docs))
     }
 }
 
@@ -258,6 +273,23 @@ pub mod tests {
         assert_eq!(properties[1].ty, f64_type());
     }
 
+    #[test]
+    fn test_parse_docs() {
+        let qobject = parse_qobject! {
+            /// The object
+            #[qobject]
+            /// The first property
+            /// over two lines
+            #[qproperty(f64, first)]
+            #[qproperty(f64, second)]
+            /// The object again
+            type T = super::TRust;
+        };
+        assert_eq!(qobject.docs.len(), 2);
+        assert_eq!(qobject.properties[0].docs.len(), 2);
+        assert!(qobject.properties[1].docs.is_empty());
+    }
+
     fn assert_qml_name(obj: ParsedQObject, str_name: &str) {
         assert_eq!(
             obj.qml_metadata,
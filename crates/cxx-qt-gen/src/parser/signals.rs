@@ -4,7 +4,10 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 use crate::parser::CaseConversion;
 use crate::{
-    parser::{extract_cfgs, extract_docs, method::MethodFields, require_attributes},
+    parser::{
+        extract_cfgs, extract_docs, method::MethodFields, parameter::ParsedFunctionParameter,
+        require_attributes,
+    },
     syntax::path::path_compare_str,
 };
 use core::ops::Deref;
@@ -51,6 +54,7 @@ impl ParsedSignal {
         let cfgs = extract_cfgs(&method.attrs);
         let fields = MethodFields::parse(method, auto_case)?;
         let attrs = require_attributes(&fields.method.attrs, &Self::ALLOWED_ATTRS)?;
+        ParsedFunctionParameter::require_no_defaults(&fields.parameters)?;
 
         if matches!(immutability_allowed, ImmutabilityConstraint::Disallowed) && !fields.mutable {
             return Err(Error::new(
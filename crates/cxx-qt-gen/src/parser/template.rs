@@ -0,0 +1,724 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use crate::{
+    naming::template::template_instance_rust_ident, syntax::attribute::attribute_get_path,
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::BTreeMap;
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    spanned::Spanned,
+    Attribute, Error, FnArg, ForeignItem, ForeignItemFn, GenericArgument, GenericParam, Generics,
+    Ident, Item, ItemForeignMod, ItemImpl, ItemType, Meta, Path, PathArguments, Result, ReturnType,
+    Signature, Token, Type, Visibility,
+};
+
+/// The declaration of a QObject template, eg `type RangeModel<T> = super::RangeModelRust<T>;`
+struct TemplateDeclaration {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    generics: Generics,
+    rust_path: Path,
+}
+
+impl Parse for TemplateDeclaration {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let _: Token![type] = input.parse()?;
+        let ident = input.parse()?;
+        let generics: Generics = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let rust_path = input.parse()?;
+        let _: Token![;] = input.parse()?;
+
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            generics,
+            rust_path,
+        })
+    }
+}
+
+/// A QObject template after expansion, used to generate the Rust trait for its methods
+pub struct ParsedTemplate {
+    /// The name of the template, which is also the name of the trait
+    pub ident: Ident,
+    /// The visibility of the template
+    pub vis: Visibility,
+    /// The type parameters of the template
+    pub params: Vec<Ident>,
+    /// The methods of the template which are implemented in Rust, with the template replaced by `Self`
+    pub methods: Vec<ForeignItemFn>,
+    /// The instantiations of the template
+    pub instances: Vec<TemplateInstance>,
+}
+
+/// An instantiation of a QObject template, eg `type IntRange = RangeModel<i32>;`
+pub struct TemplateInstance {
+    /// The ident of the generated QObject
+    pub ident: Ident,
+    /// The visibility of the instantiation
+    pub vis: Visibility,
+    /// The types the template is instantiated with
+    pub arguments: Vec<Type>,
+    /// The methods of the template with the type parameters replaced by the arguments
+    pub methods: Vec<ForeignItemFn>,
+    /// The alias for the concrete Rust struct, eg `type IntRangeRust = RangeModelRust<i32>;`
+    pub rust_type: ItemType,
+}
+
+/// A generic QObject, declared in an `extern "RustQt"` block with type parameters
+struct QObjectTemplate {
+    declaration: TemplateDeclaration,
+    /// The type parameters of the template
+    params: Vec<Ident>,
+    /// The ident of the Rust struct, eg `RangeModelRust`
+    rust_ident: Ident,
+    /// The other items of the `extern "RustQt"` block, which all belong to the template
+    block: ItemForeignMod,
+    /// Any trait implementations for the template, eg `impl cxx_qt::Threading for RangeModel<T> {}`
+    impls: Vec<ItemImpl>,
+}
+
+impl QObjectTemplate {
+    fn parse(declaration: TemplateDeclaration, block: ItemForeignMod) -> Result<Self> {
+        let params = declaration
+            .generics
+            .params
+            .iter()
+            .map(|param| match param {
+                GenericParam::Type(ty) if ty.bounds.is_empty() && ty.default.is_none() => {
+                    Ok(ty.ident.clone())
+                }
+                _ => Err(Error::new(
+                    param.span(),
+                    "QObject templates only support type parameters without bounds or defaults!",
+                )),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(where_clause) = &declaration.generics.where_clause {
+            return Err(Error::new(
+                where_clause.span(),
+                "QObject templates do not support where clauses!",
+            ));
+        }
+
+        let rust_path = &declaration.rust_path;
+        if rust_path.segments.len() != 2 || rust_path.segments[0].ident != "super" {
+            return Err(Error::new(
+                rust_path.span(),
+                "Type alias path must have exactly two segments, super::T<..>!",
+            ));
+        }
+        let rust_ident = rust_path.segments[1].ident.clone();
+
+        if block
+            .items
+            .iter()
+            .any(|item| matches!(item, ForeignItem::Type(_) | ForeignItem::Verbatim(_)))
+        {
+            return Err(Error::new(
+                block.span(),
+                "A QObject template must be the only type in its extern \"RustQt\" block!",
+            ));
+        }
+
+        Ok(Self {
+            declaration,
+            params,
+            rust_ident,
+            block,
+            impls: vec![],
+        })
+    }
+
+    /// Whether the given path refers to this template, eg `RangeModel<T>`
+    fn matches_path(&self, path: &Path) -> bool {
+        path.segments.len() == 1 && path.segments[0].ident == self.declaration.ident
+    }
+
+    /// The methods of the template which are implemented in Rust and can be shared through a trait
+    fn rust_methods(&self) -> impl Iterator<Item = &ForeignItemFn> {
+        self.block.items.iter().filter_map(|item| match item {
+            ForeignItem::Fn(foreign_fn)
+                if foreign_fn.sig.receiver().is_some()
+                    && ["qsignal", "inherit", "cxx_pure"]
+                        .iter()
+                        .all(|attr| attribute_get_path(&foreign_fn.attrs, &[attr]).is_none()) =>
+            {
+                Some(foreign_fn)
+            }
+            _ => None,
+        })
+    }
+
+    /// Expand an instantiation, eg `type IntRange = RangeModel<i32>;`, into the concrete items
+    fn instantiate(&self, instance: &ItemType, arguments: &PathArguments) -> Result<Instantiation> {
+        let arguments = match arguments {
+            PathArguments::AngleBracketed(arguments) => arguments
+                .args
+                .iter()
+                .map(|argument| match argument {
+                    GenericArgument::Type(ty) => Ok(ty.clone()),
+                    _ => Err(Error::new(
+                        argument.span(),
+                        "QObject templates can only be instantiated with types!",
+                    )),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => vec![],
+        };
+        if arguments.len() != self.params.len() {
+            return Err(Error::new(
+                instance.ty.span(),
+                format!(
+                    "QObject template {} expects {} type arguments, but {} were given!",
+                    self.declaration.ident,
+                    self.params.len(),
+                    arguments.len()
+                ),
+            ));
+        }
+
+        let substitution = Substitution {
+            template: &self.declaration.ident,
+            instance: &instance.ident,
+            params: self.params.iter().zip(arguments.iter().cloned()).collect(),
+        };
+
+        let instance_ident = &instance.ident;
+        let rust_instance = template_instance_rust_ident(instance_ident);
+        let template_attrs = self
+            .declaration
+            .attrs
+            .iter()
+            .map(|attr| substitution.attribute(attr))
+            .collect::<Result<Vec<_>>>()?;
+        let instance_attrs = &instance.attrs;
+        let vis = &instance.vis;
+
+        let mut block = self.block.clone();
+        block.items = std::iter::once(ForeignItem::Verbatim(quote! {
+            #(#template_attrs)*
+            #(#instance_attrs)*
+            #vis type #instance_ident = super::#rust_instance;
+        }))
+        .chain(self.block.items.iter().map(|item| match item {
+            ForeignItem::Fn(foreign_fn) => ForeignItem::Fn(substitution.foreign_fn(foreign_fn)),
+            other => other.clone(),
+        }))
+        .collect();
+
+        let mut items = vec![Item::ForeignMod(block)];
+        for imp in &self.impls {
+            items.push(Item::Impl(substitution.item_impl(imp)));
+        }
+
+        let rust_ident = &self.rust_ident;
+        let mut rust_arguments = self.declaration.rust_path.segments[1].arguments.clone();
+        substitution.path_arguments(&mut rust_arguments);
+        let rust_type = syn::parse2(quote! {
+            type #rust_instance = #rust_ident #rust_arguments;
+        })?;
+
+        // The methods which are forwarded to the trait refer to the QObject as `Self`
+        let self_ident = format_ident!("Self");
+        let self_substitution = Substitution {
+            instance: &self_ident,
+            ..substitution
+        };
+        let methods = self
+            .rust_methods()
+            .map(|method| self_substitution.foreign_fn(method))
+            .collect();
+
+        Ok(Instantiation {
+            items,
+            instance: TemplateInstance {
+                ident: instance_ident.clone(),
+                vis: vis.clone(),
+                arguments,
+                methods,
+                rust_type,
+            },
+        })
+    }
+
+    /// The methods of the trait for the template, which use `Self` for the QObject
+    fn trait_methods(&self) -> Vec<ForeignItemFn> {
+        let self_ident = format_ident!("Self");
+        let substitution = Substitution {
+            template: &self.declaration.ident,
+            instance: &self_ident,
+            params: BTreeMap::new(),
+        };
+        self.rust_methods()
+            .map(|method| substitution.foreign_fn(method))
+            .collect()
+    }
+}
+
+/// The items generated for an instantiation of a QObject template
+struct Instantiation {
+    /// The concrete `extern "RustQt"` block and trait implementations
+    items: Vec<Item>,
+    /// The instance, used to generate the forwarding to the trait of the template
+    instance: TemplateInstance,
+}
+
+/// Replaces the template and its type parameters with an instantiation
+///
+/// Only types are replaced, so that idents such as method or parameter names are left untouched
+struct Substitution<'a> {
+    template: &'a Ident,
+    instance: &'a Ident,
+    params: BTreeMap<&'a Ident, Type>,
+}
+
+impl Substitution<'_> {
+    /// Replace `RangeModel<T>` with `IntRange` and `T` with `i32` within the type
+    fn ty(&self, ty: &mut Type) {
+        match ty {
+            Type::Path(type_path) if type_path.qself.is_none() => {
+                let path = &mut type_path.path;
+                if path.leading_colon.is_none() && path.segments.len() == 1 {
+                    let segment = &path.segments[0];
+                    if &segment.ident == self.template {
+                        let instance = self.instance;
+                        *ty = syn::parse_quote! { #instance };
+                        return;
+                    }
+                    if segment.arguments.is_none() {
+                        if let Some(argument) = self.params.get(&segment.ident) {
+                            *ty = argument.clone();
+                            return;
+                        }
+                    }
+                }
+                for segment in path.segments.iter_mut() {
+                    self.path_arguments(&mut segment.arguments);
+                }
+            }
+            Type::Array(ty_array) => self.ty(&mut ty_array.elem),
+            Type::BareFn(ty_bare_fn) => {
+                for arg in ty_bare_fn.inputs.iter_mut() {
+                    self.ty(&mut arg.ty);
+                }
+                if let ReturnType::Type(_, ty) = &mut ty_bare_fn.output {
+                    self.ty(ty);
+                }
+            }
+            Type::Group(ty_group) => self.ty(&mut ty_group.elem),
+            Type::Paren(ty_paren) => self.ty(&mut ty_paren.elem),
+            Type::Ptr(ty_ptr) => self.ty(&mut ty_ptr.elem),
+            Type::Reference(ty_ref) => self.ty(&mut ty_ref.elem),
+            Type::Slice(ty_slice) => self.ty(&mut ty_slice.elem),
+            Type::Tuple(ty_tuple) => {
+                for elem in ty_tuple.elems.iter_mut() {
+                    self.ty(elem);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Replace any types within generic arguments, eg `QList<T>`
+    fn path_arguments(&self, arguments: &mut PathArguments) {
+        match arguments {
+            PathArguments::AngleBracketed(angled) => {
+                for argument in angled.args.iter_mut() {
+                    match argument {
+                        GenericArgument::Type(ty) => self.ty(ty),
+                        GenericArgument::AssocType(assoc) => self.ty(&mut assoc.ty),
+                        _ => {}
+                    }
+                }
+            }
+            PathArguments::Parenthesized(parenthesized) => {
+                for input in parenthesized.inputs.iter_mut() {
+                    self.ty(input);
+                }
+                if let ReturnType::Type(_, ty) = &mut parenthesized.output {
+                    self.ty(ty);
+                }
+            }
+            PathArguments::None => {}
+        }
+    }
+
+    /// Replace the types of the parameters and the return type of a method
+    fn signature(&self, sig: &mut Signature) {
+        for input in sig.inputs.iter_mut() {
+            match input {
+                FnArg::Receiver(receiver) => self.ty(&mut receiver.ty),
+                FnArg::Typed(pat_type) => self.ty(&mut pat_type.ty),
+            }
+        }
+        if let ReturnType::Type(_, ty) = &mut sig.output {
+            self.ty(ty);
+        }
+    }
+
+    fn foreign_fn(&self, foreign_fn: &ForeignItemFn) -> ForeignItemFn {
+        let mut foreign_fn = foreign_fn.clone();
+        self.signature(&mut foreign_fn.sig);
+        foreign_fn
+    }
+
+    fn item_impl(&self, imp: &ItemImpl) -> ItemImpl {
+        let mut imp = imp.clone();
+        self.ty(&mut imp.self_ty);
+        if let Some((_, path, _)) = &mut imp.trait_ {
+            for segment in path.segments.iter_mut() {
+                self.path_arguments(&mut segment.arguments);
+            }
+        }
+        imp
+    }
+
+    /// Replace the type of a `#[qproperty(T, value)]` attribute, other attributes are unchanged
+    fn attribute(&self, attr: &Attribute) -> Result<Attribute> {
+        let mut attr = attr.clone();
+        if let Meta::List(list) = &mut attr.meta {
+            if list.path.is_ident("qproperty") {
+                let (mut ty, rest) = (|input: ParseStream| {
+                    let ty: Type = input.parse()?;
+                    let rest: TokenStream = input.parse()?;
+                    Ok((ty, rest))
+                })
+                .parse2(list.tokens.clone())?;
+                self.ty(&mut ty);
+                list.tokens = quote! { #ty #rest };
+            }
+        }
+        Ok(attr)
+    }
+}
+
+/// Expand any QObject templates in the items of a bridge
+///
+/// A template is declared in an `extern "RustQt"` block with type parameters, and all items of
+/// that block belong to the template.
+/// Each instantiation, eg `type IntRange = RangeModel<i32>;`, is replaced with a copy of the block
+/// and of any trait implementations for the template, with the type parameters substituted.
+///
+/// Returns the expanded items, and the templates with their instantiations.
+pub fn expand_templates(items: Vec<Item>) -> Result<(Vec<Item>, Vec<ParsedTemplate>)> {
+    let mut templates: Vec<QObjectTemplate> = vec![];
+    let mut remaining = vec![];
+
+    // Find all of the templates first, as they can be instantiated before they are declared
+    for item in items {
+        match item {
+            Item::ForeignMod(mut foreign_mod)
+                if foreign_mod
+                    .abi
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| name.value() == "RustQt") =>
+            {
+                let position = foreign_mod.items.iter().position(|item| {
+                    if let ForeignItem::Verbatim(tokens) = item {
+                        syn::parse2::<TemplateDeclaration>(tokens.clone())
+                            .is_ok_and(|declaration| !declaration.generics.params.is_empty())
+                    } else {
+                        false
+                    }
+                });
+                if let Some(position) = position {
+                    let ForeignItem::Verbatim(tokens) = foreign_mod.items.remove(position) else {
+                        unreachable!("The template declaration is a verbatim item")
+                    };
+                    templates.push(QObjectTemplate::parse(syn::parse2(tokens)?, foreign_mod)?);
+                } else {
+                    remaining.push(Item::ForeignMod(foreign_mod));
+                }
+            }
+            others => remaining.push(others),
+        }
+    }
+
+    if templates.is_empty() {
+        return Ok((remaining, vec![]));
+    }
+
+    // Move the implementations of any traits for the templates into the templates
+    let mut others = vec![];
+    for item in remaining {
+        if let Item::Impl(imp) = item {
+            let template = match &*imp.self_ty {
+                Type::Path(type_path) => templates
+                    .iter_mut()
+                    .find(|template| template.matches_path(&type_path.path)),
+                _ => None,
+            };
+            if let Some(template) = template {
+                template.impls.push(imp);
+            } else {
+                others.push(Item::Impl(imp));
+            }
+        } else {
+            others.push(item);
+        }
+    }
+
+    // Replace the instantiations with the expanded items
+    let mut expanded = vec![];
+    let mut instances: Vec<Vec<TemplateInstance>> = templates.iter().map(|_| vec![]).collect();
+    for item in others {
+        if let Item::Type(instance) = &item {
+            if let Type::Path(type_path) = &*instance.ty {
+                let path = &type_path.path;
+                if let Some(index) = templates
+                    .iter()
+                    .position(|template| template.matches_path(path))
+                {
+                    let instantiation =
+                        templates[index].instantiate(instance, &path.segments[0].arguments)?;
+                    expanded.extend(instantiation.items);
+                    instances[index].push(instantiation.instance);
+                    continue;
+                }
+            }
+        }
+        expanded.push(item);
+    }
+
+    let parsed_templates = templates
+        .iter()
+        .zip(instances)
+        .map(|(template, instances)| ParsedTemplate {
+            ident: template.declaration.ident.clone(),
+            vis: template.declaration.vis.clone(),
+            params: template.params.clone(),
+            methods: template.trait_methods(),
+            instances,
+        })
+        .collect();
+
+    Ok((expanded, parsed_templates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tests::{assert_parse_errors, assert_tokens_eq};
+    use syn::{parse_quote, ItemMod};
+
+    fn expand(module: ItemMod) -> Result<(Vec<Item>, Vec<ParsedTemplate>)> {
+        expand_templates(module.content.unwrap().1)
+    }
+
+    #[test]
+    fn test_expand_templates() {
+        let (items, templates) = expand(parse_quote! {
+            mod ffi {
+                type IntRange = RangeModel<i32>;
+
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(T, value)]
+                    type RangeModel<T> = super::RangeModelRust<T>;
+
+                    #[qinvokable]
+                    fn add(self: Pin<&mut RangeModel<T>>, value: T) -> T;
+                }
+
+                impl cxx_qt::Threading for RangeModel<T> {}
+
+                #[cxx_name = "FloatRangeModel"]
+                type FloatRange = RangeModel<f32>;
+            }
+        })
+        .unwrap();
+
+        assert_eq!(items.len(), 4);
+        assert_tokens_eq(
+            &items[0],
+            quote! {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(i32, value)]
+                    type IntRange = super::IntRangeRust;
+
+                    #[qinvokable]
+                    fn add(self: Pin<&mut IntRange>, value: i32) -> i32;
+                }
+            },
+        );
+        assert_tokens_eq(
+            &items[1],
+            quote! {
+                impl cxx_qt::Threading for IntRange {}
+            },
+        );
+        assert_tokens_eq(
+            &items[2],
+            quote! {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(f32, value)]
+                    #[cxx_name = "FloatRangeModel"]
+                    type FloatRange = super::FloatRangeRust;
+
+                    #[qinvokable]
+                    fn add(self: Pin<&mut FloatRange>, value: f32) -> f32;
+                }
+            },
+        );
+
+        assert_eq!(templates.len(), 1);
+        let template = &templates[0];
+        assert_eq!(template.ident, "RangeModel");
+        assert_eq!(template.params, vec![format_ident!("T")]);
+        assert_eq!(template.methods.len(), 1);
+        assert_tokens_eq(
+            &template.methods[0],
+            quote! {
+                #[qinvokable]
+                fn add(self: Pin<&mut Self>, value: T) -> T;
+            },
+        );
+
+        assert_eq!(template.instances.len(), 2);
+        assert_eq!(template.instances[0].ident, "IntRange");
+        assert_tokens_eq(
+            &template.instances[0].rust_type,
+            quote! {
+                type IntRangeRust = RangeModelRust<i32>;
+            },
+        );
+        assert_tokens_eq(
+            &template.instances[0].methods[0],
+            quote! {
+                #[qinvokable]
+                fn add(self: Pin<&mut Self>, value: i32) -> i32;
+            },
+        );
+        assert_tokens_eq(
+            &template.instances[1].rust_type,
+            quote! {
+                type FloatRangeRust = RangeModelRust<f32>;
+            },
+        );
+    }
+
+    #[test]
+    fn test_expand_templates_only_types() {
+        let (items, _) = expand(parse_quote! {
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(QList<T>, T)]
+                    type Model<T> = super::ModelRust<T>;
+
+                    #[qinvokable]
+                    #[cxx_name = "T"]
+                    fn T(self: &Model<T>, T: T, other: super::T, index: <T as Index>::Output) -> T;
+
+                    #[qsignal]
+                    fn changed(self: Pin<&mut Model<T>>, values: &[T; 2], callback: fn(T) -> T);
+                }
+
+                type IntModel = Model<i32>;
+            }
+        })
+        .unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_tokens_eq(
+            &items[0],
+            quote! {
+                extern "RustQt" {
+                    #[qobject]
+                    #[qproperty(QList<i32>, T)]
+                    type IntModel = super::IntModelRust;
+
+                    #[qinvokable]
+                    #[cxx_name = "T"]
+                    fn T(self: &IntModel, T: i32, other: super::T, index: <T as Index>::Output) -> i32;
+
+                    #[qsignal]
+                    fn changed(self: Pin<&mut IntModel>, values: &[i32; 2], callback: fn(i32) -> i32);
+                }
+            },
+        );
+    }
+
+    #[test]
+    fn test_expand_templates_without_templates() {
+        let (items, templates) = expand(parse_quote! {
+            mod ffi {
+                extern "RustQt" {
+                    #[qobject]
+                    type MyObject = super::MyObjectRust;
+                }
+
+                type Alias = Other<i32>;
+            }
+        })
+        .unwrap();
+        assert_eq!(items.len(), 2);
+        assert!(templates.is_empty());
+    }
+
+    #[test]
+    fn test_expand_templates_invalid() {
+        assert_parse_errors!(
+            expand =>
+
+            // Bounds are not supported
+            {
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type RangeModel<T: Copy> = super::RangeModelRust<T>;
+                    }
+                }
+            }
+
+            // The Rust struct must be in the parent module
+            {
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type RangeModel<T> = RangeModelRust<T>;
+                    }
+                }
+            }
+
+            // A template must be the only type in its block
+            {
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type RangeModel<T> = super::RangeModelRust<T>;
+
+                        #[qobject]
+                        type MyObject = super::MyObjectRust;
+                    }
+                }
+            }
+
+            // Wrong number of type arguments
+            {
+                mod ffi {
+                    extern "RustQt" {
+                        #[qobject]
+                        type RangeModel<T> = super::RangeModelRust<T>;
+                    }
+
+                    type IntRange = RangeModel<i32, f32>;
+                }
+            }
+        );
+    }
+}
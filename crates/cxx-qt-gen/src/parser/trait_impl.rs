@@ -3,18 +3,37 @@ use indoc::formatdoc;
 // SPDX-FileContributor: Leon Matthes <leon.matthes@kdab.com>
 //
 // SPDX-License-Identifier: MIT OR Apache-2.0
-use syn::{parse_quote, Error, Ident, ItemImpl, Path, Result, Token, Type, TypePath};
+use syn::{
+    parse_quote, spanned::Spanned, Error, Ident, ItemImpl, Path, Result, Token, Type, TypePath,
+};
 
-use crate::{parser::constructor::Constructor, syntax::path::path_compare_str};
+use crate::{
+    parser::constructor::Constructor,
+    syntax::{
+        path::{path_compare_str, path_to_string},
+        suggestion::with_suggestion,
+    },
+};
 
 /// The kind of marker trait implementation.
 #[derive(Debug, PartialEq, Eq)]
 pub enum TraitKind {
     Threading,
+    Finalize,
     Constructor(Box<Constructor>),
+    /// A `#[qinterface]` trait declared in the bridge
+    Interface(Ident),
 }
 
 impl TraitKind {
+    /// The fully-qualified traits which are supported, used to suggest the intended trait for typos
+    const TRAITS: [&'static str; 4] = [
+        "cxx_qt::Threading",
+        "cxx_qt::Constructor",
+        "cxx_qt::Initialize",
+        "cxx_qt::Finalize",
+    ];
+
     fn parse_threading(not: &Option<Token![!]>, path: &Path, imp: &ItemImpl) -> Result<Self> {
         if let Some(unsafety) = imp.unsafety.as_ref() {
             return Err(Error::new_spanned(
@@ -31,12 +50,46 @@ impl TraitKind {
         Ok(Self::Threading)
     }
 
+    fn parse_finalize(not: &Option<Token![!]>, path: &Path, imp: &ItemImpl) -> Result<Self> {
+        if let Some(unsafety) = imp.unsafety.as_ref() {
+            return Err(Error::new_spanned(
+                unsafety,
+                "Unnecessary unsafe, cxx_qt::Finalize is safe to implement!",
+            ));
+        }
+        if not.is_some() {
+            return Err(Error::new_spanned(
+                path,
+                "Negative impls for cxx_qt::Finalize are not allowed!",
+            ));
+        }
+        Ok(Self::Finalize)
+    }
+
+    fn parse_interface(not: &Option<Token![!]>, path: &Path, imp: &ItemImpl) -> Result<Self> {
+        if let Some(unsafety) = imp.unsafety.as_ref() {
+            return Err(Error::new_spanned(
+                unsafety,
+                "Unnecessary unsafe, #[qinterface] traits are safe to implement!",
+            ));
+        }
+        if not.is_some() {
+            return Err(Error::new_spanned(
+                path,
+                "Negative impls for #[qinterface] traits are not allowed!",
+            ));
+        }
+        // Checked by the caller
+        let ident = path.get_ident().cloned().unwrap();
+        Ok(Self::Interface(ident))
+    }
+
     fn parse_constructor(imp: &ItemImpl) -> Result<Self> {
         let constructor = Constructor::parse(imp.clone())?;
         Ok(Self::Constructor(Box::new(constructor)))
     }
 
-    fn parse(imp: &ItemImpl) -> Result<Self> {
+    fn parse(imp: &ItemImpl, qinterfaces: &[Ident]) -> Result<Self> {
         let (not, path, _) = &imp
             .trait_
             .as_ref()
@@ -44,6 +97,8 @@ impl TraitKind {
 
         if path_compare_str(path, &["cxx_qt", "Threading"]) {
             Self::parse_threading(not, path, imp)
+        } else if path_compare_str(path, &["cxx_qt", "Finalize"]) {
+            Self::parse_finalize(not, path, imp)
         } else if path_compare_str(path, &["cxx_qt", "Constructor"]) {
             Self::parse_constructor(imp)
         } else if path_compare_str(path, &["cxx_qt", "Initialize"]) {
@@ -53,19 +108,32 @@ impl TraitKind {
                 impl cxx_qt::Constructor<()> for #struct_name {}
             };
             Self::parse_constructor(&default_constructor)
+        } else if path
+            .get_ident()
+            .is_some_and(|ident| qinterfaces.contains(ident))
+        {
+            Self::parse_interface(not, path, imp)
         } else {
-            // TODO: Give suggestions on which trait might have been meant
-            Err(Error::new_spanned(
-                path,
-                formatdoc! {"
+            let message = formatdoc! {"
                     Unsupported trait!
                     CXX-Qt currently only supports:
                       - cxx_qt::Threading
                       - cxx_qt::Constructor
                       - cxx_qt::Initialize (as shorthand for Constructor<()>)
+                      - cxx_qt::Finalize
+                      - #[qinterface] traits declared in the bridge
                       - (cxx_qt::Locking has been removed as of CXX-Qt 0.7)
                     Note that the trait must always be fully-qualified.
-                    "},
+                    "};
+            let qinterfaces = qinterfaces.iter().map(Ident::to_string).collect::<Vec<_>>();
+            Err(with_suggestion(
+                path,
+                message,
+                path.span(),
+                &path_to_string(path),
+                Self::TRAITS
+                    .into_iter()
+                    .chain(qinterfaces.iter().map(String::as_str)),
             ))
         }
     }
@@ -80,7 +148,8 @@ pub struct TraitImpl {
 }
 
 impl TraitImpl {
-    pub fn parse(imp: ItemImpl) -> Result<Self> {
+    /// Parse a trait impl, where `qinterfaces` are the names of the `#[qinterface]` traits in the bridge
+    pub fn parse(imp: ItemImpl, qinterfaces: &[Ident]) -> Result<Self> {
         if let Some(attr) = imp.attrs.first() {
             return Err(Error::new_spanned(
                 attr,
@@ -102,7 +171,7 @@ impl TraitImpl {
         } else {
             Err(invalid_path())
         }?;
-        let kind = TraitKind::parse(&imp)?;
+        let kind = TraitKind::parse(&imp, qinterfaces)?;
         Ok(Self {
             qobject,
             kind,
@@ -115,6 +184,7 @@ impl TraitImpl {
 mod tests {
     use super::*;
 
+    use crate::syntax::suggestion::Suggestion;
     use quote::format_ident;
     use syn::parse_quote;
 
@@ -123,17 +193,37 @@ mod tests {
         let imp = parse_quote! {
             impl cxx_qt::Threading for QObject {}
         };
-        let marker = TraitImpl::parse(imp).unwrap();
+        let marker = TraitImpl::parse(imp, &[]).unwrap();
         assert_eq!(marker.qobject, format_ident!("QObject"));
         assert_eq!(marker.kind, TraitKind::Threading);
     }
 
+    #[test]
+    fn parse_finalize() {
+        let imp = parse_quote! {
+            impl cxx_qt::Finalize for MyObject {}
+        };
+        let marker = TraitImpl::parse(imp, &[]).unwrap();
+        assert_eq!(marker.qobject, format_ident!("MyObject"));
+        assert_eq!(marker.kind, TraitKind::Finalize);
+    }
+
+    #[test]
+    fn parse_interface() {
+        let imp = parse_quote! {
+            impl Device for Printer {}
+        };
+        let marker = TraitImpl::parse(imp, &[format_ident!("Device")]).unwrap();
+        assert_eq!(marker.qobject, format_ident!("Printer"));
+        assert_eq!(marker.kind, TraitKind::Interface(format_ident!("Device")));
+    }
+
     #[test]
     fn parse_constructor() {
         let imp = parse_quote! {
             impl cxx_qt::Constructor<(i32, i32)> for MyObject {}
         };
-        let marker = TraitImpl::parse(imp).unwrap();
+        let marker = TraitImpl::parse(imp, &[]).unwrap();
         assert_eq!(marker.qobject, format_ident!("MyObject"));
         assert!(matches!(marker.kind, TraitKind::Constructor(_)))
     }
@@ -143,29 +233,43 @@ mod tests {
         let imp = parse_quote! {
             impl cxx_qt::Constructor<()> for MyObject {}
         };
-        let marker = TraitImpl::parse(imp).unwrap();
+        let marker = TraitImpl::parse(imp, &[]).unwrap();
         assert_eq!(marker.qobject, format_ident!("MyObject"));
         assert!(matches!(marker.kind, TraitKind::Constructor(_)));
 
         let imp = parse_quote! {
             impl cxx_qt::Initialize for MyObject {}
         };
-        let shorthand = TraitImpl::parse(imp).unwrap();
+        let shorthand = TraitImpl::parse(imp, &[]).unwrap();
         assert_eq!(marker.kind, shorthand.kind);
         assert_eq!(marker.qobject, shorthand.qobject);
     }
 
     use crate::tests::assert_parse_errors;
 
+    fn parse_with_device(imp: ItemImpl) -> Result<TraitImpl> {
+        TraitImpl::parse(imp, &[format_ident!("Device")])
+    }
+
     #[test]
     fn test_parse_errors() {
         assert_parse_errors! {
-            TraitImpl::parse =>
+            parse_with_device =>
 
             // Threading is safe to implement
             { unsafe impl cxx_qt::Threading for QObject {} }
             // Threading cannot be negated
             { impl !cxx_qt::Threading for QObject {} }
+            // Finalize is safe to implement
+            { unsafe impl cxx_qt::Finalize for QObject {} }
+            // Finalize cannot be negated
+            { impl !cxx_qt::Finalize for QObject {} }
+            // Interfaces are safe to implement
+            { unsafe impl Device for QObject {} }
+            // Interfaces cannot be negated
+            { impl !Device for QObject {} }
+            // Traits which are not fully-qualified and not a #[qinterface]
+            { impl Threading for QObject {} }
             // Invalid QObject name
             { impl cxx_qt::Locking for my::path {} }
             // Invalid trait name
@@ -185,4 +289,33 @@ mod tests {
             }
         }
     }
+
+    /// The replacements suggested by the error of an invalid trait impl
+    fn suggestions(imp: ItemImpl) -> Vec<String> {
+        let (result, suggestions) =
+            Suggestion::collect(|| TraitImpl::parse(imp, &[format_ident!("Device")]));
+        let error = result.unwrap_err();
+        suggestions
+            .into_iter()
+            .inspect(|suggestion| assert!(suggestion.is_from(&error)))
+            .map(|suggestion| suggestion.replacement)
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_suggestions() {
+        assert_eq!(
+            suggestions(parse_quote! { impl cxx_qt::Threadng for QObject {} }),
+            vec!["cxx_qt::Threading"]
+        );
+        assert_eq!(
+            suggestions(parse_quote! { impl cxx_qt::finalize for QObject {} }),
+            vec!["cxx_qt::Finalize"]
+        );
+        assert!(suggestions(parse_quote! { impl cxx_qt::AnotherTrait for QObject {} }).is_empty());
+        assert_eq!(
+            suggestions(parse_quote! { impl Devise for QObject {} }),
+            vec!["Device"]
+        );
+    }
 }
@@ -11,6 +11,7 @@ pub mod lifetimes;
 pub mod path;
 mod qtfile;
 mod qtitem;
+pub mod suggestion;
 pub mod types;
 
 pub use qtfile::{parse_qt_file, CxxQtFile};
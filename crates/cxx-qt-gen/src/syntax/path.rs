@@ -18,6 +18,15 @@ pub fn path_compare_str(path: &Path, string: &[&str]) -> bool {
             .any(|(segment, string)| segment.ident.to_string().as_str() != *string)
 }
 
+/// Returns the segments of the [syn::Path] joined by `::`, without any generic arguments
+pub fn path_to_string(path: &Path) -> String {
+    path.segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
 #[cfg(test)]
 mod tests {
     use syn::parse_quote;
@@ -32,4 +41,10 @@ mod tests {
         assert!(!path_compare_str(&path, &["a", "c", "b"]));
         assert!(!path_compare_str(&path, &["a", "b", "c", "d"]));
     }
+
+    #[test]
+    fn test_path_to_string() {
+        let path: Path = parse_quote! { a::b<T>::c };
+        assert_eq!(path_to_string(&path), "a::b::c");
+    }
 }
@@ -0,0 +1,199 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! "Did you mean" suggestions for misspelled names in a bridge
+
+use std::{cell::RefCell, fmt::Display};
+
+use proc_macro2::Span;
+use quote::ToTokens;
+use syn::Error;
+
+thread_local! {
+    /// The suggestions made on this thread while [Suggestion::collect] is running
+    static COLLECTED: RefCell<Option<Vec<Suggestion>>> = const { RefCell::new(None) };
+}
+
+/// A suggested replacement for the code at a span, made by an error of the parser
+///
+/// The suggestion is appended to the message of the [syn::Error], so it is shown by the
+/// proc-macro as well. Build systems can retrieve the suggestions with [Suggestion::collect]
+/// to show them as a fix-it.
+#[derive(Clone, Debug)]
+pub struct Suggestion {
+    /// The span of the error which made the suggestion
+    pub error_span: Span,
+    /// The span of the code which should be replaced
+    pub span: Span,
+    /// The code to replace it with
+    pub replacement: String,
+}
+
+impl Suggestion {
+    /// Find the closest of the candidates to the given input, if it is close enough to be a typo
+    fn closest<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+        // Allow roughly one typo for every three characters
+        let max_distance = input.chars().count().div_ceil(3).max(1);
+        candidates
+            .into_iter()
+            .filter(|candidate| *candidate != input)
+            .map(|candidate| (edit_distance(input, candidate), candidate))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, candidate)| candidate)
+    }
+
+    /// Run the closure and return the suggestions made by any errors created on this thread meanwhile
+    pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Vec<Self>) {
+        let outer = COLLECTED.replace(Some(vec![]));
+        let result = f();
+        let suggestions = COLLECTED.replace(outer).unwrap_or_default();
+        (result, suggestions)
+    }
+
+    /// Whether this suggestion was made by the given error
+    pub fn is_from(&self, error: &Error) -> bool {
+        let span = error.span();
+        self.error_span.start() == span.start() && self.error_span.end() == span.end()
+    }
+}
+
+/// Create an error which suggests the closest of the candidates to the input,
+/// if there is one close enough to be a typo
+pub(crate) fn with_suggestion<'a>(
+    tokens: impl ToTokens,
+    message: impl Display,
+    span: Span,
+    input: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Error {
+    let Some(replacement) = Suggestion::closest(input, candidates) else {
+        return Error::new_spanned(tokens, message);
+    };
+
+    let error = Error::new_spanned(
+        tokens,
+        format!("{message}\nhelp: did you mean `{replacement}`?"),
+    );
+    COLLECTED.with_borrow_mut(|collected| {
+        if let Some(collected) = collected {
+            collected.push(Suggestion {
+                error_span: error.span(),
+                span,
+                replacement: replacement.to_owned(),
+            });
+        }
+    });
+    error
+}
+
+/// The number of single character insertions, deletions or substitutions between the strings
+///
+/// Case is ignored, as flags like `READ` are commonly written in the wrong case.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let b: Vec<char> = b.chars().map(|c| c.to_ascii_lowercase()).collect();
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = usize::from(a_char != b_char);
+            current[j + 1] = (previous[j] + substitution)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("qproperty", "qproperty"), 0);
+        assert_eq!(edit_distance("qpropety", "qproperty"), 1);
+        assert_eq!(edit_distance("qinvokabel", "qinvokable"), 2);
+        assert_eq!(edit_distance("read", "READ"), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_closest() {
+        let candidates = ["READ", "WRITE", "RESET", "NOTIFY"];
+        let closest = |input| Suggestion::closest(input, candidates);
+        assert_eq!(closest("WRTIE"), Some("WRITE"));
+        assert_eq!(closest("read"), Some("READ"));
+        assert_eq!(closest("NOTIFI"), Some("NOTIFY"));
+        assert_eq!(closest("CONSTANT"), None);
+    }
+
+    #[test]
+    fn test_with_suggestion() {
+        let ident = quote::format_ident!("Threadng");
+        let (error, suggestions) = Suggestion::collect(|| {
+            with_suggestion(
+                &ident,
+                "Unsupported trait!",
+                ident.span(),
+                "Threadng",
+                ["Threading", "Finalize"],
+            )
+        });
+
+        // The suggestion is part of the message rather than an additional error
+        assert_eq!(error.clone().into_iter().count(), 1);
+        assert_eq!(
+            error.to_string(),
+            "Unsupported trait!\nhelp: did you mean `Threading`?"
+        );
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].replacement, "Threading");
+        assert!(suggestions[0].is_from(&error));
+    }
+
+    #[test]
+    fn test_with_suggestion_not_collected() {
+        let ident = quote::format_ident!("Unrelated");
+        let (error, suggestions) = Suggestion::collect(|| {
+            // Errors without a close candidate have no suggestion
+            let error = with_suggestion(
+                &ident,
+                "Unsupported trait!",
+                ident.span(),
+                "Unrelated",
+                ["Threading"],
+            );
+            // Nested collections take the suggestions made inside them
+            let (_, nested) = Suggestion::collect(|| {
+                with_suggestion(
+                    &ident,
+                    "Unsupported trait!",
+                    ident.span(),
+                    "Threadng",
+                    ["Threading"],
+                )
+            });
+            assert_eq!(nested.len(), 1);
+            error
+        });
+        assert_eq!(error.to_string(), "Unsupported trait!");
+        assert!(suggestions.is_empty());
+
+        // Nothing is collected outside of Suggestion::collect
+        with_suggestion(
+            &ident,
+            "Unsupported trait!",
+            ident.span(),
+            "Threadng",
+            ["Threading"],
+        );
+        let (_, suggestions) = Suggestion::collect(|| ());
+        assert!(suggestions.is_empty());
+    }
+}
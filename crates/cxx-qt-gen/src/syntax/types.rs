@@ -6,8 +6,8 @@
 use crate::syntax::path::path_compare_str;
 use quote::ToTokens;
 use syn::{
-    token::Mut, Error, GenericArgument, Ident, Path, PathArguments, Result, Type, TypePath,
-    TypeReference,
+    token::Mut, Error, GenericArgument, Ident, Path, PathArguments, Result, ReturnType, Type,
+    TypePath, TypeReference,
 };
 
 fn pin_path(ty: &Type) -> Option<Path> {
@@ -109,6 +109,20 @@ fn extract_qobject_from_mut_pin(ty: &TypePath) -> Result<(Ident, Mut)> {
     Err(err_pin_misuse(ty))
 }
 
+/// Checks if the given return type is a `Result<T>`, which CXX maps to a C++ exception
+pub fn is_result(return_ty: &ReturnType) -> bool {
+    if let ReturnType::Type(_, ty) = return_ty {
+        if let Type::Path(TypePath { path, .. }) = &**ty {
+            return path
+                .segments
+                .first()
+                .is_some_and(|segment| segment.ident == "Result");
+        }
+    }
+
+    false
+}
+
 /// Extract the qobject ident from any of the following patterns:
 /// - &T
 /// - Pin<&mut T>
@@ -175,6 +189,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_is_result() {
+        assert!(super::is_result(&parse_quote! { -> Result<i32> }));
+        assert!(super::is_result(&parse_quote! { -> Result<()> }));
+        assert!(!super::is_result(&parse_quote! { -> i32 }));
+        assert!(!super::is_result(&parse_quote! { -> &Result<i32> }));
+        assert!(!super::is_result(&parse_quote! {}));
+    }
+
     #[test]
     fn test_incorrect_pin_syntax() {
         let method: ForeignItemFn = parse_quote! {
@@ -0,0 +1,316 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Snapshot testing of the code generated for a bridge, enabled with the `testing` feature.
+//!
+//! This allows crates using CXX-Qt to commit the code generated for their bridges, so that changes
+//! in the generated code show up in review, for example when upgrading CXX-Qt.
+//!
+//! ```no_run
+//! use cxx_qt_gen::testing::BridgeSnapshot;
+//!
+//! let source = std::fs::read_to_string("src/my_object.rs").unwrap();
+//! let bridges = BridgeSnapshot::generate(&source, "my_crate/src/my_object").unwrap();
+//! bridges[0].assert_snapshot("tests/snapshots", "my_object");
+//! ```
+//!
+//! Run the tests with the [UPDATE_SNAPSHOTS_ENV_VAR] environment variable set to write new snapshots.
+//! The Rust code is formatted with `rustfmt`, so the snapshots should be updated with the same
+//! version of `rustfmt` that the tests use. The C++ code is compared as it is generated, without
+//! formatting, so the snapshots do not depend on whether `clang-format` is installed.
+
+use crate::{
+    self_inlining::qualify_self_types, write_cpp, write_rust, CppFragment, CxxQtFile, CxxQtItem,
+    GeneratedCppBlocks, GeneratedOpt, GeneratedRustBlocks, Parser, Result,
+};
+use similar::TextDiff;
+use std::{
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
+use syn::Error;
+
+/// The environment variable which makes [BridgeSnapshot::assert_snapshot] write the snapshots
+/// instead of comparing them
+pub const UPDATE_SNAPSHOTS_ENV_VAR: &str = "CXX_QT_UPDATE_SNAPSHOTS";
+
+/// The code generated for a single `#[cxx_qt::bridge]` module
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BridgeSnapshot {
+    /// The name of the bridge module
+    pub module: String,
+    /// The generated Rust code, formatted with `rustfmt`
+    pub rust: String,
+    /// The generated `.cxxqt.h` header
+    pub header: String,
+    /// The generated `.cxxqt.cpp` source
+    pub source: String,
+}
+
+impl BridgeSnapshot {
+    /// Generate the code of each `#[cxx_qt::bridge]` module in the given Rust source
+    ///
+    /// The `include_ident` is the path which the header is included as, without the `.cxxqt.h`
+    /// extension, e.g. `my_crate/src/my_object`.
+    /// Bridges using `#[cfg]` attributes need a [GeneratedOpt] with a cfg evaluator,
+    /// see [Self::generate_with_opt].
+    ///
+    /// Returns an error if the bridge is invalid, or if `rustfmt` can not format the generated code.
+    pub fn generate(source: &str, include_ident: &str) -> Result<Vec<Self>> {
+        Self::generate_with_opt(source, include_ident, &GeneratedOpt::default())
+    }
+
+    /// Generate the code of each `#[cxx_qt::bridge]` module in the given Rust source with the given options
+    ///
+    /// Returns an error if the bridge is invalid, or if `rustfmt` can not format the generated code.
+    pub fn generate_with_opt(
+        source: &str,
+        include_ident: &str,
+        opt: &GeneratedOpt,
+    ) -> Result<Vec<Self>> {
+        let file: CxxQtFile = syn::parse_str(source)?;
+        let snapshots = file
+            .items
+            .into_iter()
+            .filter_map(|item| match item {
+                CxxQtItem::CxxQt(module) => Some(module),
+                _ => None,
+            })
+            .map(|module| {
+                let module_ident = module.ident.to_string();
+                let mut parser = Parser::from(*module)?;
+                qualify_self_types(&mut parser)?;
+
+                let generated_cpp = GeneratedCppBlocks::from(&parser, opt)?;
+                let (header, source) = match write_cpp(&generated_cpp, include_ident) {
+                    CppFragment::Pair { header, source } => (header, source),
+                    CppFragment::Header(header) => (header, String::new()),
+                    CppFragment::Source(source) => (String::new(), source),
+                };
+
+                let generated_rust = GeneratedRustBlocks::from(&parser)?;
+                let rust = write_rust(&generated_rust, Some(include_ident)).to_string();
+
+                Ok(Self {
+                    module: module_ident,
+                    rust: format_rust(&rust)?,
+                    header,
+                    source,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if snapshots.is_empty() {
+            return Err(Error::new(
+                proc_macro2::Span::call_site(),
+                "No #[cxx_qt::bridge] module found!",
+            ));
+        }
+        Ok(snapshots)
+    }
+
+    /// Compare the generated code to the snapshots `<name>.rs`, `<name>.h` and `<name>.cpp` in the directory
+    ///
+    /// If the [UPDATE_SNAPSHOTS_ENV_VAR] environment variable is set, the snapshots are written instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a diff of every snapshot which does not match the generated code,
+    /// or if a snapshot does not exist.
+    pub fn assert_snapshot(&self, directory: impl AsRef<Path>, name: &str) {
+        let update = std::env::var_os(UPDATE_SNAPSHOTS_ENV_VAR).is_some();
+        if let Err(message) = self.check_snapshot(directory.as_ref(), name, update) {
+            panic!("{message}\nRun the tests with {UPDATE_SNAPSHOTS_ENV_VAR}=1 to update the snapshots.");
+        }
+    }
+
+    fn check_snapshot(
+        &self,
+        directory: &Path,
+        name: &str,
+        update: bool,
+    ) -> std::result::Result<(), String> {
+        let files = [
+            ("rs", &self.rust),
+            ("h", &self.header),
+            ("cpp", &self.source),
+        ];
+        if update {
+            std::fs::create_dir_all(directory)
+                .map_err(|err| format!("Could not create {}: {err}", directory.display()))?;
+        }
+
+        let mut mismatches = vec![];
+        for (extension, generated) in files {
+            let path = directory.join(format!("{name}.{extension}"));
+            if update {
+                std::fs::write(&path, generated)
+                    .map_err(|err| format!("Could not write {}: {err}", path.display()))?;
+                continue;
+            }
+
+            match std::fs::read_to_string(&path) {
+                Ok(mut expected) => {
+                    expected.retain(|c| c != '\r');
+                    if let Some(diff) = diff(&expected, generated) {
+                        mismatches.push(format!(
+                            "Snapshot {} does not match:\n{diff}",
+                            path.display()
+                        ));
+                    }
+                }
+                Err(err) => {
+                    mismatches.push(format!("Could not read snapshot {}: {err}", path.display()))
+                }
+            }
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(mismatches.join("\n"))
+        }
+    }
+}
+
+/// Format Rust code with `rustfmt`
+///
+/// Fails if `rustfmt` can not be run, as the snapshots would depend on whether it is installed.
+fn format_rust(code: &str) -> Result<String> {
+    let error = |message: String| Error::new(proc_macro2::Span::call_site(), message);
+
+    let mut child = Command::new("rustfmt")
+        .args(["--emit", "stdout", "--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|err| {
+            error(format!(
+                "Could not run rustfmt to format the generated Rust code: {err}!"
+            ))
+        })?;
+
+    // Scope stdin to close it before waiting for the output
+    {
+        let mut stdin = child.stdin.take().unwrap();
+        stdin.write_all(code.as_bytes()).map_err(|err| {
+            error(format!(
+                "Could not write the generated Rust code to rustfmt: {err}!"
+            ))
+        })?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|err| error(format!("Could not read the output of rustfmt: {err}!")))?;
+    if !output.status.success() {
+        return Err(error(
+            "rustfmt failed to format the generated Rust code!".to_owned(),
+        ));
+    }
+    String::from_utf8(output.stdout)
+        .map_err(|_| error("rustfmt produced invalid UTF-8!".to_owned()))
+}
+
+/// A unified diff of the expected and the actual text, or [None] if they are equal
+fn diff(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    Some(
+        TextDiff::from_lines(expected, actual)
+            .unified_diff()
+            .context_radius(3)
+            .missing_newline_hint(true)
+            .header("snapshot", "generated")
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BRIDGE: &str = r#"
+        #[cxx_qt::bridge]
+        mod ffi {
+            extern "RustQt" {
+                #[qobject]
+                #[qproperty(i32, number)]
+                type MyObject = super::MyObjectRust;
+            }
+        }
+
+        #[derive(Default)]
+        pub struct MyObjectRust {
+            number: i32,
+        }
+    "#;
+
+    #[test]
+    fn test_generate() {
+        let snapshots = BridgeSnapshot::generate(BRIDGE, "my_crate/my_object").unwrap();
+        assert_eq!(snapshots.len(), 1);
+
+        let snapshot = &snapshots[0];
+        assert_eq!(snapshot.module, "ffi");
+        assert!(snapshot
+            .rust
+            .contains("include!(\"my_crate/my_object.cxxqt.h\");"));
+        assert!(snapshot.header.contains("MyObject"));
+        assert!(snapshot
+            .source
+            .contains("#include \"my_crate/my_object.cxxqt.h\""));
+    }
+
+    #[test]
+    fn test_generate_errors() {
+        assert!(BridgeSnapshot::generate("mod ffi {}", "my_object").is_err());
+        assert!(BridgeSnapshot::generate(
+            "#[cxx_qt::bridge] mod ffi { extern \"RustQt\" { #[qobject] type T; } }",
+            "my_object"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_check_snapshot() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let directory = tempdir.path().join("snapshots");
+        let mut snapshot = BridgeSnapshot::generate(BRIDGE, "my_crate/my_object")
+            .unwrap()
+            .remove(0);
+
+        assert!(snapshot
+            .check_snapshot(&directory, "my_object", false)
+            .is_err());
+        assert!(snapshot
+            .check_snapshot(&directory, "my_object", true)
+            .is_ok());
+        assert!(snapshot
+            .check_snapshot(&directory, "my_object", false)
+            .is_ok());
+
+        snapshot.header.push_str("// changed\n");
+        let message = snapshot
+            .check_snapshot(&directory, "my_object", false)
+            .unwrap_err();
+        assert!(message.contains("my_object.h does not match"));
+        assert!(message.contains("+// changed"));
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("a\nb\n", "a\nb\n"), None);
+        assert_eq!(
+            diff("a\nb\nc\n", "a\nx\nc\n").unwrap(),
+            "--- snapshot\n+++ generated\n@@ -1,3 +1,3 @@\n a\n-b\n+x\n c\n"
+        );
+        assert!(diff("a\n", "a")
+            .unwrap()
+            .contains("\\ No newline at end of file"));
+    }
+}
@@ -78,16 +78,22 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
         } else {
             String::new()
         };
+        // The definition of a custom destructor is part of the generated methods
+        let destructor = if qobject.blocks.has_destructor {
+            format!("virtual ~{ident}();")
+        } else {
+            format!("virtual ~{ident}() = default;")
+        };
         let class_definition = namespaced(
             qobject.name.namespace().unwrap_or_default(),
             &formatdoc! { r#"
-                class {ident} : {base_classes}
+                {doc_comment}class {ident} : {base_classes}
                 {{
                   {qobject_macro}
                 public:
                   {metaobjects}
 
-                  virtual ~{ident}() = default;
+                  {destructor}
 
                 {public_methods}
                 {private_methods}
@@ -101,6 +107,7 @@ fn qobjects_header(generated: &GeneratedCppBlocks) -> Vec<String> {
                 }};
 
                 {qobject_assert}"#,
+            doc_comment = qobject.doc_comment,
             // Note that there is always a base class as we always have CxxQtType
             base_classes = qobject.blocks.base_classes.join(", "),
             metaobjects = qobject.blocks.metaobjects.join("\n  "),
@@ -0,0 +1,35 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::instantiate")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        /// A QObject which can be instantiated from Rust
+        #[qobject]
+        type Concrete = super::ConcreteRust;
+
+        /// An abstract QObject which can only be instantiated from a subclass in C++
+        #[qobject]
+        type AbstractObject = super::AbstractObjectRust;
+
+        /// A QObject which only has the default constructor
+        #[qobject]
+        type DefaultOnly = super::DefaultOnlyRust;
+    }
+
+    unsafe extern "RustQt" {
+        #[qinvokable]
+        #[cxx_virtual]
+        #[cxx_pure]
+        fn log(self: &AbstractObject, message: &QString);
+    }
+
+    impl cxx_qt::Constructor<()> for Concrete {}
+
+    impl<'a> cxx_qt::Constructor<(&'a QString,)> for Concrete {}
+
+    impl cxx_qt::Constructor<()> for AbstractObject {}
+}
@@ -21,6 +21,9 @@ mod ffi {
 
         fn cpp_method(self: &MyObject);
 
+        /// An invokable without parameters
+        ///
+        /// Callable from QML
         #[qinvokable]
         fn invokable(self: &MyObject);
 
@@ -58,10 +61,30 @@ mod ffi {
 
         #[qinvokable]
         fn invokable_result_type(self: &MyObject) -> Result<String>;
+
+        #[qinvokable]
+        #[Self = "MyObject"]
+        fn invokable_static(value: i32) -> i32;
+
+        #[qinvokable]
+        fn invokable_default(self: &MyObject, value: i32, #[default = 10] step: i32) -> i32;
+
+        #[qinvokable]
+        fn invokable_result(self: &MyObject, text: &QString) -> Result<i32>;
+
+        #[qinvokable]
+        #[cxx_name = "invokableOverloaded"]
+        fn invokable_overloaded_int(self: &MyObject, value: i32);
+
+        #[qinvokable]
+        #[cxx_name = "invokableOverloaded"]
+        fn invokable_overloaded_string(self: &MyObject, value: &QString);
     }
 
     impl cxx_qt::Threading for MyObject {}
 
+    impl cxx_qt::Finalize for MyObject {}
+
     impl<'a>
         cxx_qt::Constructor<
             (i32, &'a QString),
@@ -0,0 +1,47 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::overloads")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+    }
+
+    unsafe extern "C++Qt" {
+        include!(<QtWidgets/QCompleter>);
+        #[qobject]
+        #[namespace = ""]
+        type QCompleter;
+
+        /// The completion is activated with its text
+        #[qsignal]
+        #[cxx_name = "activated"]
+        fn activated_text(self: Pin<&mut Self>, text: &QString);
+
+        /// The completion is activated with its index
+        #[qsignal]
+        #[cxx_name = "activated"]
+        fn activated_index(self: Pin<&mut Self>, index: &QModelIndex);
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qproperty(i32, value)]
+        #[qproperty(i32, doubled, READ, NOTIFY, depends_on(value))]
+        type MyObject = super::MyObjectRust;
+
+        /// An overload of the NOTIFY signal of the value property
+        #[qsignal]
+        #[cxx_name = "valueChanged"]
+        fn value_changed_from(self: Pin<&mut Self>, previous: i32);
+
+        #[qinvokable]
+        #[cxx_name = "reset"]
+        fn reset(self: Pin<&mut Self>);
+
+        #[qinvokable]
+        #[cxx_name = "reset"]
+        fn reset_to(self: Pin<&mut Self>, value: i32);
+    }
+}
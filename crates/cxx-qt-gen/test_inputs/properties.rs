@@ -8,7 +8,9 @@ mod ffi {
 
     #[auto_cxx_name]
     extern "RustQt" {
+        /// A QObject with properties
         #[qobject]
+        /// A primitive property
         #[qproperty(i32, primitive)]
         #[qproperty(QPoint, trivial)]
         #[qproperty(i32, prop_auto_cxx_name)]
@@ -27,6 +29,7 @@ mod ffi {
         #[qproperty(i32, resettable_prop, cxx_name = "resettableProp", READ, WRITE, RESET = myResetFn)]
         #[qproperty(i32, required_prop, cxx_name = "requiredProp", READ, WRITE, REQUIRED)]
         #[qproperty(i32, final_prop, cxx_name = "finalProp", READ, WRITE, FINAL)]
+        #[qproperty(i32, auto_reset_prop, cxx_name = "autoResetProp", READ, WRITE, RESET)]
         type MyObject = super::MyObjectRust;
     }
 
@@ -33,6 +33,16 @@ mod ffi {
         Variant2,
     }
 
+    // Free functions are generated in the namespace of their qnamespace!
+    extern "RustQt" {
+        #[qinvokable]
+        fn next_namespaced_enum(value: MyNamespacedEnum) -> MyNamespacedEnum;
+
+        #[qinvokable]
+        #[namespace = "other_namespace"]
+        fn is_first_variant(value: MyOtherNamespacedEnum) -> bool;
+    }
+
     unsafe extern "RustQt" {
         #[qobject]
         type MyObject = super::MyObjectRust;
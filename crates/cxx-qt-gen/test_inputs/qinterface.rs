@@ -0,0 +1,31 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::devices")]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    /// A device which can be connected to
+    #[qinterface]
+    trait Device {
+        /// Connect to the device
+        #[qinvokable]
+        fn connect(self: Pin<&mut Self>, address: &QString) -> bool;
+
+        #[qinvokable]
+        #[cxx_name = "isConnected"]
+        fn is_connected(&self) -> bool;
+
+        #[qsignal]
+        #[cxx_name = "stateChanged"]
+        fn state_changed(self: Pin<&mut Self>, connected: bool);
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        type Printer = super::PrinterRust;
+    }
+
+    impl Device for Printer {}
+}
@@ -0,0 +1,19 @@
+#[cxx_qt::bridge(namespace = "cxx_qt::templates")]
+mod ffi {
+    extern "RustQt" {
+        #[qobject]
+        #[qproperty(T, value)]
+        type RangeModel<T> = super::RangeModelRust<T>;
+
+        #[qinvokable]
+        fn clamp(self: &RangeModel<T>, value: T) -> T;
+    }
+
+    impl cxx_qt::Threading for RangeModel<T> {}
+
+    type IntRange = RangeModel<i32>;
+
+    #[qml_element]
+    #[cxx_name = "FloatRangeModel"]
+    type FloatRange = RangeModel<f64>;
+}
@@ -500,6 +500,10 @@ mod ffi {
         type EnumEnabled2 = super::cxx_qt_private_qenum_EnumEnabled2::EnumEnabled2;
     }
 }
+#[cfg(enabled)]
+impl ::cxx_qt::QProperties for ffi::QObjectEnabled {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
 #[cfg(not(enabled))]
 impl ffi::QObjectEnabled {
     #[doc = "Connect the given function pointer to the signal "]
@@ -684,6 +688,10 @@ impl ::cxx_qt::CxxQtType for ffi::QObjectEnabled {
     }
 }
 #[cfg(not(enabled))]
+impl ::cxx_qt::QProperties for ffi::QObjectDisabled {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
+#[cfg(not(enabled))]
 impl ffi::QObjectDisabled {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "signal_disabled"]
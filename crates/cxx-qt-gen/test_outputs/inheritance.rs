@@ -130,6 +130,9 @@ mod inheritance {
         type QObject = cxx_qt::QObject;
     }
 }
+impl ::cxx_qt::QProperties for inheritance::MyObject {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
 unsafe impl ::cxx_qt::casting::Upcast<inheritance::QAbstractItemModel> for inheritance::MyObject {
     unsafe fn upcast_ptr(this: *const Self) -> *const inheritance::QAbstractItemModel {
         inheritance::cxx_qt_ffi_MyObject_upcastPtr(this)
@@ -0,0 +1,66 @@
+#include "directory/file_ident.cxxqt.h"
+
+namespace cxx_qt::instantiate {
+Concrete::Concrete()
+  : Concrete(::cxx_qt::instantiate::cxx_qt_Concrete::routeArguments0())
+{
+}
+
+Concrete::Concrete(QString const& arg0)
+  : Concrete(::cxx_qt::instantiate::cxx_qt_Concrete::routeArguments1(
+      ::std::move(arg0)))
+{
+}
+
+Concrete::Concrete(
+  ::cxx_qt::instantiate::cxx_qt_Concrete::CxxQtConstructorArguments0&& args)
+  : QObject()
+  , ::rust::cxxqt1::CxxQtType<ConcreteRust>(
+      ::cxx_qt::instantiate::cxx_qt_Concrete::newRs0(::std::move(args.new_)))
+{
+  ::cxx_qt::instantiate::cxx_qt_Concrete::initialize0(
+    *this, ::std::move(args.initialize));
+}
+
+Concrete::Concrete(
+  ::cxx_qt::instantiate::cxx_qt_Concrete::CxxQtConstructorArguments1&& args)
+  : QObject()
+  , ::rust::cxxqt1::CxxQtType<ConcreteRust>(
+      ::cxx_qt::instantiate::cxx_qt_Concrete::newRs1(::std::move(args.new_)))
+{
+  ::cxx_qt::instantiate::cxx_qt_Concrete::initialize1(
+    *this, ::std::move(args.initialize));
+}
+
+} // namespace cxx_qt::instantiate
+
+namespace cxx_qt::instantiate {
+AbstractObject::AbstractObject()
+  : AbstractObject(
+      ::cxx_qt::instantiate::cxx_qt_AbstractObject::routeArguments0())
+{
+}
+
+AbstractObject::AbstractObject(
+  ::cxx_qt::instantiate::cxx_qt_AbstractObject::CxxQtConstructorArguments0&&
+    args)
+  : QObject()
+  , ::rust::cxxqt1::CxxQtType<AbstractObjectRust>(
+      ::cxx_qt::instantiate::cxx_qt_AbstractObject::newRs0(
+        ::std::move(args.new_)))
+{
+  ::cxx_qt::instantiate::cxx_qt_AbstractObject::initialize0(
+    *this, ::std::move(args.initialize));
+}
+
+} // namespace cxx_qt::instantiate
+
+namespace cxx_qt::instantiate {
+DefaultOnly::DefaultOnly(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<DefaultOnlyRust>(
+      ::cxx_qt::instantiate::cxx_qt_DefaultOnly::createRs())
+{
+}
+
+} // namespace cxx_qt::instantiate
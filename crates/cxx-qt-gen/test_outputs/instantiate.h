@@ -0,0 +1,115 @@
+#pragma once
+
+#include <cxx-qt/casting.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::instantiate {
+class Concrete;
+
+} // namespace cxx_qt::instantiate
+
+namespace cxx_qt::instantiate {
+class AbstractObject;
+
+} // namespace cxx_qt::instantiate
+
+namespace cxx_qt::instantiate {
+class DefaultOnly;
+
+} // namespace cxx_qt::instantiate
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::instantiate {
+/// A QObject which can be instantiated from Rust
+class Concrete
+  : public QObject
+  , private ::rust::cxxqt1::CxxQtType<ConcreteRust>
+{
+  Q_OBJECT
+public:
+  virtual ~Concrete() = default;
+
+public:
+  explicit Concrete();
+  explicit Concrete(QString const& arg0);
+
+private:
+  explicit Concrete(
+    ::cxx_qt::instantiate::cxx_qt_Concrete::CxxQtConstructorArguments0&& args);
+  explicit Concrete(
+    ::cxx_qt::instantiate::cxx_qt_Concrete::CxxQtConstructorArguments1&& args);
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, Concrete>::value,
+              "Concrete must inherit from QObject");
+} // namespace cxx_qt::instantiate
+
+Q_DECLARE_METATYPE(cxx_qt::instantiate::Concrete*)
+
+namespace cxx_qt::instantiate {
+/// An abstract QObject which can only be instantiated from a subclass in C++
+class AbstractObject
+  : public QObject
+  , private ::rust::cxxqt1::CxxQtType<AbstractObjectRust>
+{
+  Q_OBJECT
+public:
+  virtual ~AbstractObject() = default;
+
+public:
+  Q_INVOKABLE virtual void log(QString const& message) const noexcept = 0;
+  explicit AbstractObject();
+
+private:
+  explicit AbstractObject(
+    ::cxx_qt::instantiate::cxx_qt_AbstractObject::CxxQtConstructorArguments0&&
+      args);
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, AbstractObject>::value,
+              "AbstractObject must inherit from QObject");
+} // namespace cxx_qt::instantiate
+
+Q_DECLARE_METATYPE(cxx_qt::instantiate::AbstractObject*)
+
+namespace cxx_qt::instantiate {
+/// A QObject which only has the default constructor
+class DefaultOnly
+  : public QObject
+  , private ::rust::cxxqt1::CxxQtType<DefaultOnlyRust>
+{
+  Q_OBJECT
+public:
+  virtual ~DefaultOnly() = default;
+
+public:
+  explicit DefaultOnly(QObject* parent = nullptr);
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, DefaultOnly>::value,
+              "DefaultOnly must inherit from QObject");
+} // namespace cxx_qt::instantiate
+
+Q_DECLARE_METATYPE(cxx_qt::instantiate::DefaultOnly*)
@@ -0,0 +1,497 @@
+#[cxx::bridge(namespace = "cxx_qt::instantiate")]
+#[allow(unused_unsafe)]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "ConcreteRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::instantiate"]
+        type Concrete;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::instantiate"]
+        type ConcreteRust;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_Concrete_upcastPtr(thiz: *const Concrete) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_Concrete_downcastPtr(base: *const QObject) -> *const Concrete;
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+    #[cxx_name = "CxxQtConstructorArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorArgumentsConcrete0 {
+        base: CxxQtConstructorBaseArgumentsConcrete0,
+        #[cxx_name = "new_"]
+        new: CxxQtConstructorNewArgumentsConcrete0,
+        initialize: CxxQtConstructorInitializeArgumentsConcrete0,
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+    #[cxx_name = "CxxQtConstructorBaseArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorBaseArgumentsConcrete0 {
+        not_empty: i8,
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+    #[cxx_name = "CxxQtConstructorNewArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorNewArgumentsConcrete0 {
+        not_empty: i8,
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+    #[cxx_name = "CxxQtConstructorInitializeArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorInitializeArgumentsConcrete0 {
+        not_empty: i8,
+    }
+    #[allow(clippy::needless_lifetimes)]
+    extern "Rust" {
+        #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+        #[cxx_name = "routeArguments0"]
+        fn route_arguments_Concrete_0() -> CxxQtConstructorArgumentsConcrete0;
+        #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+        #[cxx_name = "newRs0"]
+        fn new_rs_Concrete_0(args: CxxQtConstructorNewArgumentsConcrete0) -> Box<ConcreteRust>;
+        #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+        #[cxx_name = "initialize0"]
+        fn initialize_Concrete_0(
+            qobject: Pin<&mut Concrete>,
+            args: CxxQtConstructorInitializeArgumentsConcrete0,
+        );
+    }
+    unsafe extern "C++" {
+        include ! (< cxx - qt / instantiate . h >);
+        #[doc(hidden)]
+        #[cxx_name = "qobjectNew"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_Concrete_new0() -> UniquePtr<Concrete>;
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+    #[cxx_name = "CxxQtConstructorArguments1"]
+    #[doc(hidden)]
+    struct CxxQtConstructorArgumentsConcrete1 {
+        base: CxxQtConstructorBaseArgumentsConcrete1,
+        #[cxx_name = "new_"]
+        new: CxxQtConstructorNewArgumentsConcrete1,
+        initialize: CxxQtConstructorInitializeArgumentsConcrete1,
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+    #[cxx_name = "CxxQtConstructorBaseArguments1"]
+    #[doc(hidden)]
+    struct CxxQtConstructorBaseArgumentsConcrete1 {
+        not_empty: i8,
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+    #[cxx_name = "CxxQtConstructorNewArguments1"]
+    #[doc(hidden)]
+    struct CxxQtConstructorNewArgumentsConcrete1 {
+        not_empty: i8,
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+    #[cxx_name = "CxxQtConstructorInitializeArguments1"]
+    #[doc(hidden)]
+    struct CxxQtConstructorInitializeArgumentsConcrete1 {
+        not_empty: i8,
+    }
+    #[allow(clippy::needless_lifetimes)]
+    extern "Rust" {
+        #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+        #[cxx_name = "routeArguments1"]
+        unsafe fn route_arguments_Concrete_1<'a>(
+            arg0: &'a QString,
+        ) -> CxxQtConstructorArgumentsConcrete1;
+        #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+        #[cxx_name = "newRs1"]
+        unsafe fn new_rs_Concrete_1(
+            args: CxxQtConstructorNewArgumentsConcrete1,
+        ) -> Box<ConcreteRust>;
+        #[namespace = "cxx_qt::instantiate::cxx_qt_Concrete"]
+        #[cxx_name = "initialize1"]
+        unsafe fn initialize_Concrete_1(
+            qobject: Pin<&mut Concrete>,
+            args: CxxQtConstructorInitializeArgumentsConcrete1,
+        );
+    }
+    unsafe extern "C++" {
+        include ! (< cxx - qt / instantiate . h >);
+        #[doc(hidden)]
+        #[cxx_name = "qobjectNew"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_Concrete_new1<'a>(arg0: &'a QString) -> UniquePtr<Concrete>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_Concrete_unsafeRust(outer: &Concrete) -> &ConcreteRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_Concrete_unsafeRustMut(outer: Pin<&mut Concrete>) -> Pin<&mut ConcreteRust>;
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "AbstractObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::instantiate"]
+        type AbstractObject;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::instantiate"]
+        type AbstractObjectRust;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "log"]
+        #[namespace = "cxx_qt::instantiate"]
+        #[doc(hidden)]
+        fn log(self: &AbstractObject, message: &QString);
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_AbstractObject_upcastPtr(
+            thiz: *const AbstractObject,
+        ) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_AbstractObject_downcastPtr(
+            base: *const QObject,
+        ) -> *const AbstractObject;
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_AbstractObject"]
+    #[cxx_name = "CxxQtConstructorArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorArgumentsAbstractObject0 {
+        base: CxxQtConstructorBaseArgumentsAbstractObject0,
+        #[cxx_name = "new_"]
+        new: CxxQtConstructorNewArgumentsAbstractObject0,
+        initialize: CxxQtConstructorInitializeArgumentsAbstractObject0,
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_AbstractObject"]
+    #[cxx_name = "CxxQtConstructorBaseArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorBaseArgumentsAbstractObject0 {
+        not_empty: i8,
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_AbstractObject"]
+    #[cxx_name = "CxxQtConstructorNewArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorNewArgumentsAbstractObject0 {
+        not_empty: i8,
+    }
+    #[namespace = "cxx_qt::instantiate::cxx_qt_AbstractObject"]
+    #[cxx_name = "CxxQtConstructorInitializeArguments0"]
+    #[doc(hidden)]
+    struct CxxQtConstructorInitializeArgumentsAbstractObject0 {
+        not_empty: i8,
+    }
+    #[allow(clippy::needless_lifetimes)]
+    extern "Rust" {
+        #[namespace = "cxx_qt::instantiate::cxx_qt_AbstractObject"]
+        #[cxx_name = "routeArguments0"]
+        fn route_arguments_AbstractObject_0() -> CxxQtConstructorArgumentsAbstractObject0;
+        #[namespace = "cxx_qt::instantiate::cxx_qt_AbstractObject"]
+        #[cxx_name = "newRs0"]
+        fn new_rs_AbstractObject_0(
+            args: CxxQtConstructorNewArgumentsAbstractObject0,
+        ) -> Box<AbstractObjectRust>;
+        #[namespace = "cxx_qt::instantiate::cxx_qt_AbstractObject"]
+        #[cxx_name = "initialize0"]
+        fn initialize_AbstractObject_0(
+            qobject: Pin<&mut AbstractObject>,
+            args: CxxQtConstructorInitializeArgumentsAbstractObject0,
+        );
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_AbstractObject_unsafeRust(outer: &AbstractObject) -> &AbstractObjectRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_AbstractObject_unsafeRustMut(
+            outer: Pin<&mut AbstractObject>,
+        ) -> Pin<&mut AbstractObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "DefaultOnlyRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::instantiate"]
+        type DefaultOnly;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::instantiate"]
+        type DefaultOnlyRust;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_DefaultOnly_upcastPtr(thiz: *const DefaultOnly) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_DefaultOnly_downcastPtr(base: *const QObject) -> *const DefaultOnly;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::instantiate::cxx_qt_DefaultOnly"]
+        fn create_rs_DefaultOnlyRust() -> Box<DefaultOnlyRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_DefaultOnly_unsafeRust(outer: &DefaultOnly) -> &DefaultOnlyRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_DefaultOnly_unsafeRustMut(
+            outer: Pin<&mut DefaultOnly>,
+        ) -> Pin<&mut DefaultOnlyRust>;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QObject = cxx_qt::QObject;
+    }
+}
+impl ::cxx_qt::QProperties for ffi::Concrete {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::Concrete {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_Concrete_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_Concrete_downcastPtr(base)
+    }
+}
+#[allow(clippy::needless_lifetimes)]
+impl ::cxx_qt::ConstructorDeclared<()> for ffi::Concrete {}
+#[doc(hidden)]
+pub fn route_arguments_Concrete_0() -> ffi::CxxQtConstructorArgumentsConcrete0 {
+    #[allow(unused_variables)]
+    #[allow(clippy::let_unit_value)]
+    let (new_arguments, base_arguments, initialize_arguments) =
+        <ffi::Concrete as cxx_qt::Constructor<()>>::route_arguments(());
+    ffi::CxxQtConstructorArgumentsConcrete0 {
+        base: ffi::CxxQtConstructorBaseArgumentsConcrete0 { not_empty: 0 },
+        initialize: ffi::CxxQtConstructorInitializeArgumentsConcrete0 { not_empty: 0 },
+        new: ffi::CxxQtConstructorNewArgumentsConcrete0 { not_empty: 0 },
+    }
+}
+#[doc(hidden)]
+#[allow(unused_variables)]
+#[allow(clippy::extra_unused_lifetimes)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn new_rs_Concrete_0(
+    new_arguments: ffi::CxxQtConstructorNewArgumentsConcrete0,
+) -> std::boxed::Box<ConcreteRust> {
+    std::boxed::Box::new(<ffi::Concrete as cxx_qt::Constructor<()>>::new(()))
+}
+#[doc(hidden)]
+#[allow(unused_variables)]
+#[allow(clippy::extra_unused_lifetimes)]
+pub fn initialize_Concrete_0(
+    qobject: core::pin::Pin<&mut ffi::Concrete>,
+    initialize_arguments: ffi::CxxQtConstructorInitializeArgumentsConcrete0,
+) {
+    <ffi::Concrete as cxx_qt::Constructor<()>>::initialize(qobject, ());
+}
+impl cxx_qt::Instantiate<()> for ffi::Concrete {
+    fn new_unique((): ()) -> cxx::UniquePtr<Self> {
+        ffi::cxx_qt_ffi_Concrete_new0()
+    }
+}
+#[allow(clippy::needless_lifetimes)]
+impl<'a> ::cxx_qt::ConstructorDeclared<(&'a QString,)> for ffi::Concrete {}
+#[doc(hidden)]
+pub fn route_arguments_Concrete_1<'a>(
+    arg0: &'a ffi::QString,
+) -> ffi::CxxQtConstructorArgumentsConcrete1 {
+    #[allow(unused_variables)]
+    #[allow(clippy::let_unit_value)]
+    let (new_arguments, base_arguments, initialize_arguments) =
+        <ffi::Concrete as cxx_qt::Constructor<(&'a ffi::QString,)>>::route_arguments((arg0,));
+    ffi::CxxQtConstructorArgumentsConcrete1 {
+        base: ffi::CxxQtConstructorBaseArgumentsConcrete1 { not_empty: 0 },
+        initialize: ffi::CxxQtConstructorInitializeArgumentsConcrete1 { not_empty: 0 },
+        new: ffi::CxxQtConstructorNewArgumentsConcrete1 { not_empty: 0 },
+    }
+}
+#[doc(hidden)]
+#[allow(unused_variables)]
+#[allow(clippy::extra_unused_lifetimes)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn new_rs_Concrete_1<'a>(
+    new_arguments: ffi::CxxQtConstructorNewArgumentsConcrete1,
+) -> std::boxed::Box<ConcreteRust> {
+    std::boxed::Box::new(<ffi::Concrete as cxx_qt::Constructor<
+        (&'a ffi::QString,),
+    >>::new(()))
+}
+#[doc(hidden)]
+#[allow(unused_variables)]
+#[allow(clippy::extra_unused_lifetimes)]
+pub fn initialize_Concrete_1<'a>(
+    qobject: core::pin::Pin<&mut ffi::Concrete>,
+    initialize_arguments: ffi::CxxQtConstructorInitializeArgumentsConcrete1,
+) {
+    <ffi::Concrete as cxx_qt::Constructor<(&'a ffi::QString,)>>::initialize(qobject, ());
+}
+impl<'a> cxx_qt::Instantiate<(&'a ffi::QString,)> for ffi::Concrete {
+    fn new_unique((arg0,): (&'a ffi::QString,)) -> cxx::UniquePtr<Self> {
+        ffi::cxx_qt_ffi_Concrete_new1(arg0)
+    }
+}
+impl ::core::ops::Deref for ffi::Concrete {
+    type Target = ConcreteRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_Concrete_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::Concrete {
+    type Rust = ConcreteRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_Concrete_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_Concrete_unsafeRustMut(self)
+    }
+}
+impl ::cxx_qt::QProperties for ffi::AbstractObject {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::AbstractObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_AbstractObject_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_AbstractObject_downcastPtr(base)
+    }
+}
+#[allow(clippy::needless_lifetimes)]
+impl ::cxx_qt::ConstructorDeclared<()> for ffi::AbstractObject {}
+#[doc(hidden)]
+pub fn route_arguments_AbstractObject_0() -> ffi::CxxQtConstructorArgumentsAbstractObject0 {
+    #[allow(unused_variables)]
+    #[allow(clippy::let_unit_value)]
+    let (new_arguments, base_arguments, initialize_arguments) =
+        <ffi::AbstractObject as cxx_qt::Constructor<()>>::route_arguments(());
+    ffi::CxxQtConstructorArgumentsAbstractObject0 {
+        base: ffi::CxxQtConstructorBaseArgumentsAbstractObject0 { not_empty: 0 },
+        initialize: ffi::CxxQtConstructorInitializeArgumentsAbstractObject0 { not_empty: 0 },
+        new: ffi::CxxQtConstructorNewArgumentsAbstractObject0 { not_empty: 0 },
+    }
+}
+#[doc(hidden)]
+#[allow(unused_variables)]
+#[allow(clippy::extra_unused_lifetimes)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn new_rs_AbstractObject_0(
+    new_arguments: ffi::CxxQtConstructorNewArgumentsAbstractObject0,
+) -> std::boxed::Box<AbstractObjectRust> {
+    std::boxed::Box::new(<ffi::AbstractObject as cxx_qt::Constructor<()>>::new(()))
+}
+#[doc(hidden)]
+#[allow(unused_variables)]
+#[allow(clippy::extra_unused_lifetimes)]
+pub fn initialize_AbstractObject_0(
+    qobject: core::pin::Pin<&mut ffi::AbstractObject>,
+    initialize_arguments: ffi::CxxQtConstructorInitializeArgumentsAbstractObject0,
+) {
+    <ffi::AbstractObject as cxx_qt::Constructor<()>>::initialize(qobject, ());
+}
+impl ::core::ops::Deref for ffi::AbstractObject {
+    type Target = AbstractObjectRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_AbstractObject_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::AbstractObject {
+    type Rust = AbstractObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_AbstractObject_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_AbstractObject_unsafeRustMut(self)
+    }
+}
+impl ::cxx_qt::QProperties for ffi::DefaultOnly {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::DefaultOnly {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_DefaultOnly_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_DefaultOnly_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_DefaultOnlyRust() -> std::boxed::Box<DefaultOnlyRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::DefaultOnly {
+    type Target = DefaultOnlyRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_DefaultOnly_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::DefaultOnly {
+    type Rust = DefaultOnlyRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_DefaultOnly_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_DefaultOnly_unsafeRustMut(self)
+    }
+}
@@ -6,6 +6,11 @@ static_assert(alignof(MyObjectCxxQtThread) <= alignof(::std::size_t),
 static_assert(sizeof(MyObjectCxxQtThread) == sizeof(::std::size_t[2]),
               "unexpected size");
 
+MyObject::~MyObject()
+{
+  ::cxx_qt::my_object::cxx_qt_MyObject::finalize(*this);
+}
+
 MyObject::MyObject(::std::int32_t arg0, QString const& arg1)
   : MyObject(
       ::cxx_qt::my_object::cxx_qt_MyObject::routeArguments0(::std::move(arg0),
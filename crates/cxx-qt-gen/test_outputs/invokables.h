@@ -20,10 +20,13 @@ class MyObject
 {
   Q_OBJECT
 public:
-  virtual ~MyObject() = default;
+  virtual ~MyObject();
 
 public:
   void cpp_method() const noexcept;
+  /// An invokable without parameters
+  ///
+  /// Callable from QML
   Q_INVOKABLE void invokable() const noexcept;
   Q_INVOKABLE void invokable_mutable() noexcept;
   Q_INVOKABLE void invokable_parameters(
@@ -38,6 +41,16 @@ public:
   Q_INVOKABLE virtual void invokable_pure_virtual() const noexcept = 0;
   Q_INVOKABLE void invokable_result_tuple() const;
   Q_INVOKABLE ::rust::String invokable_result_type() const;
+  Q_INVOKABLE static ::std::int32_t invokable_static(
+    ::std::int32_t value) noexcept;
+  Q_INVOKABLE ::std::int32_t invokable_default(
+    ::std::int32_t value,
+    ::std::int32_t step) const noexcept;
+  Q_INVOKABLE ::std::int32_t invokable_default(
+    ::std::int32_t value) const noexcept;
+  Q_INVOKABLE ::std::int32_t invokable_result(QString const& text) const;
+  Q_INVOKABLE void invokableOverloaded(::std::int32_t value) const noexcept;
+  Q_INVOKABLE void invokableOverloaded(QString const& value) const noexcept;
   explicit MyObject(::std::int32_t arg0, QString const& arg1);
   explicit MyObject();
 
@@ -121,6 +121,43 @@ mod ffi {
         #[doc(hidden)]
         unsafe fn invokable_result_type(self: &MyObject) -> Result<String>;
     }
+    extern "Rust" {
+        #[cxx_name = "invokable_static"]
+        #[namespace = "cxx_qt::my_object"]
+        #[Self = "MyObject"]
+        #[doc(hidden)]
+        unsafe fn invokable_static(value: i32) -> i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "invokable_default"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        unsafe fn invokable_default(self: &MyObject, value: i32, step: i32) -> i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "invokable_default"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        unsafe fn invokable_default_cxx_qt_default_1(self: &MyObject, value: i32) -> i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "invokable_result"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        unsafe fn invokable_result(self: &MyObject, text: &QString) -> Result<i32>;
+    }
+    extern "Rust" {
+        #[cxx_name = "invokableOverloaded"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        unsafe fn invokable_overloaded_int(self: &MyObject, value: i32);
+    }
+    extern "Rust" {
+        #[cxx_name = "invokableOverloaded"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        unsafe fn invokable_overloaded_string(self: &MyObject, value: &QString);
+    }
     unsafe extern "C++" {
         #[doc(hidden)]
         #[namespace = "cxx_qt::my_object"]
@@ -157,6 +194,11 @@ mod ffi {
         #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
         type MyObjectCxxQtThreadQueuedFn;
     }
+    extern "Rust" {
+        #[namespace = "cxx_qt::my_object::cxx_qt_MyObject"]
+        #[cxx_name = "finalize"]
+        fn finalize_MyObject(qobject: Pin<&mut MyObject>);
+    }
     extern "C++" {
         #[doc(hidden)]
         #[cxx_name = "upcastPtr"]
@@ -274,6 +316,15 @@ mod ffi {
         type QObject = cxx_qt::QObject;
     }
 }
+impl ::cxx_qt::QProperties for ffi::MyObject {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
+impl ffi::MyObject {
+    #[doc(hidden)]
+    pub fn invokable_default_cxx_qt_default_1(self: &ffi::MyObject, value: i32) -> i32 {
+        Self::invokable_default(self, value, 10)
+    }
+}
 impl cxx_qt::Threading for ffi::MyObject {
     type BoxedQueuedFn = MyObjectCxxQtThreadQueuedFn;
     type ThreadingTypeId = cxx::type_id!("cxx_qt::my_object::MyObjectCxxQtThread");
@@ -326,6 +377,10 @@ impl cxx_qt::Threading for ffi::MyObject {
 pub struct MyObjectCxxQtThreadQueuedFn {
     inner: std::boxed::Box<dyn FnOnce(core::pin::Pin<&mut ffi::MyObject>) + Send>,
 }
+#[doc(hidden)]
+pub fn finalize_MyObject(qobject: core::pin::Pin<&mut ffi::MyObject>) {
+    <ffi::MyObject as cxx_qt::Finalize>::finalize(qobject);
+}
 unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyObject {
     unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
         ffi::cxx_qt_ffi_MyObject_upcastPtr(this)
@@ -0,0 +1,297 @@
+#include "directory/file_ident.cxxqt.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<::rust::cxxqtgen1::QCompleterCxxQtSignalParamsactivated_text*>::
+  ~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_QCompleter_signal_handler_activated_text(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::rust::cxxqtgen1::QCompleterCxxQtSignalParamsactivated_text*>::
+operator()<QCompleter&, QString const&>(QCompleter& self, QString const& text)
+{
+  call_QCompleter_signal_handler_activated_text(
+    *this, self, ::std::move(text));
+}
+
+static_assert(
+  alignof(
+    SignalHandler<
+      ::rust::cxxqtgen1::QCompleterCxxQtSignalParamsactivated_text*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(
+    SignalHandler<
+      ::rust::cxxqtgen1::QCompleterCxxQtSignalParamsactivated_text*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace rust::cxxqtgen1 {
+::QMetaObject::Connection
+QCompleter_activatedConnect(
+  QCompleter& self,
+  ::rust::cxxqtgen1::QCompleterCxxQtSignalHandleractivated_text closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    QOverload<QString const&>::of(&QCompleter::activated),
+    &self,
+    [&, closure = ::std::move(closure)](QString const& text) mutable {
+      closure.template operator()<QCompleter&, QString const&>(
+        self, ::std::move(text));
+    },
+    type);
+}
+} // namespace rust::cxxqtgen1
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<::rust::cxxqtgen1::QCompleterCxxQtSignalParamsactivated_index*>::
+  ~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_QCompleter_signal_handler_activated_index(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::rust::cxxqtgen1::QCompleterCxxQtSignalParamsactivated_index*>::
+operator()<QCompleter&, QModelIndex const&>(QCompleter& self,
+                                            QModelIndex const& index)
+{
+  call_QCompleter_signal_handler_activated_index(
+    *this, self, ::std::move(index));
+}
+
+static_assert(
+  alignof(
+    SignalHandler<
+      ::rust::cxxqtgen1::QCompleterCxxQtSignalParamsactivated_index*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(
+    SignalHandler<
+      ::rust::cxxqtgen1::QCompleterCxxQtSignalParamsactivated_index*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace rust::cxxqtgen1 {
+::QMetaObject::Connection
+QCompleter_activatedConnect(
+  QCompleter& self,
+  ::rust::cxxqtgen1::QCompleterCxxQtSignalHandleractivated_index closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    QOverload<QModelIndex const&>::of(&QCompleter::activated),
+    &self,
+    [&, closure = ::std::move(closure)](QModelIndex const& index) mutable {
+      closure.template operator()<QCompleter&, QModelIndex const&>(
+        self, ::std::move(index));
+    },
+    type);
+}
+} // namespace rust::cxxqtgen1
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamsvalue_changed*>::
+  ~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_MyObject_signal_handler_value_changed(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamsvalue_changed*>::
+operator()<cxx_qt::overloads::MyObject&>(cxx_qt::overloads::MyObject& self)
+{
+  call_MyObject_signal_handler_value_changed(*this, self);
+}
+
+static_assert(alignof(SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                                      MyObjectCxxQtSignalParamsvalue_changed*>) <=
+                alignof(::std::size_t),
+              "unexpected aligment");
+static_assert(sizeof(SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                                     MyObjectCxxQtSignalParamsvalue_changed*>) ==
+                sizeof(::std::size_t[2]),
+              "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::overloads::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_valueChangedConnect(
+  cxx_qt::overloads::MyObject& self,
+  ::cxx_qt::overloads::rust::cxxqtgen1::MyObjectCxxQtSignalHandlervalue_changed
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    QOverload<>::of(&cxx_qt::overloads::MyObject::valueChanged),
+    &self,
+    [&, closure = ::std::move(closure)]() mutable {
+      closure.template operator()<cxx_qt::overloads::MyObject&>(self);
+    },
+    type);
+}
+} // namespace cxx_qt::overloads::rust::cxxqtgen1
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamsdoubledChanged*>::
+  ~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_MyObject_signal_handler_doubledChanged(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamsdoubledChanged*>::
+operator()<cxx_qt::overloads::MyObject&>(cxx_qt::overloads::MyObject& self)
+{
+  call_MyObject_signal_handler_doubledChanged(*this, self);
+}
+
+static_assert(alignof(SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                                      MyObjectCxxQtSignalParamsdoubledChanged*>) <=
+                alignof(::std::size_t),
+              "unexpected aligment");
+static_assert(sizeof(SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                                     MyObjectCxxQtSignalParamsdoubledChanged*>) ==
+                sizeof(::std::size_t[2]),
+              "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::overloads::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_doubledChangedConnect(
+  cxx_qt::overloads::MyObject& self,
+  ::cxx_qt::overloads::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdoubledChanged
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::overloads::MyObject::doubledChanged,
+    &self,
+    [&, closure = ::std::move(closure)]() mutable {
+      closure.template operator()<cxx_qt::overloads::MyObject&>(self);
+    },
+    type);
+}
+} // namespace cxx_qt::overloads::rust::cxxqtgen1
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamsvalue_changed_from*>::
+  ~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_MyObject_signal_handler_value_changed_from(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                MyObjectCxxQtSignalParamsvalue_changed_from*>::
+operator()<cxx_qt::overloads::MyObject&, ::std::int32_t>(
+  cxx_qt::overloads::MyObject& self,
+  ::std::int32_t previous)
+{
+  call_MyObject_signal_handler_value_changed_from(
+    *this, self, ::std::move(previous));
+}
+
+static_assert(alignof(SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                                      MyObjectCxxQtSignalParamsvalue_changed_from*>) <=
+                alignof(::std::size_t),
+              "unexpected aligment");
+static_assert(sizeof(SignalHandler<::cxx_qt::overloads::rust::cxxqtgen1::
+                                     MyObjectCxxQtSignalParamsvalue_changed_from*>) ==
+                sizeof(::std::size_t[2]),
+              "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::overloads::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_valueChangedConnect(
+  cxx_qt::overloads::MyObject& self,
+  ::cxx_qt::overloads::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlervalue_changed_from closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    QOverload<::std::int32_t>::of(&cxx_qt::overloads::MyObject::valueChanged),
+    &self,
+    [&, closure = ::std::move(closure)](::std::int32_t previous) mutable {
+      closure
+        .template operator()<cxx_qt::overloads::MyObject&, ::std::int32_t>(
+          self, ::std::move(previous));
+    },
+    type);
+}
+} // namespace cxx_qt::overloads::rust::cxxqtgen1
+
+namespace cxx_qt::overloads {
+MyObject::MyObject(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<MyObjectRust>(
+      ::cxx_qt::overloads::cxx_qt_MyObject::createRs())
+{
+  QObject::connect(this,
+                   QOverload<>::of(&MyObject::valueChanged),
+                   this,
+                   &MyObject::doubledChanged);
+}
+
+} // namespace cxx_qt::overloads
@@ -0,0 +1,125 @@
+#pragma once
+
+#include <cxx-qt/casting.h>
+#include <cxx-qt/signalhandler.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::overloads {
+class MyObject;
+
+} // namespace cxx_qt::overloads
+
+namespace cxx_qt::overloads::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlervalue_changed =
+  ::rust::cxxqt1::SignalHandler<struct MyObjectCxxQtSignalParamsvalue_changed*>;
+} // namespace cxx_qt::overloads::rust::cxxqtgen1
+
+namespace cxx_qt::overloads::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlerdoubledChanged =
+  ::rust::cxxqt1::SignalHandler<
+    struct MyObjectCxxQtSignalParamsdoubledChanged*>;
+} // namespace cxx_qt::overloads::rust::cxxqtgen1
+
+namespace cxx_qt::overloads::rust::cxxqtgen1 {
+using MyObjectCxxQtSignalHandlervalue_changed_from =
+  ::rust::cxxqt1::SignalHandler<
+    struct MyObjectCxxQtSignalParamsvalue_changed_from*>;
+} // namespace cxx_qt::overloads::rust::cxxqtgen1
+
+namespace rust::cxxqtgen1 {
+using QCompleterCxxQtSignalHandleractivated_text =
+  ::rust::cxxqt1::SignalHandler<
+    struct QCompleterCxxQtSignalParamsactivated_text*>;
+} // namespace rust::cxxqtgen1
+
+namespace rust::cxxqtgen1 {
+using QCompleterCxxQtSignalHandleractivated_index =
+  ::rust::cxxqt1::SignalHandler<
+    struct QCompleterCxxQtSignalParamsactivated_index*>;
+} // namespace rust::cxxqtgen1
+
+#include "directory/file_ident.cxx.h"
+
+namespace rust::cxxqtgen1 {
+::QMetaObject::Connection
+QCompleter_activatedConnect(
+  QCompleter& self,
+  ::rust::cxxqtgen1::QCompleterCxxQtSignalHandleractivated_text closure,
+  ::Qt::ConnectionType type);
+} // namespace rust::cxxqtgen1
+
+namespace rust::cxxqtgen1 {
+::QMetaObject::Connection
+QCompleter_activatedConnect(
+  QCompleter& self,
+  ::rust::cxxqtgen1::QCompleterCxxQtSignalHandleractivated_index closure,
+  ::Qt::ConnectionType type);
+} // namespace rust::cxxqtgen1
+
+namespace cxx_qt::overloads::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_valueChangedConnect(
+  cxx_qt::overloads::MyObject& self,
+  ::cxx_qt::overloads::rust::cxxqtgen1::MyObjectCxxQtSignalHandlervalue_changed
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::overloads::rust::cxxqtgen1
+
+namespace cxx_qt::overloads::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_doubledChangedConnect(
+  cxx_qt::overloads::MyObject& self,
+  ::cxx_qt::overloads::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdoubledChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::overloads::rust::cxxqtgen1
+
+namespace cxx_qt::overloads::rust::cxxqtgen1 {
+::QMetaObject::Connection
+MyObject_valueChangedConnect(
+  cxx_qt::overloads::MyObject& self,
+  ::cxx_qt::overloads::rust::cxxqtgen1::
+    MyObjectCxxQtSignalHandlervalue_changed_from closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::overloads::rust::cxxqtgen1
+
+namespace cxx_qt::overloads {
+class MyObject
+  : public QObject
+  , private ::rust::cxxqt1::CxxQtType<MyObjectRust>
+{
+  Q_OBJECT
+public:
+  Q_PROPERTY(
+    ::std::int32_t value READ getValue WRITE setValue NOTIFY valueChanged)
+  Q_PROPERTY(::std::int32_t doubled READ getDoubled NOTIFY doubledChanged)
+
+  virtual ~MyObject() = default;
+
+public:
+  ::std::int32_t const& getValue() const noexcept;
+  Q_SLOT void setValue(::std::int32_t value) noexcept;
+  ::std::int32_t const& getDoubled() const noexcept;
+  /// Notify for the Q_PROPERTY
+  Q_SIGNAL void valueChanged();
+  /// Notify for the Q_PROPERTY
+  Q_SIGNAL void doubledChanged();
+  Q_INVOKABLE void reset() noexcept;
+  Q_INVOKABLE void reset(::std::int32_t value) noexcept;
+  /// An overload of the NOTIFY signal of the value property
+  Q_SIGNAL void valueChanged(::std::int32_t previous);
+  explicit MyObject(QObject* parent = nullptr);
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, MyObject>::value,
+              "MyObject must inherit from QObject");
+} // namespace cxx_qt::overloads
+
+Q_DECLARE_METATYPE(cxx_qt::overloads::MyObject*)
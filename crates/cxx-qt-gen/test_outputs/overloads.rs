@@ -0,0 +1,716 @@
+#[cxx::bridge(namespace = "cxx_qt::overloads")]
+#[allow(unused_unsafe)]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qmodelindex.h");
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "MyObjectRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::overloads"]
+        type MyObject;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::overloads"]
+        type MyObjectRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "getValue"]
+        #[namespace = "cxx_qt::overloads"]
+        unsafe fn value<'a>(self: &'a MyObject) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "setValue"]
+        #[namespace = "cxx_qt::overloads"]
+        fn set_value(self: Pin<&mut MyObject>, value: i32);
+    }
+    extern "Rust" {
+        #[cxx_name = "getDoubled"]
+        #[namespace = "cxx_qt::overloads"]
+        unsafe fn doubled<'a>(self: &'a MyObject) -> &'a i32;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "valueChanged"]
+        #[doc = "Notify for the Q_PROPERTY"]
+        #[namespace = "cxx_qt::overloads"]
+        fn value_changed(self: Pin<&mut MyObject>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::overloads::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlervalue_changed = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MyObjectCxxQtSignalClosurevalue_changed,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::overloads::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_valueChangedConnect"]
+        fn MyObject_connect_value_changed(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlervalue_changed,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::overloads::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_value_changed(
+            handler: MyObjectCxxQtSignalHandlervalue_changed,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_value_changed(
+            handler: &mut MyObjectCxxQtSignalHandlervalue_changed,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "doubledChanged"]
+        #[doc = "Notify for the Q_PROPERTY"]
+        #[namespace = "cxx_qt::overloads"]
+        fn doubled_changed(self: Pin<&mut MyObject>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::overloads::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlerdoubledChanged = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::MyObjectCxxQtSignalClosuredoubledChanged,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::overloads::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_doubledChangedConnect"]
+        fn MyObject_connect_doubled_changed(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlerdoubledChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::overloads::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_doubledChanged(
+            handler: MyObjectCxxQtSignalHandlerdoubledChanged,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_doubledChanged(
+            handler: &mut MyObjectCxxQtSignalHandlerdoubledChanged,
+            self_value: Pin<&mut MyObject>,
+        );
+    }
+    unsafe extern "C++" {
+        include ! (< cxx - qt / property . h >);
+        #[doc(hidden)]
+        #[cxx_name = "propertyMetaTypeId"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_propertyMetaTypeId(thiz: *const MyObject, name: &str) -> i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "reset"]
+        #[namespace = "cxx_qt::overloads"]
+        #[doc(hidden)]
+        unsafe fn reset(self: Pin<&mut MyObject>);
+    }
+    extern "Rust" {
+        #[cxx_name = "reset"]
+        #[namespace = "cxx_qt::overloads"]
+        #[doc(hidden)]
+        unsafe fn reset_to(self: Pin<&mut MyObject>, value: i32);
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "valueChanged"]
+        #[doc = " An overload of the NOTIFY signal of the value property"]
+        #[namespace = "cxx_qt::overloads"]
+        fn value_changed_from(self: Pin<&mut MyObject>, previous: i32);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::overloads::rust::cxxqtgen1"]
+        type MyObjectCxxQtSignalHandlervalue_changed_from =
+            cxx_qt::signalhandler::CxxQtSignalHandler<
+                super::MyObjectCxxQtSignalClosurevalue_changed_from,
+            >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::overloads::rust::cxxqtgen1"]
+        #[cxx_name = "MyObject_valueChangedConnect"]
+        fn MyObject_connect_value_changed_from(
+            self_value: Pin<&mut MyObject>,
+            signal_handler: MyObjectCxxQtSignalHandlervalue_changed_from,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::overloads::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_MyObject_signal_handler_value_changed_from(
+            handler: MyObjectCxxQtSignalHandlervalue_changed_from,
+        );
+        #[doc(hidden)]
+        fn call_MyObject_signal_handler_value_changed_from(
+            handler: &mut MyObjectCxxQtSignalHandlervalue_changed_from,
+            self_value: Pin<&mut MyObject>,
+            previous: i32,
+        );
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_upcastPtr(thiz: *const MyObject) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_downcastPtr(base: *const QObject) -> *const MyObject;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::overloads::cxx_qt_MyObject"]
+        fn create_rs_MyObjectRust() -> Box<MyObjectRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRust(outer: &MyObject) -> &MyObjectRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_MyObject_unsafeRustMut(outer: Pin<&mut MyObject>) -> Pin<&mut MyObjectRust>;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_QCompleter_upcastPtr(thiz: *const QCompleter) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_QCompleter_downcastPtr(base: *const QObject) -> *const QCompleter;
+    }
+    unsafe extern "C++" {
+        type QCompleter;
+    }
+    unsafe extern "C++" {
+        include ! (< QtWidgets / QCompleter >);
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "activated"]
+        #[doc = " The completion is activated with its text"]
+        fn activated_text(self: Pin<&mut QCompleter>, text: &QString);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtgen1"]
+        type QCompleterCxxQtSignalHandleractivated_text = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::QCompleterCxxQtSignalClosureactivated_text,
+        >;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtgen1"]
+        #[cxx_name = "QCompleter_activatedConnect"]
+        fn QCompleter_connect_activated_text(
+            self_value: Pin<&mut QCompleter>,
+            signal_handler: QCompleterCxxQtSignalHandleractivated_text,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_QCompleter_signal_handler_activated_text(
+            handler: QCompleterCxxQtSignalHandleractivated_text,
+        );
+        #[doc(hidden)]
+        fn call_QCompleter_signal_handler_activated_text(
+            handler: &mut QCompleterCxxQtSignalHandleractivated_text,
+            self_value: Pin<&mut QCompleter>,
+            text: &QString,
+        );
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "activated"]
+        #[doc = " The completion is activated with its index"]
+        fn activated_index(self: Pin<&mut QCompleter>, index: &QModelIndex);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtgen1"]
+        type QCompleterCxxQtSignalHandleractivated_index =
+            cxx_qt::signalhandler::CxxQtSignalHandler<
+                super::QCompleterCxxQtSignalClosureactivated_index,
+            >;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqtgen1"]
+        #[cxx_name = "QCompleter_activatedConnect"]
+        fn QCompleter_connect_activated_index(
+            self_value: Pin<&mut QCompleter>,
+            signal_handler: QCompleterCxxQtSignalHandleractivated_index,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_QCompleter_signal_handler_activated_index(
+            handler: QCompleterCxxQtSignalHandleractivated_index,
+        );
+        #[doc(hidden)]
+        fn call_QCompleter_signal_handler_activated_index(
+            handler: &mut QCompleterCxxQtSignalHandleractivated_index,
+            self_value: Pin<&mut QCompleter>,
+            index: &QModelIndex,
+        );
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QObject = cxx_qt::QObject;
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "value"]
+    pub fn value(&self) -> &i32 {
+        &self.value
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "value"]
+    pub fn set_value(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.value == value {
+            return;
+        }
+        self.as_mut().rust_mut().value = value;
+        self.as_mut().value_changed();
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "doubled"]
+    pub fn doubled(&self) -> &i32 {
+        &self.doubled
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "valueChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_value_changed<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_value_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosurevalue_changed > :: new (Box :: new (closure)) , conn_type ,))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "valueChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_value_changed<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_value_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosurevalue_changed > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosurevalue_changed {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosurevalue_changed {
+    type Id = cxx::type_id!(
+        "::cxx_qt::overloads::rust::cxxqtgen1::MyObjectCxxQtSignalHandlervalue_changed"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_value_changed;
+fn call_MyObject_signal_handler_value_changed(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosurevalue_changed,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurevalue_changed>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurevalue_changed>,
+    [usize; 2]
+);
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "doubledChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_doubled_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_doubled_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosuredoubledChanged > :: new (Box :: new (closure)) , conn_type ,))
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "doubledChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_doubled_changed<F: FnMut(core::pin::Pin<&mut ffi::MyObject>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: MyObject_connect_doubled_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < MyObjectCxxQtSignalClosuredoubledChanged > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosuredoubledChanged {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for MyObjectCxxQtSignalClosuredoubledChanged {
+    type Id = cxx::type_id!(
+        "::cxx_qt::overloads::rust::cxxqtgen1::MyObjectCxxQtSignalHandlerdoubledChanged"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_doubledChanged;
+fn call_MyObject_signal_handler_doubledChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosuredoubledChanged,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredoubledChanged>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosuredoubledChanged>,
+    [usize; 2]
+);
+impl ::cxx_qt::QProperties for ffi::MyObject {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[
+        ::cxx_qt::QPropertyDescriptor::new(
+            "value",
+            "value",
+            || unsafe { ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "value") },
+            |qobject, f| {
+                let value = qobject.value();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_value(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_value_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "doubled",
+            "doubled",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "doubled")
+            },
+            |qobject, f| {
+                let value = qobject.doubled();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            None,
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_doubled_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        ),
+    ];
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "valueChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_value_changed_from<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>, i32) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::MyObject_connect_value_changed_from(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosurevalue_changed_from,
+                >::new(Box::new(closure)),
+                conn_type,
+            ),
+        )
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "valueChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_value_changed_from<
+        F: FnMut(core::pin::Pin<&mut ffi::MyObject>, i32) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::MyObject>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::MyObject_connect_value_changed_from(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    MyObjectCxxQtSignalClosurevalue_changed_from,
+                >::new(Box::new(closure)),
+                cxx_qt::ConnectionType::AutoConnection,
+            ),
+        )
+    }
+}
+#[doc(hidden)]
+pub struct MyObjectCxxQtSignalClosurevalue_changed_from {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for MyObjectCxxQtSignalClosurevalue_changed_from
+{
+    type Id = cxx::type_id!(
+        "::cxx_qt::overloads::rust::cxxqtgen1::MyObjectCxxQtSignalHandlervalue_changed_from"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::MyObject>, i32) + Send;
+}
+use core::mem::drop as drop_MyObject_signal_handler_value_changed_from;
+fn call_MyObject_signal_handler_value_changed_from(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        MyObjectCxxQtSignalClosurevalue_changed_from,
+    >,
+    self_value: core::pin::Pin<&mut ffi::MyObject>,
+    previous: i32,
+) {
+    handler.closure()(self_value, previous);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurevalue_changed_from>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurevalue_changed_from>,
+    [usize; 2]
+);
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyObject {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_MyObject_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_MyObject_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_MyObjectRust() -> std::boxed::Box<MyObjectRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::MyObject {
+    type Target = MyObjectRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::MyObject {
+    type Rust = MyObjectRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_MyObject_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_MyObject_unsafeRustMut(self)
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::QCompleter {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_QCompleter_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_QCompleter_downcastPtr(base)
+    }
+}
+impl ffi::QCompleter {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "activated"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_activated_text<
+        F: FnMut(core::pin::Pin<&mut ffi::QCompleter>, &ffi::QString) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::QCompleter>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::QCompleter_connect_activated_text(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QCompleterCxxQtSignalClosureactivated_text,
+                >::new(Box::new(closure)),
+                conn_type,
+            ),
+        )
+    }
+}
+impl ffi::QCompleter {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "activated"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_activated_text<
+        F: FnMut(core::pin::Pin<&mut ffi::QCompleter>, &ffi::QString) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::QCompleter>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::QCompleter_connect_activated_text(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QCompleterCxxQtSignalClosureactivated_text,
+                >::new(Box::new(closure)),
+                cxx_qt::ConnectionType::AutoConnection,
+            ),
+        )
+    }
+}
+#[doc(hidden)]
+pub struct QCompleterCxxQtSignalClosureactivated_text {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for QCompleterCxxQtSignalClosureactivated_text
+{
+    type Id = cxx::type_id!("::rust::cxxqtgen1::QCompleterCxxQtSignalHandleractivated_text");
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::QCompleter>, &ffi::QString) + Send;
+}
+use core::mem::drop as drop_QCompleter_signal_handler_activated_text;
+fn call_QCompleter_signal_handler_activated_text(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        QCompleterCxxQtSignalClosureactivated_text,
+    >,
+    self_value: core::pin::Pin<&mut ffi::QCompleter>,
+    text: &ffi::QString,
+) {
+    handler.closure()(self_value, text);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<QCompleterCxxQtSignalClosureactivated_text>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<QCompleterCxxQtSignalClosureactivated_text>,
+    [usize; 2]
+);
+impl ffi::QCompleter {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "activated"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_activated_index<
+        F: FnMut(core::pin::Pin<&mut ffi::QCompleter>, &ffi::QModelIndex) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::QCompleter>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::QCompleter_connect_activated_index(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QCompleterCxxQtSignalClosureactivated_index,
+                >::new(Box::new(closure)),
+                conn_type,
+            ),
+        )
+    }
+}
+impl ffi::QCompleter {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "activated"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_activated_index<
+        F: FnMut(core::pin::Pin<&mut ffi::QCompleter>, &ffi::QModelIndex) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::QCompleter>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(
+            ffi::QCompleter_connect_activated_index(
+                self,
+                cxx_qt::signalhandler::CxxQtSignalHandler::<
+                    QCompleterCxxQtSignalClosureactivated_index,
+                >::new(Box::new(closure)),
+                cxx_qt::ConnectionType::AutoConnection,
+            ),
+        )
+    }
+}
+#[doc(hidden)]
+pub struct QCompleterCxxQtSignalClosureactivated_index {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure
+    for QCompleterCxxQtSignalClosureactivated_index
+{
+    type Id = cxx::type_id!("::rust::cxxqtgen1::QCompleterCxxQtSignalHandleractivated_index");
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::QCompleter>, &ffi::QModelIndex) + Send;
+}
+use core::mem::drop as drop_QCompleter_signal_handler_activated_index;
+fn call_QCompleter_signal_handler_activated_index(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        QCompleterCxxQtSignalClosureactivated_index,
+    >,
+    self_value: core::pin::Pin<&mut ffi::QCompleter>,
+    index: &ffi::QModelIndex,
+) {
+    handler.closure()(self_value, index);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<QCompleterCxxQtSignalClosureactivated_index>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<QCompleterCxxQtSignalClosureactivated_index>,
+    [usize; 2]
+);
@@ -119,6 +119,7 @@ public:
 public:
   ::std::int32_t const& getPropertyName() const noexcept;
   Q_SLOT void setPropertyName(::std::int32_t value) noexcept;
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void propertyNameChanged();
   Q_INVOKABLE void invokable_name() noexcept;
   Q_SIGNAL void ready();
@@ -170,6 +171,7 @@ public:
 public:
   ::std::int32_t const& getPropertyName() const noexcept;
   Q_SLOT void setPropertyName(::std::int32_t value) noexcept;
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void propertyNameChanged();
   Q_INVOKABLE void invokableName() noexcept;
   void myRenamedFunction(::std::int32_t param) const noexcept;
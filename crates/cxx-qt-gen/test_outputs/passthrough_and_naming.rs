@@ -130,6 +130,13 @@ pub mod ffi {
             self_value: Pin<&mut MyObject>,
         );
     }
+    unsafe extern "C++" {
+        include ! (< cxx - qt / property . h >);
+        #[doc(hidden)]
+        #[cxx_name = "propertyMetaTypeId"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_propertyMetaTypeId(thiz: *const MyObject, name: &str) -> i32;
+    }
     extern "Rust" {
         #[cxx_name = "invokable_name"]
         #[namespace = "cxx_qt::multi_object"]
@@ -251,6 +258,16 @@ pub mod ffi {
             self_value: Pin<&mut SecondObject>,
         );
     }
+    unsafe extern "C++" {
+        include ! (< cxx - qt / property . h >);
+        #[doc(hidden)]
+        #[cxx_name = "propertyMetaTypeId"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_SecondObject_propertyMetaTypeId(
+            thiz: *const SecondObject,
+            name: &str,
+        ) -> i32;
+    }
     extern "Rust" {
         #[cxx_name = "invokableName"]
         #[namespace = "second_object"]
@@ -591,6 +608,27 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurepropertyNameChanged>,
     [usize; 2]
 );
+impl ::cxx_qt::QProperties for ffi::MyObject {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] =
+        &[::cxx_qt::QPropertyDescriptor::new(
+            "propertyName",
+            "property_name",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "propertyName")
+            },
+            |qobject, f| {
+                let value = qobject.property_name();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_property_name(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_property_name_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        )];
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "ready"]
@@ -769,6 +807,27 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<SecondObjectCxxQtSignalClosurepropertyNameChanged>,
     [usize; 2]
 );
+impl ::cxx_qt::QProperties for ffi::SecondObject {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] =
+        &[::cxx_qt::QPropertyDescriptor::new(
+            "propertyName",
+            "property_name",
+            || unsafe {
+                ffi::cxx_qt_ffi_SecondObject_propertyMetaTypeId(::core::ptr::null(), "propertyName")
+            },
+            |qobject, f| {
+                let value = qobject.property_name();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_property_name(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_property_name_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        )];
+}
 impl ffi::SecondObject {
     pub fn invokable_name(self: Pin<&mut ffi::SecondObject>) {
         use cxx_qt::CxxQtType;
@@ -870,6 +929,9 @@ impl ::cxx_qt::CxxQtType for ffi::SecondObject {
         ffi::cxx_qt_ffi_SecondObject_unsafeRustMut(self)
     }
 }
+impl ::cxx_qt::QProperties for ffi::MyRustName {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
 unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyRustName {
     unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
         ffi::cxx_qt_ffi_MyCxxName_upcastPtr(this)
@@ -115,12 +115,14 @@ MyObject_my_on_changedConnect(
 } // namespace cxx_qt::my_object::rust::cxxqtgen1
 
 namespace cxx_qt::my_object {
+/// A QObject with properties
 class MyObject
   : public QObject
   , private ::rust::cxxqt1::CxxQtType<MyObjectRust>
 {
   Q_OBJECT
 public:
+  /// A primitive property
   Q_PROPERTY(::std::int32_t primitive READ getPrimitive WRITE setPrimitive
                NOTIFY primitiveChanged)
   Q_PROPERTY(
@@ -145,6 +147,8 @@ public:
                setRequiredProp REQUIRED)
   Q_PROPERTY(
     ::std::int32_t finalProp READ getFinalProp WRITE setFinalProp FINAL)
+  Q_PROPERTY(::std::int32_t autoResetProp READ getAutoResetProp WRITE
+               setAutoResetProp RESET resetAutoResetProp)
 
   virtual ~MyObject() = default;
 
@@ -171,11 +175,20 @@ public:
   Q_SLOT void setRequiredProp(::std::int32_t value) noexcept;
   ::std::int32_t const& getFinalProp() const noexcept;
   Q_SLOT void setFinalProp(::std::int32_t value) noexcept;
+  ::std::int32_t const& getAutoResetProp() const noexcept;
+  Q_SLOT void setAutoResetProp(::std::int32_t value) noexcept;
+  Q_SLOT void resetAutoResetProp() noexcept;
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void primitiveChanged();
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void trivialChanged();
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void propAutoCxxNameChanged();
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void customFunctionPropChanged();
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void renamedPropertyChanged();
+  /// Notify for the Q_PROPERTY
   Q_SIGNAL void named_prop_2Changed();
   ::std::int32_t myGetter() const noexcept;
   void MyCustomSetter(::std::int32_t value) noexcept;
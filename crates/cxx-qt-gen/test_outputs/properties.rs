@@ -147,6 +147,21 @@ mod ffi {
         #[namespace = "cxx_qt::my_object"]
         fn set_final_prop(self: Pin<&mut MyObject>, value: i32);
     }
+    extern "Rust" {
+        #[cxx_name = "getAutoResetProp"]
+        #[namespace = "cxx_qt::my_object"]
+        unsafe fn auto_reset_prop<'a>(self: &'a MyObject) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "setAutoResetProp"]
+        #[namespace = "cxx_qt::my_object"]
+        fn set_auto_reset_prop(self: Pin<&mut MyObject>, value: i32);
+    }
+    extern "Rust" {
+        #[cxx_name = "resetAutoResetProp"]
+        #[namespace = "cxx_qt::my_object"]
+        fn reset_auto_reset_prop(self: Pin<&mut MyObject>);
+    }
     unsafe extern "C++" {
         #[cxx_name = "primitiveChanged"]
         #[doc = "Notify for the Q_PROPERTY"]
@@ -349,6 +364,13 @@ mod ffi {
             self_value: Pin<&mut MyObject>,
         );
     }
+    unsafe extern "C++" {
+        include ! (< cxx - qt / property . h >);
+        #[doc(hidden)]
+        #[cxx_name = "propertyMetaTypeId"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_MyObject_propertyMetaTypeId(thiz: *const MyObject, name: &str) -> i32;
+    }
     extern "Rust" {
         #[cxx_name = "myGetter"]
         #[namespace = "cxx_qt::my_object"]
@@ -633,6 +655,36 @@ impl ffi::MyObject {
         self.as_mut().rust_mut().final_prop = value;
     }
 }
+impl ffi::MyObject {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "auto_reset_prop"]
+    pub fn auto_reset_prop(&self) -> &i32 {
+        &self.auto_reset_prop
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "auto_reset_prop"]
+    pub fn set_auto_reset_prop(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.auto_reset_prop == value {
+            return;
+        }
+        self.as_mut().rust_mut().auto_reset_prop = value;
+    }
+}
+impl ffi::MyObject {
+    #[doc = "Reset for the Q_PROPERTY "]
+    #[doc = "auto_reset_prop"]
+    pub fn reset_auto_reset_prop(mut self: core::pin::Pin<&mut Self>) {
+        use cxx_qt::CxxQtType;
+        let value: i32 = <MyObjectRust as Default>::default().auto_reset_prop;
+        if self.auto_reset_prop == value {
+            return;
+        }
+        self.as_mut().rust_mut().auto_reset_prop = value;
+    }
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "primitiveChanged"]
@@ -1043,6 +1095,255 @@ cxx_qt::static_assertions::assert_eq_size!(
     cxx_qt::signalhandler::CxxQtSignalHandler<MyObjectCxxQtSignalClosurenamed_prop_2Changed>,
     [usize; 2]
 );
+impl ::cxx_qt::QProperties for ffi::MyObject {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[
+        ::cxx_qt::QPropertyDescriptor::new(
+            "primitive",
+            "primitive",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "primitive")
+            },
+            |qobject, f| {
+                let value = qobject.primitive();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_primitive(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_primitive_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "trivial",
+            "trivial",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "trivial")
+            },
+            |qobject, f| {
+                let value = qobject.trivial();
+                f(::core::borrow::Borrow::<ffi::QPoint>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_trivial(*value.downcast::<ffi::QPoint>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_trivial_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "propAutoCxxName",
+            "prop_auto_cxx_name",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "propAutoCxxName")
+            },
+            |qobject, f| {
+                let value = qobject.prop_auto_cxx_name();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_prop_auto_cxx_name(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject
+                    .connect_prop_auto_cxx_name_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "customFunctionProp",
+            "custom_function_prop",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(
+                    ::core::ptr::null(),
+                    "customFunctionProp",
+                )
+            },
+            |qobject, f| {
+                let value = qobject.my_getter();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.my_setter(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_custom_function_prop_changed(
+                    move |qobject| closure(qobject),
+                    conn_type,
+                )
+            }),
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "readonlyProp",
+            "readonly_prop",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "readonlyProp")
+            },
+            |qobject, f| {
+                let value = qobject.readonly_prop();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            None,
+            None,
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "renamedProperty",
+            "renamed_property",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "renamedProperty")
+            },
+            |qobject, f| {
+                let value = qobject.renamed_property();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_renamed_property(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_renamed_property_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "reusedSignalProp",
+            "reused_signal_prop",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "reusedSignalProp")
+            },
+            |qobject, f| {
+                let value = qobject.reused_signal_prop();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_reused_signal_prop(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_trivial_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "named_prop_2",
+            "renamed_property_2",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "named_prop_2")
+            },
+            |qobject, f| {
+                let value = qobject.renamed_property_2();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_renamed_property_2(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject
+                    .connect_renamed_property_2_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "customOnChangedProp",
+            "custom_on_changed_prop",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(
+                    ::core::ptr::null(),
+                    "customOnChangedProp",
+                )
+            },
+            |qobject, f| {
+                let value = qobject.custom_on_changed_prop();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_custom_on_changed_prop(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_my_on_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "constProp",
+            "const_prop",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "constProp")
+            },
+            |qobject, f| {
+                let value = qobject.const_prop();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            None,
+            None,
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "resettableProp",
+            "resettable_prop",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "resettableProp")
+            },
+            |qobject, f| {
+                let value = qobject.resettable_prop();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_resettable_prop(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            None,
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "requiredProp",
+            "required_prop",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "requiredProp")
+            },
+            |qobject, f| {
+                let value = qobject.required_prop();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_required_prop(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            None,
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "finalProp",
+            "final_prop",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "finalProp")
+            },
+            |qobject, f| {
+                let value = qobject.final_prop();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_final_prop(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            None,
+        ),
+        ::cxx_qt::QPropertyDescriptor::new(
+            "autoResetProp",
+            "auto_reset_prop",
+            || unsafe {
+                ffi::cxx_qt_ffi_MyObject_propertyMetaTypeId(::core::ptr::null(), "autoResetProp")
+            },
+            |qobject, f| {
+                let value = qobject.auto_reset_prop();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_auto_reset_prop(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            None,
+        ),
+    ];
+}
 impl ffi::MyObject {
     #[doc = "Connect the given function pointer to the signal "]
     #[doc = "my_on_changed"]
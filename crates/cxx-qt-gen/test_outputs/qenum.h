@@ -18,7 +18,6 @@ class CxxName;
 
 namespace cxx_qt::my_object {
 Q_NAMESPACE
-QML_ELEMENT
 } // namespace cxx_qt::my_object
 
 namespace other_namespace {
@@ -48,6 +47,29 @@ Q_ENUM_NS(MyOtherNamespacedEnum)
 
 #include "directory/file_ident.cxx.h"
 
+namespace cxx_qt::my_object {
+class QmlFunctionsDirectoryFileIdent : public QObject
+{
+  Q_OBJECT
+  QML_NAMED_ELEMENT(my_object)
+  QML_SINGLETON
+  QML_EXTENDED_NAMESPACE(cxx_qt::my_object)
+
+public:
+  explicit QmlFunctionsDirectoryFileIdent(QObject* parent = nullptr)
+    : QObject(parent)
+  {
+  }
+
+  Q_INVOKABLE static cxx_qt::my_object::MyNamespacedEnum next_namespaced_enum(
+    cxx_qt::my_object::MyNamespacedEnum value) noexcept
+  {
+    return ::cxx_qt::my_object::next_namespaced_enum(value);
+  }
+};
+
+} // namespace cxx_qt::my_object
+
 namespace cxx_qt::my_object {
 class MyObject
   : public QObject
@@ -111,6 +111,18 @@ mod ffi {
             outer: Pin<&mut MyRenamedObject>,
         ) -> Pin<&mut InternalObject>;
     }
+    extern "Rust" {
+        #[cxx_name = "next_namespaced_enum"]
+        #[namespace = "cxx_qt::my_object"]
+        #[doc(hidden)]
+        fn next_namespaced_enum(value: MyNamespacedEnum) -> MyNamespacedEnum;
+    }
+    extern "Rust" {
+        #[cxx_name = "is_first_variant"]
+        #[namespace = "other_namespace"]
+        #[doc(hidden)]
+        fn is_first_variant(value: MyOtherNamespacedEnum) -> bool;
+    }
     extern "C++" {
         #[doc(hidden)]
         #[namespace = ""]
@@ -150,6 +162,9 @@ mod ffi {
         type MyRenamedEnum = super::cxx_qt_private_qenum_MyRenamedEnum::MyRenamedEnum;
     }
 }
+impl ::cxx_qt::QProperties for ffi::MyObject {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
 unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyObject {
     unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
         ffi::cxx_qt_ffi_MyObject_upcastPtr(this)
@@ -178,6 +193,9 @@ impl ::cxx_qt::CxxQtType for ffi::MyObject {
         ffi::cxx_qt_ffi_MyObject_unsafeRustMut(self)
     }
 }
+impl ::cxx_qt::QProperties for ffi::MyRenamedObject {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
 unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::MyRenamedObject {
     unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
         ffi::cxx_qt_ffi_CxxName_upcastPtr(this)
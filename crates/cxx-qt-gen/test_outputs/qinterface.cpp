@@ -0,0 +1,70 @@
+#include "directory/file_ident.cxxqt.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<::cxx_qt::devices::rust::cxxqtgen1::
+                DeviceCxxQtSignalParamsstateChanged*>::~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_Device_signal_handler_stateChanged(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<
+  ::cxx_qt::devices::rust::cxxqtgen1::DeviceCxxQtSignalParamsstateChanged*>::
+operator()<cxx_qt::devices::Device&, bool>(cxx_qt::devices::Device& self,
+                                           bool connected)
+{
+  call_Device_signal_handler_stateChanged(*this, self, ::std::move(connected));
+}
+
+static_assert(
+  alignof(
+    SignalHandler<
+      ::cxx_qt::devices::rust::cxxqtgen1::DeviceCxxQtSignalParamsstateChanged*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(
+    SignalHandler<
+      ::cxx_qt::devices::rust::cxxqtgen1::DeviceCxxQtSignalParamsstateChanged*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::devices::rust::cxxqtgen1 {
+::QMetaObject::Connection
+Device_stateChangedConnect(
+  cxx_qt::devices::Device& self,
+  ::cxx_qt::devices::rust::cxxqtgen1::DeviceCxxQtSignalHandlerstateChanged
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::devices::Device::stateChanged,
+    &self,
+    [&, closure = ::std::move(closure)](bool connected) mutable {
+      closure.template operator()<cxx_qt::devices::Device&, bool>(
+        self, ::std::move(connected));
+    },
+    type);
+}
+} // namespace cxx_qt::devices::rust::cxxqtgen1
+
+namespace cxx_qt::devices {
+Printer::Printer(QObject* parent)
+  : cxx_qt::devices::Device(parent)
+  , ::rust::cxxqt1::CxxQtType<PrinterRust>(
+      ::cxx_qt::devices::cxx_qt_Printer::createRs())
+{
+}
+
+} // namespace cxx_qt::devices
@@ -0,0 +1,79 @@
+#pragma once
+
+#include <cxx-qt/casting.h>
+#include <cxx-qt/signalhandler.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::devices {
+class Printer;
+
+} // namespace cxx_qt::devices
+
+namespace cxx_qt::devices {
+class Device;
+} // namespace cxx_qt::devices
+
+namespace cxx_qt::devices::rust::cxxqtgen1 {
+using DeviceCxxQtSignalHandlerstateChanged =
+  ::rust::cxxqt1::SignalHandler<struct DeviceCxxQtSignalParamsstateChanged*>;
+} // namespace cxx_qt::devices::rust::cxxqtgen1
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::devices {
+class Device : public QObject
+{
+  Q_OBJECT
+public:
+  explicit Device(QObject* parent = nullptr)
+    : QObject(parent)
+  {
+  }
+  virtual ~Device() = default;
+
+  /// Connect to the device
+  Q_INVOKABLE virtual bool connect(QString const& address) noexcept = 0;
+  Q_INVOKABLE virtual bool isConnected() const noexcept = 0;
+  Q_SIGNAL void stateChanged(bool connected);
+};
+
+} // namespace cxx_qt::devices
+
+Q_DECLARE_METATYPE(cxx_qt::devices::Device*)
+
+namespace cxx_qt::devices::rust::cxxqtgen1 {
+::QMetaObject::Connection
+Device_stateChangedConnect(
+  cxx_qt::devices::Device& self,
+  ::cxx_qt::devices::rust::cxxqtgen1::DeviceCxxQtSignalHandlerstateChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::devices::rust::cxxqtgen1
+
+namespace cxx_qt::devices {
+class Printer
+  : public cxx_qt::devices::Device
+  , private ::rust::cxxqt1::CxxQtType<PrinterRust>
+{
+  Q_OBJECT
+public:
+  virtual ~Printer() = default;
+
+public:
+  Q_INVOKABLE bool connect(QString const& address) noexcept override;
+  Q_INVOKABLE bool isConnected() const noexcept override;
+  explicit Printer(QObject* parent = nullptr);
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, Printer>::value,
+              "Printer must inherit from QObject");
+} // namespace cxx_qt::devices
+
+Q_DECLARE_METATYPE(cxx_qt::devices::Printer*)
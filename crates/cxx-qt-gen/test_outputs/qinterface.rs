@@ -0,0 +1,260 @@
+#[cxx::bridge(namespace = "cxx_qt::devices")]
+#[allow(unused_unsafe)]
+mod ffi {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "PrinterRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::devices"]
+        type Printer;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::devices"]
+        type PrinterRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "connect"]
+        #[namespace = "cxx_qt::devices"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_Device_connect(self: Pin<&mut Printer>, address: &QString) -> bool;
+    }
+    extern "Rust" {
+        #[cxx_name = "isConnected"]
+        #[namespace = "cxx_qt::devices"]
+        #[doc(hidden)]
+        fn cxx_qt_ffi_Device_is_connected(self: &Printer) -> bool;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_Printer_upcastPtr(thiz: *const Printer) -> *const Device;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_Printer_downcastPtr(base: *const Device) -> *const Printer;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::devices::cxx_qt_Printer"]
+        fn create_rs_PrinterRust() -> Box<PrinterRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_Printer_unsafeRust(outer: &Printer) -> &PrinterRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_Printer_unsafeRustMut(outer: Pin<&mut Printer>) -> Pin<&mut PrinterRust>;
+    }
+    unsafe extern "C++" {
+        #[namespace = "cxx_qt::devices"]
+        #[doc = " A device which can be connected to"]
+        type Device;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_Device_upcastPtr(thiz: *const Device) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_Device_downcastPtr(base: *const QObject) -> *const Device;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "connect"]
+        #[namespace = "cxx_qt::devices"]
+        #[doc(hidden)]
+        fn connect(self: Pin<&mut Device>, address: &QString) -> bool;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "isConnected"]
+        #[namespace = "cxx_qt::devices"]
+        #[doc(hidden)]
+        fn is_connected(self: &Device) -> bool;
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "stateChanged"]
+        #[namespace = "cxx_qt::devices"]
+        fn state_changed(self: Pin<&mut Device>, connected: bool);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::devices::rust::cxxqtgen1"]
+        type DeviceCxxQtSignalHandlerstateChanged =
+            cxx_qt::signalhandler::CxxQtSignalHandler<super::DeviceCxxQtSignalClosurestateChanged>;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::devices::rust::cxxqtgen1"]
+        #[cxx_name = "Device_stateChangedConnect"]
+        fn Device_connect_state_changed(
+            self_value: Pin<&mut Device>,
+            signal_handler: DeviceCxxQtSignalHandlerstateChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::devices::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_Device_signal_handler_stateChanged(handler: DeviceCxxQtSignalHandlerstateChanged);
+        #[doc(hidden)]
+        fn call_Device_signal_handler_stateChanged(
+            handler: &mut DeviceCxxQtSignalHandlerstateChanged,
+            self_value: Pin<&mut Device>,
+            connected: bool,
+        );
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QObject = cxx_qt::QObject;
+    }
+}
+impl ::cxx_qt::QProperties for ffi::Printer {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] = &[];
+}
+impl ffi::Printer {
+    #[doc(hidden)]
+    fn cxx_qt_ffi_Device_connect(self: core::pin::Pin<&mut Self>, address: &ffi::QString) -> bool {
+        <Self as Device>::connect(self, address)
+    }
+    #[doc(hidden)]
+    fn cxx_qt_ffi_Device_is_connected(self: &Self) -> bool {
+        <Self as Device>::is_connected(self)
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<ffi::Device> for ffi::Printer {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ffi::Device {
+        ffi::cxx_qt_ffi_Printer_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ffi::Device) -> *const Self {
+        ffi::cxx_qt_ffi_Printer_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_PrinterRust() -> std::boxed::Box<PrinterRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::Printer {
+    type Target = PrinterRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_Printer_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::Printer {
+    type Rust = PrinterRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_Printer_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_Printer_unsafeRustMut(self)
+    }
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::Device {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_Device_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_Device_downcastPtr(base)
+    }
+}
+impl ffi::Device {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "stateChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_state_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::Device>, bool) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::Device>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::Device_connect_state_changed(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<DeviceCxxQtSignalClosurestateChanged>::new(
+                Box::new(closure),
+            ),
+            conn_type,
+        ))
+    }
+}
+impl ffi::Device {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "stateChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_state_changed<F: FnMut(core::pin::Pin<&mut ffi::Device>, bool) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::Device>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt::QMetaObjectConnectionGuard::from(ffi::Device_connect_state_changed(
+            self,
+            cxx_qt::signalhandler::CxxQtSignalHandler::<DeviceCxxQtSignalClosurestateChanged>::new(
+                Box::new(closure),
+            ),
+            cxx_qt::ConnectionType::AutoConnection,
+        ))
+    }
+}
+#[doc(hidden)]
+pub struct DeviceCxxQtSignalClosurestateChanged {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for DeviceCxxQtSignalClosurestateChanged {
+    type Id =
+        cxx::type_id!("::cxx_qt::devices::rust::cxxqtgen1::DeviceCxxQtSignalHandlerstateChanged");
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::Device>, bool) + Send;
+}
+use core::mem::drop as drop_Device_signal_handler_stateChanged;
+fn call_Device_signal_handler_stateChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<DeviceCxxQtSignalClosurestateChanged>,
+    self_value: core::pin::Pin<&mut ffi::Device>,
+    connected: bool,
+) {
+    handler.closure()(self_value, connected);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<DeviceCxxQtSignalClosurestateChanged>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<DeviceCxxQtSignalClosurestateChanged>,
+    [usize; 2]
+);
+#[doc = " A device which can be connected to"]
+trait Device: ::cxx_qt::casting::Upcast<ffi::Device> {
+    #[doc = " Connect to the device"]
+    fn connect(self: core::pin::Pin<&mut Self>, address: &ffi::QString) -> bool;
+    fn is_connected(self: &Self) -> bool;
+}
@@ -0,0 +1,146 @@
+#include "directory/file_ident.cxxqt.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<
+  ::cxx_qt::templates::rust::cxxqtgen1::
+    IntRangeCxxQtSignalParamsvalueChanged*>::~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_IntRange_signal_handler_valueChanged(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::templates::rust::cxxqtgen1::
+                IntRangeCxxQtSignalParamsvalueChanged*>::
+operator()<cxx_qt::templates::IntRange&>(cxx_qt::templates::IntRange& self)
+{
+  call_IntRange_signal_handler_valueChanged(*this, self);
+}
+
+static_assert(
+  alignof(SignalHandler<::cxx_qt::templates::rust::cxxqtgen1::
+                          IntRangeCxxQtSignalParamsvalueChanged*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::cxx_qt::templates::rust::cxxqtgen1::
+                         IntRangeCxxQtSignalParamsvalueChanged*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::templates::rust::cxxqtgen1 {
+::QMetaObject::Connection
+IntRange_valueChangedConnect(
+  cxx_qt::templates::IntRange& self,
+  ::cxx_qt::templates::rust::cxxqtgen1::IntRangeCxxQtSignalHandlervalueChanged
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::templates::IntRange::valueChanged,
+    &self,
+    [&, closure = ::std::move(closure)]() mutable {
+      closure.template operator()<cxx_qt::templates::IntRange&>(self);
+    },
+    type);
+}
+} // namespace cxx_qt::templates::rust::cxxqtgen1
+
+namespace cxx_qt::templates {
+static_assert(alignof(IntRangeCxxQtThread) <= alignof(::std::size_t),
+              "unexpected aligment");
+static_assert(sizeof(IntRangeCxxQtThread) == sizeof(::std::size_t[2]),
+              "unexpected size");
+
+IntRange::IntRange(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<IntRangeRust>(
+      ::cxx_qt::templates::cxx_qt_IntRange::createRs())
+  , ::rust::cxxqt1::CxxQtThreading<IntRange>(this)
+{
+}
+
+} // namespace cxx_qt::templates
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust::cxxqt1 {
+template<>
+SignalHandler<
+  ::cxx_qt::templates::rust::cxxqtgen1::
+    FloatRangeCxxQtSignalParamsvalueChanged*>::~SignalHandler() noexcept
+{
+  if (data[0] == nullptr && data[1] == nullptr) {
+    return;
+  }
+
+  drop_FloatRange_signal_handler_valueChanged(::std::move(*this));
+}
+
+template<>
+template<>
+void
+SignalHandler<::cxx_qt::templates::rust::cxxqtgen1::
+                FloatRangeCxxQtSignalParamsvalueChanged*>::
+operator()<cxx_qt::templates::FloatRangeModel&>(
+  cxx_qt::templates::FloatRangeModel& self)
+{
+  call_FloatRange_signal_handler_valueChanged(*this, self);
+}
+
+static_assert(
+  alignof(SignalHandler<::cxx_qt::templates::rust::cxxqtgen1::
+                          FloatRangeCxxQtSignalParamsvalueChanged*>) <=
+    alignof(::std::size_t),
+  "unexpected aligment");
+static_assert(
+  sizeof(SignalHandler<::cxx_qt::templates::rust::cxxqtgen1::
+                         FloatRangeCxxQtSignalParamsvalueChanged*>) ==
+    sizeof(::std::size_t[2]),
+  "unexpected size");
+} // namespace rust::cxxqt1
+
+namespace cxx_qt::templates::rust::cxxqtgen1 {
+::QMetaObject::Connection
+FloatRangeModel_valueChangedConnect(
+  cxx_qt::templates::FloatRangeModel& self,
+  ::cxx_qt::templates::rust::cxxqtgen1::FloatRangeCxxQtSignalHandlervalueChanged
+    closure,
+  ::Qt::ConnectionType type)
+{
+  return ::QObject::connect(
+    &self,
+    &cxx_qt::templates::FloatRangeModel::valueChanged,
+    &self,
+    [&, closure = ::std::move(closure)]() mutable {
+      closure.template operator()<cxx_qt::templates::FloatRangeModel&>(self);
+    },
+    type);
+}
+} // namespace cxx_qt::templates::rust::cxxqtgen1
+
+namespace cxx_qt::templates {
+static_assert(alignof(FloatRangeCxxQtThread) <= alignof(::std::size_t),
+              "unexpected aligment");
+static_assert(sizeof(FloatRangeCxxQtThread) == sizeof(::std::size_t[2]),
+              "unexpected size");
+
+FloatRangeModel::FloatRangeModel(QObject* parent)
+  : QObject(parent)
+  , ::rust::cxxqt1::CxxQtType<FloatRangeRust>(
+      ::cxx_qt::templates::cxx_qt_FloatRange::createRs())
+  , ::rust::cxxqt1::CxxQtThreading<FloatRangeModel>(this)
+{
+}
+
+} // namespace cxx_qt::templates
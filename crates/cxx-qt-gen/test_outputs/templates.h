@@ -0,0 +1,128 @@
+#pragma once
+
+#include <cxx-qt/casting.h>
+#include <cxx-qt/signalhandler.h>
+#include <cxx-qt/threading.h>
+#include <cxx-qt/type.h>
+
+namespace cxx_qt::templates {
+class IntRange;
+using IntRangeCxxQtThread = ::rust::cxxqt1::CxxQtThread<IntRange>;
+
+} // namespace cxx_qt::templates
+
+namespace cxx_qt::templates::rust::cxxqtgen1 {
+using IntRangeCxxQtSignalHandlervalueChanged = ::rust::cxxqt1::SignalHandler<
+  struct IntRangeCxxQtSignalParamsvalueChanged*>;
+} // namespace cxx_qt::templates::rust::cxxqtgen1
+
+namespace cxx_qt::templates {
+class FloatRangeModel;
+using FloatRangeCxxQtThread = ::rust::cxxqt1::CxxQtThread<FloatRangeModel>;
+
+} // namespace cxx_qt::templates
+
+namespace cxx_qt::templates::rust::cxxqtgen1 {
+using FloatRangeCxxQtSignalHandlervalueChanged = ::rust::cxxqt1::SignalHandler<
+  struct FloatRangeCxxQtSignalParamsvalueChanged*>;
+} // namespace cxx_qt::templates::rust::cxxqtgen1
+
+#include "directory/file_ident.cxx.h"
+
+namespace cxx_qt::templates::rust::cxxqtgen1 {
+::QMetaObject::Connection
+IntRange_valueChangedConnect(
+  cxx_qt::templates::IntRange& self,
+  ::cxx_qt::templates::rust::cxxqtgen1::IntRangeCxxQtSignalHandlervalueChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::templates::rust::cxxqtgen1
+
+namespace cxx_qt::templates {
+class IntRange
+  : public QObject
+  , private ::rust::cxxqt1::CxxQtType<IntRangeRust>
+  , private ::rust::cxxqt1::CxxQtThreading<IntRange>
+{
+  Q_OBJECT
+public:
+  Q_PROPERTY(
+    ::std::int32_t value READ getValue WRITE setValue NOTIFY valueChanged)
+
+  virtual ~IntRange() = default;
+
+public:
+  ::std::int32_t const& getValue() const noexcept;
+  Q_SLOT void setValue(::std::int32_t value) noexcept;
+  /// Notify for the Q_PROPERTY
+  Q_SIGNAL void valueChanged();
+  Q_INVOKABLE ::std::int32_t clamp(::std::int32_t value) const noexcept;
+  explicit IntRange(QObject* parent = nullptr);
+
+private:
+  template<typename T>
+  friend auto ::rust::cxxqt1::qtThread(const T& qobject)
+    -> ::rust::cxxqt1::CxxQtThread<T>;
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, IntRange>::value,
+              "IntRange must inherit from QObject");
+} // namespace cxx_qt::templates
+
+Q_DECLARE_METATYPE(cxx_qt::templates::IntRange*)
+
+namespace cxx_qt::templates::rust::cxxqtgen1 {
+::QMetaObject::Connection
+FloatRangeModel_valueChangedConnect(
+  cxx_qt::templates::FloatRangeModel& self,
+  ::cxx_qt::templates::rust::cxxqtgen1::FloatRangeCxxQtSignalHandlervalueChanged
+    closure,
+  ::Qt::ConnectionType type);
+} // namespace cxx_qt::templates::rust::cxxqtgen1
+
+namespace cxx_qt::templates {
+class FloatRangeModel
+  : public QObject
+  , private ::rust::cxxqt1::CxxQtType<FloatRangeRust>
+  , private ::rust::cxxqt1::CxxQtThreading<FloatRangeModel>
+{
+  Q_OBJECT
+public:
+  QML_NAMED_ELEMENT(FloatRangeModel)
+  Q_PROPERTY(double value READ getValue WRITE setValue NOTIFY valueChanged)
+
+  virtual ~FloatRangeModel() = default;
+
+public:
+  double const& getValue() const noexcept;
+  Q_SLOT void setValue(double value) noexcept;
+  /// Notify for the Q_PROPERTY
+  Q_SIGNAL void valueChanged();
+  Q_INVOKABLE double clamp(double value) const noexcept;
+  explicit FloatRangeModel(QObject* parent = nullptr);
+
+private:
+  template<typename T>
+  friend auto ::rust::cxxqt1::qtThread(const T& qobject)
+    -> ::rust::cxxqt1::CxxQtThread<T>;
+
+private:
+  template<typename Inner, typename Outer>
+  friend Inner& ::rust::cxxqt1::unsafeRustMut(Outer& outer);
+
+  template<typename Inner, typename Outer>
+  friend const Inner& ::rust::cxxqt1::unsafeRust(const Outer& outer);
+};
+
+static_assert(::std::is_base_of<QObject, FloatRangeModel>::value,
+              "FloatRangeModel must inherit from QObject");
+} // namespace cxx_qt::templates
+
+Q_DECLARE_METATYPE(cxx_qt::templates::FloatRangeModel*)
@@ -0,0 +1,666 @@
+#[cxx::bridge(namespace = "cxx_qt::templates")]
+#[allow(unused_unsafe)]
+mod ffi {
+    unsafe extern "C++" {
+        include ! (< QtCore / QObject >);
+        include!("cxx-qt/connection.h");
+        #[doc(hidden)]
+        #[namespace = "Qt"]
+        #[rust_name = "CxxQtConnectionType"]
+        #[allow(dead_code)]
+        type ConnectionType = cxx_qt::ConnectionType;
+        #[doc(hidden)]
+        #[namespace = "rust::cxxqt1"]
+        #[rust_name = "CxxQtQMetaObjectConnection"]
+        #[allow(dead_code)]
+        type QMetaObjectConnection = cxx_qt::QMetaObjectConnection;
+    }
+    unsafe extern "C++" {
+        include!("directory/file_ident.cxxqt.h");
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "IntRangeRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::templates"]
+        type IntRange;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::templates"]
+        type IntRangeRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "getValue"]
+        #[namespace = "cxx_qt::templates"]
+        unsafe fn value<'a>(self: &'a IntRange) -> &'a i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "setValue"]
+        #[namespace = "cxx_qt::templates"]
+        fn set_value(self: Pin<&mut IntRange>, value: i32);
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "valueChanged"]
+        #[doc = "Notify for the Q_PROPERTY"]
+        #[namespace = "cxx_qt::templates"]
+        fn value_changed(self: Pin<&mut IntRange>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::templates::rust::cxxqtgen1"]
+        type IntRangeCxxQtSignalHandlervalueChanged = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::IntRangeCxxQtSignalClosurevalueChanged,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::templates::rust::cxxqtgen1"]
+        #[cxx_name = "IntRange_valueChangedConnect"]
+        fn IntRange_connect_value_changed(
+            self_value: Pin<&mut IntRange>,
+            signal_handler: IntRangeCxxQtSignalHandlervalueChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::templates::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_IntRange_signal_handler_valueChanged(
+            handler: IntRangeCxxQtSignalHandlervalueChanged,
+        );
+        #[doc(hidden)]
+        fn call_IntRange_signal_handler_valueChanged(
+            handler: &mut IntRangeCxxQtSignalHandlervalueChanged,
+            self_value: Pin<&mut IntRange>,
+        );
+    }
+    unsafe extern "C++" {
+        include ! (< cxx - qt / property . h >);
+        #[doc(hidden)]
+        #[cxx_name = "propertyMetaTypeId"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_IntRange_propertyMetaTypeId(thiz: *const IntRange, name: &str) -> i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "clamp"]
+        #[namespace = "cxx_qt::templates"]
+        #[doc(hidden)]
+        fn clamp(self: &IntRange, value: i32) -> i32;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::templates"]
+        type IntRangeCxxQtThread = cxx_qt::CxxQtThread<IntRange>;
+        include!("cxx-qt/thread.h");
+        #[doc(hidden)]
+        #[cxx_name = "qtThread"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_IntRange_qtThread(qobject: &IntRange) -> IntRangeCxxQtThread;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadQueue"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_IntRange_cxxQtThreadQueue(
+            cxx_qt_thread: &IntRangeCxxQtThread,
+            func: fn(Pin<&mut IntRange>, Box<IntRangeCxxQtThreadQueuedFn>),
+            arg: Box<IntRangeCxxQtThreadQueuedFn>,
+        ) -> u8;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadClone"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_IntRange_cxxQtThreadClone(
+            cxx_qt_thread: &IntRangeCxxQtThread,
+        ) -> IntRangeCxxQtThread;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadDrop"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_IntRange_cxxQtThreadDrop(cxx_qt_thread: Pin<&mut IntRangeCxxQtThread>);
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadIsDestroyed"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_IntRange_cxxQtThreadIsDestroyed(cxx_qt_thread: &IntRangeCxxQtThread) -> bool;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::templates::cxx_qt_IntRange"]
+        type IntRangeCxxQtThreadQueuedFn;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_IntRange_upcastPtr(thiz: *const IntRange) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_IntRange_downcastPtr(base: *const QObject) -> *const IntRange;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::templates::cxx_qt_IntRange"]
+        fn create_rs_IntRangeRust() -> Box<IntRangeRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_IntRange_unsafeRust(outer: &IntRange) -> &IntRangeRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_IntRange_unsafeRustMut(outer: Pin<&mut IntRange>) -> Pin<&mut IntRangeRust>;
+    }
+    unsafe extern "C++" {
+        #[doc = "The C++ type for the QObject "]
+        #[doc = "FloatRangeRust"]
+        #[doc = "\n"]
+        #[doc = "Use this type when referring to the QObject as a pointer"]
+        #[doc = "\n"]
+        #[doc = "See the book for more information: <https://kdab.github.io/cxx-qt/book/concepts/generated_qobject.html>"]
+        #[namespace = "cxx_qt::templates"]
+        #[doc = "\n\nNote: The C++ name of this QObject is: "]
+        #[doc = "FloatRangeModel"]
+        #[cxx_name = "FloatRangeModel"]
+        type FloatRange;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::templates"]
+        type FloatRangeRust;
+    }
+    extern "Rust" {
+        #[cxx_name = "getValue"]
+        #[namespace = "cxx_qt::templates"]
+        unsafe fn value<'a>(self: &'a FloatRange) -> &'a f64;
+    }
+    extern "Rust" {
+        #[cxx_name = "setValue"]
+        #[namespace = "cxx_qt::templates"]
+        fn set_value(self: Pin<&mut FloatRange>, value: f64);
+    }
+    unsafe extern "C++" {
+        #[cxx_name = "valueChanged"]
+        #[doc = "Notify for the Q_PROPERTY"]
+        #[namespace = "cxx_qt::templates"]
+        fn value_changed(self: Pin<&mut FloatRange>);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::templates::rust::cxxqtgen1"]
+        type FloatRangeCxxQtSignalHandlervalueChanged = cxx_qt::signalhandler::CxxQtSignalHandler<
+            super::FloatRangeCxxQtSignalClosurevalueChanged,
+        >;
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::templates::rust::cxxqtgen1"]
+        #[cxx_name = "FloatRangeModel_valueChangedConnect"]
+        fn FloatRange_connect_value_changed(
+            self_value: Pin<&mut FloatRange>,
+            signal_handler: FloatRangeCxxQtSignalHandlervalueChanged,
+            conn_type: CxxQtConnectionType,
+        ) -> CxxQtQMetaObjectConnection;
+    }
+    #[namespace = "cxx_qt::templates::rust::cxxqtgen1"]
+    extern "Rust" {
+        #[doc(hidden)]
+        fn drop_FloatRange_signal_handler_valueChanged(
+            handler: FloatRangeCxxQtSignalHandlervalueChanged,
+        );
+        #[doc(hidden)]
+        fn call_FloatRange_signal_handler_valueChanged(
+            handler: &mut FloatRangeCxxQtSignalHandlervalueChanged,
+            self_value: Pin<&mut FloatRange>,
+        );
+    }
+    unsafe extern "C++" {
+        include ! (< cxx - qt / property . h >);
+        #[doc(hidden)]
+        #[cxx_name = "propertyMetaTypeId"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_FloatRangeModel_propertyMetaTypeId(
+            thiz: *const FloatRange,
+            name: &str,
+        ) -> i32;
+    }
+    extern "Rust" {
+        #[cxx_name = "clamp"]
+        #[namespace = "cxx_qt::templates"]
+        #[doc(hidden)]
+        fn clamp(self: &FloatRange, value: f64) -> f64;
+    }
+    unsafe extern "C++" {
+        include ! (< QtQml / QQmlEngine >);
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[namespace = "cxx_qt::templates"]
+        type FloatRangeCxxQtThread = cxx_qt::CxxQtThread<FloatRange>;
+        include!("cxx-qt/thread.h");
+        #[doc(hidden)]
+        #[cxx_name = "qtThread"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_FloatRangeModel_qtThread(qobject: &FloatRange) -> FloatRangeCxxQtThread;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadQueue"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_FloatRangeModel_cxxQtThreadQueue(
+            cxx_qt_thread: &FloatRangeCxxQtThread,
+            func: fn(Pin<&mut FloatRange>, Box<FloatRangeCxxQtThreadQueuedFn>),
+            arg: Box<FloatRangeCxxQtThreadQueuedFn>,
+        ) -> u8;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadClone"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_FloatRangeModel_cxxQtThreadClone(
+            cxx_qt_thread: &FloatRangeCxxQtThread,
+        ) -> FloatRangeCxxQtThread;
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadDrop"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_FloatRangeModel_cxxQtThreadDrop(
+            cxx_qt_thread: Pin<&mut FloatRangeCxxQtThread>,
+        );
+        #[doc(hidden)]
+        #[cxx_name = "cxxQtThreadIsDestroyed"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_FloatRangeModel_cxxQtThreadIsDestroyed(
+            cxx_qt_thread: &FloatRangeCxxQtThread,
+        ) -> bool;
+    }
+    extern "Rust" {
+        #[namespace = "cxx_qt::templates::cxx_qt_FloatRange"]
+        type FloatRangeCxxQtThreadQueuedFn;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "upcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_FloatRangeModel_upcastPtr(thiz: *const FloatRange) -> *const QObject;
+        #[doc(hidden)]
+        #[cxx_name = "downcastPtr"]
+        #[namespace = "rust::cxxqt1"]
+        unsafe fn cxx_qt_ffi_FloatRangeModel_downcastPtr(base: *const QObject)
+            -> *const FloatRange;
+    }
+    extern "Rust" {
+        #[cxx_name = "createRs"]
+        #[namespace = "cxx_qt::templates::cxx_qt_FloatRange"]
+        fn create_rs_FloatRangeRust() -> Box<FloatRangeRust>;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRust"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_FloatRangeModel_unsafeRust(outer: &FloatRange) -> &FloatRangeRust;
+    }
+    unsafe extern "C++" {
+        #[doc(hidden)]
+        #[cxx_name = "unsafeRustMut"]
+        #[namespace = "rust::cxxqt1"]
+        fn cxx_qt_ffi_FloatRangeModel_unsafeRustMut(
+            outer: Pin<&mut FloatRange>,
+        ) -> Pin<&mut FloatRangeRust>;
+    }
+    extern "C++" {
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QObject = cxx_qt::QObject;
+    }
+}
+type IntRangeRust = RangeModelRust<i32>;
+type FloatRangeRust = RangeModelRust<f64>;
+#[doc = "The methods of the QObject template, which are shared by all instantiations"]
+trait RangeModel<T> {
+    fn clamp(self: &Self, value: T) -> T;
+}
+impl ffi::IntRange {
+    fn clamp(self: &Self, value: i32) -> i32 {
+        <Self as RangeModel<i32>>::clamp(self, value)
+    }
+}
+impl ffi::FloatRange {
+    fn clamp(self: &Self, value: f64) -> f64 {
+        <Self as RangeModel<f64>>::clamp(self, value)
+    }
+}
+impl ffi::IntRange {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "value"]
+    pub fn value(&self) -> &i32 {
+        &self.value
+    }
+}
+impl ffi::IntRange {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "value"]
+    pub fn set_value(mut self: core::pin::Pin<&mut Self>, value: i32) {
+        use cxx_qt::CxxQtType;
+        if self.value == value {
+            return;
+        }
+        self.as_mut().rust_mut().value = value;
+        self.as_mut().value_changed();
+    }
+}
+impl ffi::IntRange {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "valueChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_value_changed<F: FnMut(core::pin::Pin<&mut ffi::IntRange>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::IntRange>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: IntRange_connect_value_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < IntRangeCxxQtSignalClosurevalueChanged > :: new (Box :: new (closure)) , conn_type ,))
+    }
+}
+impl ffi::IntRange {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "valueChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_value_changed<F: FnMut(core::pin::Pin<&mut ffi::IntRange>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::IntRange>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: IntRange_connect_value_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < IntRangeCxxQtSignalClosurevalueChanged > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
+    }
+}
+#[doc(hidden)]
+pub struct IntRangeCxxQtSignalClosurevalueChanged {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for IntRangeCxxQtSignalClosurevalueChanged {
+    type Id = cxx::type_id!(
+        "::cxx_qt::templates::rust::cxxqtgen1::IntRangeCxxQtSignalHandlervalueChanged"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::IntRange>) + Send;
+}
+use core::mem::drop as drop_IntRange_signal_handler_valueChanged;
+fn call_IntRange_signal_handler_valueChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<IntRangeCxxQtSignalClosurevalueChanged>,
+    self_value: core::pin::Pin<&mut ffi::IntRange>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<IntRangeCxxQtSignalClosurevalueChanged>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<IntRangeCxxQtSignalClosurevalueChanged>,
+    [usize; 2]
+);
+impl ::cxx_qt::QProperties for ffi::IntRange {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] =
+        &[::cxx_qt::QPropertyDescriptor::new(
+            "value",
+            "value",
+            || unsafe { ffi::cxx_qt_ffi_IntRange_propertyMetaTypeId(::core::ptr::null(), "value") },
+            |qobject, f| {
+                let value = qobject.value();
+                f(::core::borrow::Borrow::<i32>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_value(*value.downcast::<i32>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_value_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        )];
+}
+impl cxx_qt::Threading for ffi::IntRange {
+    type BoxedQueuedFn = IntRangeCxxQtThreadQueuedFn;
+    type ThreadingTypeId = cxx::type_id!("cxx_qt::templates::IntRangeCxxQtThread");
+    fn qt_thread(&self) -> ffi::IntRangeCxxQtThread {
+        ffi::cxx_qt_ffi_IntRange_qtThread(self)
+    }
+    #[doc(hidden)]
+    fn is_destroyed(cxx_qt_thread: &ffi::IntRangeCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_IntRange_cxxQtThreadIsDestroyed(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn queue<F>(
+        cxx_qt_thread: &ffi::IntRangeCxxQtThread,
+        f: F,
+    ) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
+    where
+        F: FnOnce(core::pin::Pin<&mut ffi::IntRange>),
+        F: Send + 'static,
+    {
+        #[allow(clippy::boxed_local)]
+        #[doc(hidden)]
+        fn func(
+            obj: core::pin::Pin<&mut ffi::IntRange>,
+            arg: std::boxed::Box<IntRangeCxxQtThreadQueuedFn>,
+        ) {
+            (arg.inner)(obj)
+        }
+        let arg = IntRangeCxxQtThreadQueuedFn {
+            inner: std::boxed::Box::new(f),
+        };
+        match ffi::cxx_qt_ffi_IntRange_cxxQtThreadQueue(
+            cxx_qt_thread,
+            func,
+            std::boxed::Box::new(arg),
+        ) {
+            0 => Ok(()),
+            others => Err(others.into()),
+        }
+    }
+    #[doc(hidden)]
+    fn threading_clone(cxx_qt_thread: &ffi::IntRangeCxxQtThread) -> ffi::IntRangeCxxQtThread {
+        ffi::cxx_qt_ffi_IntRange_cxxQtThreadClone(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn threading_drop(cxx_qt_thread: core::pin::Pin<&mut ffi::IntRangeCxxQtThread>) {
+        ffi::cxx_qt_ffi_IntRange_cxxQtThreadDrop(cxx_qt_thread);
+    }
+}
+#[doc(hidden)]
+pub struct IntRangeCxxQtThreadQueuedFn {
+    inner: std::boxed::Box<dyn FnOnce(core::pin::Pin<&mut ffi::IntRange>) + Send>,
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::IntRange {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_IntRange_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_IntRange_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_IntRangeRust() -> std::boxed::Box<IntRangeRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::IntRange {
+    type Target = IntRangeRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_IntRange_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::IntRange {
+    type Rust = IntRangeRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_IntRange_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_IntRange_unsafeRustMut(self)
+    }
+}
+impl ffi::FloatRange {
+    #[doc = "Getter for the Q_PROPERTY "]
+    #[doc = "value"]
+    pub fn value(&self) -> &f64 {
+        &self.value
+    }
+}
+impl ffi::FloatRange {
+    #[doc = "Setter for the Q_PROPERTY "]
+    #[doc = "value"]
+    pub fn set_value(mut self: core::pin::Pin<&mut Self>, value: f64) {
+        use cxx_qt::CxxQtType;
+        if self.value == value {
+            return;
+        }
+        self.as_mut().rust_mut().value = value;
+        self.as_mut().value_changed();
+    }
+}
+impl ffi::FloatRange {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "valueChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[allow(dead_code)]
+    pub fn connect_value_changed<
+        F: FnMut(core::pin::Pin<&mut ffi::FloatRange>) + 'static + Send,
+    >(
+        self: core::pin::Pin<&mut ffi::FloatRange>,
+        closure: F,
+        conn_type: cxx_qt::ConnectionType,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: FloatRange_connect_value_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < FloatRangeCxxQtSignalClosurevalueChanged > :: new (Box :: new (closure)) , conn_type ,))
+    }
+}
+impl ffi::FloatRange {
+    #[doc = "Connect the given function pointer to the signal "]
+    #[doc = "valueChanged"]
+    #[doc = ", so that when the signal is emitted the function pointer is executed."]
+    #[doc = "\n"]
+    #[doc = "Note that this method uses a AutoConnection connection type."]
+    #[allow(dead_code)]
+    pub fn on_value_changed<F: FnMut(core::pin::Pin<&mut ffi::FloatRange>) + 'static + Send>(
+        self: core::pin::Pin<&mut ffi::FloatRange>,
+        closure: F,
+    ) -> cxx_qt::QMetaObjectConnectionGuard {
+        cxx_qt :: QMetaObjectConnectionGuard :: from (ffi :: FloatRange_connect_value_changed (self , cxx_qt :: signalhandler :: CxxQtSignalHandler :: < FloatRangeCxxQtSignalClosurevalueChanged > :: new (Box :: new (closure)) , cxx_qt :: ConnectionType :: AutoConnection ,))
+    }
+}
+#[doc(hidden)]
+pub struct FloatRangeCxxQtSignalClosurevalueChanged {}
+impl cxx_qt::signalhandler::CxxQtSignalHandlerClosure for FloatRangeCxxQtSignalClosurevalueChanged {
+    type Id = cxx::type_id!(
+        "::cxx_qt::templates::rust::cxxqtgen1::FloatRangeCxxQtSignalHandlervalueChanged"
+    );
+    type FnType = dyn FnMut(core::pin::Pin<&mut ffi::FloatRange>) + Send;
+}
+use core::mem::drop as drop_FloatRange_signal_handler_valueChanged;
+fn call_FloatRange_signal_handler_valueChanged(
+    handler: &mut cxx_qt::signalhandler::CxxQtSignalHandler<
+        FloatRangeCxxQtSignalClosurevalueChanged,
+    >,
+    self_value: core::pin::Pin<&mut ffi::FloatRange>,
+) {
+    handler.closure()(self_value);
+}
+cxx_qt::static_assertions::assert_eq_align!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<FloatRangeCxxQtSignalClosurevalueChanged>,
+    usize
+);
+cxx_qt::static_assertions::assert_eq_size!(
+    cxx_qt::signalhandler::CxxQtSignalHandler<FloatRangeCxxQtSignalClosurevalueChanged>,
+    [usize; 2]
+);
+impl ::cxx_qt::QProperties for ffi::FloatRange {
+    const PROPERTIES: &'static [::cxx_qt::QPropertyDescriptor<Self>] =
+        &[::cxx_qt::QPropertyDescriptor::new(
+            "value",
+            "value",
+            || unsafe {
+                ffi::cxx_qt_ffi_FloatRangeModel_propertyMetaTypeId(::core::ptr::null(), "value")
+            },
+            |qobject, f| {
+                let value = qobject.value();
+                f(::core::borrow::Borrow::<f64>::borrow(&value))
+            },
+            Some(|qobject, value| {
+                qobject.set_value(*value.downcast::<f64>()?);
+                Ok(())
+            }),
+            Some(|qobject, mut closure, conn_type| {
+                qobject.connect_value_changed(move |qobject| closure(qobject), conn_type)
+            }),
+        )];
+}
+impl cxx_qt::Threading for ffi::FloatRange {
+    type BoxedQueuedFn = FloatRangeCxxQtThreadQueuedFn;
+    type ThreadingTypeId = cxx::type_id!("cxx_qt::templates::FloatRangeCxxQtThread");
+    fn qt_thread(&self) -> ffi::FloatRangeCxxQtThread {
+        ffi::cxx_qt_ffi_FloatRangeModel_qtThread(self)
+    }
+    #[doc(hidden)]
+    fn is_destroyed(cxx_qt_thread: &ffi::FloatRangeCxxQtThread) -> bool {
+        ffi::cxx_qt_ffi_FloatRangeModel_cxxQtThreadIsDestroyed(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn queue<F>(
+        cxx_qt_thread: &ffi::FloatRangeCxxQtThread,
+        f: F,
+    ) -> std::result::Result<(), cxx_qt::ThreadingQueueError>
+    where
+        F: FnOnce(core::pin::Pin<&mut ffi::FloatRange>),
+        F: Send + 'static,
+    {
+        #[allow(clippy::boxed_local)]
+        #[doc(hidden)]
+        fn func(
+            obj: core::pin::Pin<&mut ffi::FloatRange>,
+            arg: std::boxed::Box<FloatRangeCxxQtThreadQueuedFn>,
+        ) {
+            (arg.inner)(obj)
+        }
+        let arg = FloatRangeCxxQtThreadQueuedFn {
+            inner: std::boxed::Box::new(f),
+        };
+        match ffi::cxx_qt_ffi_FloatRangeModel_cxxQtThreadQueue(
+            cxx_qt_thread,
+            func,
+            std::boxed::Box::new(arg),
+        ) {
+            0 => Ok(()),
+            others => Err(others.into()),
+        }
+    }
+    #[doc(hidden)]
+    fn threading_clone(cxx_qt_thread: &ffi::FloatRangeCxxQtThread) -> ffi::FloatRangeCxxQtThread {
+        ffi::cxx_qt_ffi_FloatRangeModel_cxxQtThreadClone(cxx_qt_thread)
+    }
+    #[doc(hidden)]
+    fn threading_drop(cxx_qt_thread: core::pin::Pin<&mut ffi::FloatRangeCxxQtThread>) {
+        ffi::cxx_qt_ffi_FloatRangeModel_cxxQtThreadDrop(cxx_qt_thread);
+    }
+}
+#[doc(hidden)]
+pub struct FloatRangeCxxQtThreadQueuedFn {
+    inner: std::boxed::Box<dyn FnOnce(core::pin::Pin<&mut ffi::FloatRange>) + Send>,
+}
+unsafe impl ::cxx_qt::casting::Upcast<::cxx_qt::QObject> for ffi::FloatRange {
+    unsafe fn upcast_ptr(this: *const Self) -> *const ::cxx_qt::QObject {
+        ffi::cxx_qt_ffi_FloatRangeModel_upcastPtr(this)
+    }
+    unsafe fn from_base_ptr(base: *const ::cxx_qt::QObject) -> *const Self {
+        ffi::cxx_qt_ffi_FloatRangeModel_downcastPtr(base)
+    }
+}
+#[doc(hidden)]
+#[allow(clippy::unnecessary_box_returns)]
+pub fn create_rs_FloatRangeRust() -> std::boxed::Box<FloatRangeRust> {
+    std::boxed::Box::new(core::default::Default::default())
+}
+impl ::core::ops::Deref for ffi::FloatRange {
+    type Target = FloatRangeRust;
+    fn deref(&self) -> &Self::Target {
+        ffi::cxx_qt_ffi_FloatRangeModel_unsafeRust(self)
+    }
+}
+impl ::cxx_qt::CxxQtType for ffi::FloatRange {
+    type Rust = FloatRangeRust;
+    fn rust(&self) -> &Self::Rust {
+        ffi::cxx_qt_ffi_FloatRangeModel_unsafeRust(self)
+    }
+    fn rust_mut(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut Self::Rust> {
+        ffi::cxx_qt_ffi_FloatRangeModel_unsafeRustMut(self)
+    }
+}
@@ -15,7 +15,9 @@
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, ItemMod};
 
-use cxx_qt_gen::{self_inlining::qualify_self_types, write_rust, GeneratedRustBlocks, Parser};
+use cxx_qt_gen::{
+    lint, self_inlining::qualify_self_types, write_rust, GeneratedRustBlocks, LintConfig, Parser,
+};
 
 #[proc_macro_attribute]
 pub fn bridge(args: TokenStream, input: TokenStream) -> TokenStream {
@@ -79,8 +81,18 @@ fn extract_and_generate(module: ItemMod) -> TokenStream {
             qualify_self_types(&mut parser)?;
             Ok(parser)
         })
-        .and_then(|parser| GeneratedRustBlocks::from(&parser))
-        .map(|generated_rust| write_rust(&generated_rust, None))
+        .and_then(|parser| {
+            let generated_rust = GeneratedRustBlocks::from(&parser)?;
+            let mut tokens = write_rust(&generated_rust, None);
+
+            // Warnings can not be emitted from a proc-macro, so only denied lints are reported here,
+            // cxx-qt-build reports the other lints when generating the C++ code
+            let lints = lint(&parser, &LintConfig::from_env())?;
+            for diagnostic in lints.iter().filter(|diagnostic| diagnostic.is_error()) {
+                tokens.extend(diagnostic.to_error().to_compile_error());
+            }
+            Ok(tokens)
+        })
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
@@ -0,0 +1,26 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <memory>
+#include <utility>
+
+#include <QtCore/QObject>
+
+namespace rust::cxxqt1 {
+
+// Construct a new instance of T with the given arguments.
+//
+// T and the arguments are deduced from the signature declared in the bridge.
+template<typename T, typename... Args>
+::std::unique_ptr<T>
+qobjectNew(Args... args)
+{
+  static_assert(::std::is_base_of_v<QObject, T>);
+  return ::std::make_unique<T>(::std::forward<Args>(args)...);
+}
+
+}
@@ -0,0 +1,35 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <cstdint>
+
+#include <QtCore/QByteArray>
+#include <QtCore/QMetaObject>
+#include <QtCore/QMetaProperty>
+
+#include "rust/cxx.h"
+
+namespace rust::cxxqt1 {
+
+// Returns the QMetaType id of the property with the given name on T.
+//
+// The pointer is only used for deducing T and is never dereferenced.
+template<typename T>
+::std::int32_t
+propertyMetaTypeId(const T*, ::rust::Str name)
+{
+  const QByteArray nameBytes(name.data(), static_cast<int>(name.size()));
+  const QMetaObject& metaObject = T::staticMetaObject;
+  const int index = metaObject.indexOfProperty(nameBytes.constData());
+  if (index < 0) {
+    return 0;
+  }
+
+  return metaObject.property(index).userType();
+}
+
+}
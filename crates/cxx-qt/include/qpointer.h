@@ -0,0 +1,49 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+
+#include "rust/cxx.h"
+
+// Define namespace otherwise we hit a GCC bug
+// https://gcc.gnu.org/bugzilla/show_bug.cgi?id=56480
+namespace rust {
+
+template<>
+struct IsRelocatable<::QPointer<::QObject>> : ::std::true_type
+{};
+
+} // namespace rust
+
+namespace rust {
+namespace cxxqt1 {
+
+// Every cxx_qt::QPointer<T> is represented by a QPointer<QObject>,
+// the Rust side upcasts and downcasts the tracked object as required.
+using QPointerQObject = ::QPointer<::QObject>;
+
+::QPointer<::QObject>
+qpointerDefault();
+
+::QPointer<::QObject>
+qpointerFromPtr(::QObject* object);
+
+::QPointer<::QObject>
+qpointerClone(const ::QPointer<::QObject>& pointer);
+
+void
+qpointerDrop(::QPointer<::QObject>& pointer);
+
+::QObject*
+qpointerData(const ::QPointer<::QObject>& pointer);
+
+::QObject*
+qpointerDataOnCurrentThread(const ::QPointer<::QObject>& pointer);
+
+}
+}
@@ -15,7 +15,9 @@ use std::{fs::File, io::Write, path::Path};
 pub mod casting;
 mod connection;
 mod connectionguard;
+mod property;
 mod qobject;
+mod qpointer;
 #[doc(hidden)]
 pub mod signalhandler;
 mod threading;
@@ -114,9 +116,11 @@ pub use cxx_qt_macro::init_qml_module;
 /// ```
 pub use cxx_qt_macro::qobject;
 pub use qobject::QObject;
+pub use qpointer::QPointer;
 
 pub use connection::{ConnectionType, QMetaObjectConnection};
 pub use connectionguard::QMetaObjectConnectionGuard;
+pub use property::{FuzzyCompare, QProperties, QPropertyDescriptor, QPropertyError};
 pub use threading::{CxxQtThread, ThreadingQueueError};
 
 // Export static assertions that can then be used in cxx-qt-gen generation
@@ -438,6 +442,95 @@ where
     }
 }
 
+/// This trait can be implemented on any [CxxQtType] to run Rust code before the QObject is destroyed.
+///
+/// Ensure that the `impl cxx_qt::Finalize for ... {}` is declared inside the CXX-Qt bridge.
+///
+/// The `finalize` function is called at the start of the C++ destructor of the QObject.
+/// Unlike [Drop] on the inner Rust struct, it has access to the QObject itself,
+/// so it can still emit signals, read properties or disconnect from other objects.
+/// If [Threading] is enabled, the [CxxQtThread] of the QObject is still valid at this point,
+/// queued invocations are rejected once the destructor has finished.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// # // FIXME: test doesn't link correctly on Windows
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     extern "RustQt" {
+///         #[qobject]
+///         type MyStruct = super::MyStructRust;
+///
+///         #[qsignal]
+///         fn about_to_be_destroyed(self: Pin<&mut MyStruct>);
+///     }
+///
+///     impl cxx_qt::Finalize for MyStruct {}
+/// }
+///
+/// #[derive(Default)]
+/// pub struct MyStructRust;
+///
+/// impl cxx_qt::Finalize for qobject::MyStruct {
+///     fn finalize(self: core::pin::Pin<&mut Self>) {
+///         self.about_to_be_destroyed();
+///     }
+/// }
+///
+/// # // Note that we need a fake main function for doc tests to build.
+/// # fn main() {}
+/// ```
+pub trait Finalize: CxxQtType {
+    /// This function is called before the QObject is destroyed.
+    fn finalize(self: core::pin::Pin<&mut Self>);
+}
+
+/// This trait is automatically implemented by CXX-Qt for each [Constructor] declared in the bridge,
+/// unless the QObject is abstract, because it has `#[cxx_pure]` methods or no `#[qobject]` attribute.
+/// For the default constructor, declare `impl cxx_qt::Constructor<()> for MyObject {}`.
+/// It allows for creating instances of the QObject from Rust, which are constructed in the same
+/// way as when they are created from C++ or QML.
+///
+/// Note that any pointers in the arguments are passed to the C++ constructor as-is.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use cxx_qt::{casting::Upcast, Instantiate};
+///
+/// // Owned by the returned UniquePtr
+/// let object = qobject::MyObject::new_unique(());
+///
+/// // Owned by the given parent, which deletes it when the parent is deleted
+/// let child = qobject::MyObject::new_with_parent((), object.pin_mut().upcast_pin());
+/// ```
+pub trait Instantiate<Arguments>: cxx::memory::UniquePtrTarget + Sized {
+    /// Create a new instance of the QObject with the given arguments, owned by the returned `UniquePtr`
+    fn new_unique(arguments: Arguments) -> cxx::UniquePtr<Self>;
+
+    /// Create a new instance of the QObject with the given arguments, and transfer ownership to the given parent.
+    ///
+    /// The parent deletes the new instance when it is destroyed itself.
+    fn new_with_parent(
+        arguments: Arguments,
+        parent: core::pin::Pin<&mut QObject>,
+    ) -> core::pin::Pin<&mut Self>
+    where
+        Self: casting::Upcast<QObject>,
+    {
+        let child = Self::new_unique(arguments).into_raw();
+        // SAFETY: the child was just created so it is valid, and it lives as long as the parent,
+        // which is borrowed for the lifetime of the returned reference.
+        unsafe {
+            let child_qobject = Self::upcast_ptr(child).cast_mut();
+            core::pin::Pin::new_unchecked(&mut *child_qobject)
+                .cxx_qt_set_parent(parent.get_unchecked_mut());
+            core::pin::Pin::new_unchecked(&mut *child)
+        }
+    }
+}
+
 #[doc(hidden)]
 // Write the cxx-qt headers to the specified directory.
 pub fn write_headers(directory: impl AsRef<Path>) {
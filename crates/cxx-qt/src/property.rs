@@ -0,0 +1,275 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::{any::Any, pin::Pin};
+use thiserror::Error;
+
+use crate::{ConnectionType, QMetaObjectConnectionGuard};
+
+/// Errors that can occur when accessing a property through a [QPropertyDescriptor]
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum QPropertyError {
+    /// The property does not have a `WRITE` function
+    #[error("Cannot write to property as it is read only")]
+    ReadOnly,
+    /// The value given did not have the type of the property
+    #[error("Cannot write to property as the value has the wrong type")]
+    TypeMismatch,
+}
+
+type ReadFn<T> = fn(&T, &mut dyn FnMut(&dyn Any));
+type WriteFn<T> = fn(Pin<&mut T>, Box<dyn Any>) -> Result<(), Box<dyn Any>>;
+type NotifyFn<T> = fn(
+    Pin<&mut T>,
+    Box<dyn FnMut(Pin<&mut T>) + Send>,
+    ConnectionType,
+) -> QMetaObjectConnectionGuard;
+
+/// Describes a single `Q_PROPERTY` of a QObject generated by CXX-Qt.
+///
+/// This allows for reading, writing and observing properties generically,
+/// without knowing the concrete property at compile time.
+///
+/// The descriptors of a QObject can be found via the [QProperties] trait.
+pub struct QPropertyDescriptor<T: 'static> {
+    name: &'static str,
+    rust_name: &'static str,
+    meta_type_id: fn() -> i32,
+    read: ReadFn<T>,
+    write: Option<WriteFn<T>>,
+    notify: Option<NotifyFn<T>>,
+}
+
+impl<T: 'static> QPropertyDescriptor<T> {
+    #[doc(hidden)]
+    // This is only used by the code generated by CXX-Qt
+    pub const fn new(
+        name: &'static str,
+        rust_name: &'static str,
+        meta_type_id: fn() -> i32,
+        read: ReadFn<T>,
+        write: Option<WriteFn<T>>,
+        notify: Option<NotifyFn<T>>,
+    ) -> Self {
+        Self {
+            name,
+            rust_name,
+            meta_type_id,
+            read,
+            write,
+            notify,
+        }
+    }
+
+    /// The name of the property as seen from C++ and QML
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The name of the property as seen from Rust
+    pub fn rust_name(&self) -> &'static str {
+        self.rust_name
+    }
+
+    /// The `QMetaType` id of the type of the property
+    ///
+    /// This can be converted into a `cxx_qt_lib::QMetaTypeType`.
+    pub fn meta_type_id(&self) -> i32 {
+        (self.meta_type_id)()
+    }
+
+    /// Whether the property can be written to
+    pub fn is_writable(&self) -> bool {
+        self.write.is_some()
+    }
+
+    /// Whether the property has a notify signal which can be connected to
+    pub fn has_notify(&self) -> bool {
+        self.notify.is_some()
+    }
+
+    /// Call the given closure with a reference to the current value of the property
+    pub fn read_with<F>(&self, qobject: &T, f: F)
+    where
+        F: FnOnce(&dyn Any),
+    {
+        let mut f = Some(f);
+        (self.read)(qobject, &mut |value| {
+            if let Some(f) = f.take() {
+                f(value)
+            }
+        });
+    }
+
+    /// Retrieve a copy of the current value of the property
+    ///
+    /// Returns [None] if the property is not of the type `V`.
+    pub fn read<V>(&self, qobject: &T) -> Option<V>
+    where
+        V: Clone + 'static,
+    {
+        let mut result = None;
+        self.read_with(qobject, |value| {
+            result = value.downcast_ref::<V>().cloned();
+        });
+        result
+    }
+
+    /// Write the given value to the property, this is equivalent to calling the setter.
+    pub fn write<V>(&self, qobject: Pin<&mut T>, value: V) -> Result<(), QPropertyError>
+    where
+        V: 'static,
+    {
+        self.write_any(qobject, Box::new(value))
+    }
+
+    /// Write the given boxed value to the property, this is equivalent to calling the setter.
+    pub fn write_any(
+        &self,
+        qobject: Pin<&mut T>,
+        value: Box<dyn Any>,
+    ) -> Result<(), QPropertyError> {
+        let write = self.write.ok_or(QPropertyError::ReadOnly)?;
+        write(qobject, value).map_err(|_| QPropertyError::TypeMismatch)
+    }
+
+    /// Connect the given closure to the notify signal of the property.
+    ///
+    /// Returns [None] if the property does not have a notify signal.
+    pub fn connect_notify<F>(
+        &self,
+        qobject: Pin<&mut T>,
+        closure: F,
+        conn_type: ConnectionType,
+    ) -> Option<QMetaObjectConnectionGuard>
+    where
+        F: FnMut(Pin<&mut T>) + Send + 'static,
+    {
+        self.notify
+            .map(|notify| notify(qobject, Box::new(closure), conn_type))
+    }
+}
+
+/// This trait is automatically implemented for all QObject types generated by CXX-Qt.
+/// It provides a [QPropertyDescriptor] for each `#[qproperty]` declared on the QObject,
+/// in the order that they are declared in the bridge.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use cxx_qt::QProperties;
+///
+/// fn print_properties<T: QProperties>(qobject: &T) {
+///     for property in T::PROPERTIES {
+///         property.read_with(qobject, |value| {
+///             if let Some(value) = value.downcast_ref::<i32>() {
+///                 println!("{}: {value}", property.name());
+///             }
+///         });
+///     }
+/// }
+/// ```
+pub trait QProperties: Sized + 'static {
+    /// The descriptors for each of the properties of this QObject
+    const PROPERTIES: &'static [QPropertyDescriptor<Self>];
+
+    /// Find the descriptor of the property with the given C++ name
+    fn property(name: &str) -> Option<&'static QPropertyDescriptor<Self>> {
+        Self::PROPERTIES
+            .iter()
+            .find(|property| property.name() == name)
+    }
+}
+
+/// Compare floating point values like `qFuzzyCompare`, this is used by properties with the `fuzzy_compare` flag.
+///
+/// Unlike `qFuzzyCompare`, two NaN values are considered equal,
+/// so that setting a property to NaN repeatedly does not emit the notify signal each time.
+///
+/// Note that as with `qFuzzyCompare`, comparing a value with zero only succeeds if both values are exactly zero.
+pub trait FuzzyCompare {
+    /// Returns true if the values are considered equal
+    fn fuzzy_compare(&self, other: &Self) -> bool;
+}
+
+impl FuzzyCompare for f64 {
+    fn fuzzy_compare(&self, other: &Self) -> bool {
+        if self.is_nan() || other.is_nan() {
+            return self.is_nan() && other.is_nan();
+        }
+
+        if self == other {
+            return true;
+        }
+
+        // The difference of opposite infinities would compare equal to their magnitude
+        if self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+
+        (self - other).abs() * 1_000_000_000_000. <= self.abs().min(other.abs())
+    }
+}
+
+impl FuzzyCompare for f32 {
+    fn fuzzy_compare(&self, other: &Self) -> bool {
+        if self.is_nan() || other.is_nan() {
+            return self.is_nan() && other.is_nan();
+        }
+
+        if self == other {
+            return true;
+        }
+
+        // The difference of opposite infinities would compare equal to their magnitude
+        if self.is_infinite() || other.is_infinite() {
+            return false;
+        }
+
+        (self - other).abs() * 100_000. <= self.abs().min(other.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_compare_f64() {
+        assert!(1.0_f64.fuzzy_compare(&1.0));
+        assert!(1.0_f64.fuzzy_compare(&(1.0 + 1e-14)));
+        assert!(!1.0_f64.fuzzy_compare(&(1.0 + 1e-9)));
+        assert!(1e20_f64.fuzzy_compare(&(1e20 + 1e6)));
+
+        // Zero only compares equal to exactly zero, like qFuzzyCompare
+        assert!(0.0_f64.fuzzy_compare(&0.0));
+        assert!(0.0_f64.fuzzy_compare(&-0.0));
+        assert!(!0.0_f64.fuzzy_compare(&1e-300));
+
+        assert!(f64::NAN.fuzzy_compare(&f64::NAN));
+        assert!(!f64::NAN.fuzzy_compare(&1.0));
+        assert!(!1.0_f64.fuzzy_compare(&f64::NAN));
+        assert!(f64::INFINITY.fuzzy_compare(&f64::INFINITY));
+        assert!(!f64::INFINITY.fuzzy_compare(&f64::NEG_INFINITY));
+        assert!(!f64::INFINITY.fuzzy_compare(&f64::MAX));
+    }
+
+    #[test]
+    fn test_fuzzy_compare_f32() {
+        assert!(1.0_f32.fuzzy_compare(&1.0));
+        assert!(1.0_f32.fuzzy_compare(&(1.0 + 1e-6)));
+        assert!(!1.0_f32.fuzzy_compare(&(1.0 + 1e-4)));
+
+        assert!(0.0_f32.fuzzy_compare(&-0.0));
+        assert!(!0.0_f32.fuzzy_compare(&1e-30));
+
+        assert!(f32::NAN.fuzzy_compare(&f32::NAN));
+        assert!(!f32::NAN.fuzzy_compare(&1.0));
+        assert!(!1.0_f32.fuzzy_compare(&f32::NAN));
+        assert!(f32::INFINITY.fuzzy_compare(&f32::INFINITY));
+        assert!(!f32::INFINITY.fuzzy_compare(&f32::NEG_INFINITY));
+        assert!(!f32::INFINITY.fuzzy_compare(&f32::MAX));
+    }
+}
@@ -16,6 +16,15 @@ mod ffi {
         ///
         /// Qt Documentation: [QObject](https://doc.qt.io/qt/qobject.html#details)
         type QObject;
+
+        /// Sets the parent of the QObject, which then takes ownership of it.
+        ///
+        /// # Safety
+        ///
+        /// The parent must be a valid pointer to a QObject, or a null pointer.
+        #[allow(clippy::missing_safety_doc)]
+        #[cxx_name = "setParent"]
+        pub(crate) unsafe fn cxx_qt_set_parent(self: Pin<&mut QObject>, parent: *mut QObject);
     }
 }
 
@@ -0,0 +1,71 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#include "cxx-qt/qpointer.h"
+
+#include <QtCore/QThread>
+
+#include <type_traits>
+
+// ::QPointer<T> is a QWeakPointer<QObject> which is the size of two pointers
+//
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/kernel/qpointer.h?h=v6.2.4#n20
+// https://code.qt.io/cgit/qt/qtbase.git/tree/src/corelib/tools/qsharedpointer_impl.h?h=v6.2.4#n698
+static_assert(alignof(::QPointer<::QObject>) <= (alignof(::std::size_t)),
+              "unexpectedly large ::QPointer<::QObject> alignment!");
+static_assert(sizeof(::QPointer<::QObject>) == (sizeof(::std::size_t[2])),
+              "unexpected ::QPointer<::QObject> size!");
+
+static_assert(!::std::is_trivially_copy_assignable<::QPointer<::QObject>>::value);
+static_assert(
+  !::std::is_trivially_copy_constructible<::QPointer<::QObject>>::value);
+static_assert(!::std::is_trivially_destructible<::QPointer<::QObject>>::value);
+
+namespace rust {
+namespace cxxqt1 {
+
+::QPointer<::QObject>
+qpointerDefault()
+{
+  return ::QPointer<::QObject>();
+}
+
+::QPointer<::QObject>
+qpointerFromPtr(::QObject* object)
+{
+  return ::QPointer<::QObject>(object);
+}
+
+::QPointer<::QObject>
+qpointerClone(const ::QPointer<::QObject>& pointer)
+{
+  return pointer;
+}
+
+void
+qpointerDrop(::QPointer<::QObject>& pointer)
+{
+  pointer.~QPointer();
+}
+
+::QObject*
+qpointerData(const ::QPointer<::QObject>& pointer)
+{
+  return pointer.data();
+}
+
+::QObject*
+qpointerDataOnCurrentThread(const ::QPointer<::QObject>& pointer)
+{
+  ::QObject* object = pointer.data();
+  if (object == nullptr || object->thread() != ::QThread::currentThread()) {
+    return nullptr;
+  }
+
+  return object;
+}
+
+}
+}
@@ -0,0 +1,245 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+use crate::{casting::Upcast, QObject};
+use core::{marker::PhantomData, mem::ManuallyDrop, pin::Pin};
+use cxx::{type_id, ExternType};
+use std::mem::MaybeUninit;
+
+#[cxx::bridge]
+mod ffi {
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/qpointer.h");
+
+        #[doc(hidden)]
+        #[namespace = ""]
+        type QObject = crate::QObject;
+
+        #[doc(hidden)]
+        type QPointerQObject = crate::QPointer<QObject>;
+
+        #[doc(hidden)]
+        #[rust_name = "qpointer_default"]
+        fn qpointerDefault() -> QPointerQObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qpointer_from_ptr"]
+        unsafe fn qpointerFromPtr(object: *mut QObject) -> QPointerQObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qpointer_clone"]
+        fn qpointerClone(pointer: &QPointerQObject) -> QPointerQObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qpointer_drop"]
+        fn qpointerDrop(pointer: &mut QPointerQObject);
+
+        #[doc(hidden)]
+        #[rust_name = "qpointer_data"]
+        fn qpointerData(pointer: &QPointerQObject) -> *mut QObject;
+
+        #[doc(hidden)]
+        #[rust_name = "qpointer_data_on_current_thread"]
+        fn qpointerDataOnCurrentThread(pointer: &QPointerQObject) -> *mut QObject;
+    }
+}
+
+/// A weak handle to a QObject, which becomes null when the QObject is destroyed.
+///
+/// This can be used instead of storing a `*mut T` to another QObject, as the pointer can never dangle.
+/// It can be created for any type which upcasts to [QObject], including QObjects defined in a CXX-Qt bridge.
+///
+/// The handle is not [Send], and it can only be upgraded to a reference on the thread the QObject lives in.
+///
+/// To use it as the type of a `#[qproperty]` or in other bridge functions,
+/// declare an alias for it in an `extern "C++"` block:
+///
+/// ```rust,ignore
+/// #[cxx_qt::bridge]
+/// mod qobject {
+///     unsafe extern "C++" {
+///         include!("cxx-qt/qpointer.h");
+///
+///         #[cxx_name = "QPointerQObject"]
+///         #[namespace = "rust::cxxqt1"]
+///         type QPointer_InnerObject = cxx_qt::QPointer<InnerObject>;
+///     }
+///
+///     extern "RustQt" {
+///         #[qobject]
+///         type InnerObject = super::InnerObjectRust;
+///
+///         #[qobject]
+///         #[qproperty(QPointer_InnerObject, inner)]
+///         type OuterObject = super::OuterObjectRust;
+///     }
+/// }
+/// ```
+///
+/// On the C++ side this is a [QPointer](https://doc.qt.io/qt/qpointer.html)`<QObject>`.
+///
+/// Qt Documentation: [QPointer](https://doc.qt.io/qt/qpointer.html#details)
+#[repr(C)]
+pub struct QPointer<T> {
+    _space: MaybeUninit<[usize; 2]>,
+    // The handle must stay on the thread it was created on, so it is neither Send nor Sync
+    _marker: PhantomData<*const T>,
+}
+
+impl<T> QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Create a new handle tracking the given QObject
+    pub fn new(object: Pin<&mut T>) -> Self {
+        let object = object.upcast_pin();
+        // SAFETY: the pointer comes from a valid reference to a QObject
+        let pointer = unsafe { ffi::qpointer_from_ptr(object.get_unchecked_mut()) };
+        Self::from_qobject_pointer(pointer)
+    }
+
+    /// Create a handle which does not track any QObject
+    pub fn null() -> Self {
+        Self::from_qobject_pointer(ffi::qpointer_default())
+    }
+
+    /// Returns `true` if the tracked QObject has been destroyed or if no QObject was tracked
+    pub fn is_null(&self) -> bool {
+        ffi::qpointer_data(self.as_qobject_pointer()).is_null()
+    }
+
+    /// Returns the pointer to the tracked QObject, or a null pointer if it has been destroyed
+    pub fn as_ptr(&self) -> *mut T {
+        Self::downcast_ptr(ffi::qpointer_data(self.as_qobject_pointer()))
+    }
+
+    /// Returns a reference to the tracked QObject.
+    ///
+    /// This returns `None` if the QObject has been destroyed,
+    /// or if the QObject does not live in the current thread.
+    ///
+    /// # Safety
+    ///
+    /// The returned reference is not tied to the lifetime of the QObject, as it is owned by C++.
+    /// The caller must ensure that:
+    ///
+    /// - no mutable reference to the QObject exists while the returned reference is alive,
+    ///   for example the `Pin<&mut T>` of an invokable which upgrades a handle to its own object.
+    /// - the QObject is not destroyed while the returned reference is alive,
+    ///   for example by calling code which deletes it or by returning to the event loop.
+    pub unsafe fn upgrade(&self) -> Option<&T> {
+        let ptr = Self::downcast_ptr(ffi::qpointer_data_on_current_thread(
+            self.as_qobject_pointer(),
+        ));
+        // SAFETY: the QObject is alive and lives in the current thread,
+        // and the caller guarantees that it is not mutably referenced for the lifetime of the reference.
+        unsafe { ptr.as_ref() }
+    }
+
+    /// Returns a pinned mutable reference to the tracked QObject.
+    ///
+    /// This returns `None` if the QObject has been destroyed,
+    /// or if the QObject does not live in the current thread.
+    ///
+    /// # Safety
+    ///
+    /// The returned reference is not tied to the lifetime of the QObject, as it is owned by C++.
+    /// The caller must ensure that:
+    ///
+    /// - no other reference to the QObject exists while the returned reference is alive,
+    ///   for example one upgraded from a clone of this handle or held by the caller of an invokable.
+    /// - the QObject is not destroyed while the returned reference is alive,
+    ///   for example by calling code which deletes it or by returning to the event loop.
+    pub unsafe fn upgrade_mut(&mut self) -> Option<Pin<&mut T>> {
+        let ptr = Self::downcast_ptr(ffi::qpointer_data_on_current_thread(
+            self.as_qobject_pointer(),
+        ));
+        // SAFETY: the QObject is alive and lives in the current thread,
+        // the caller guarantees that the reference is unique for its lifetime,
+        // and QObjects are never moved in memory.
+        unsafe { ptr.as_mut().map(|object| Pin::new_unchecked(object)) }
+    }
+
+    fn downcast_ptr(ptr: *mut QObject) -> *mut T {
+        if ptr.is_null() {
+            core::ptr::null_mut()
+        } else {
+            // SAFETY: the QPointer can only be created from a T
+            unsafe { T::from_base_ptr(ptr).cast_mut() }
+        }
+    }
+
+    fn as_qobject_pointer(&self) -> &QPointer<QObject> {
+        // SAFETY: all QPointers have the same layout, regardless of T
+        unsafe { &*(self as *const Self).cast::<QPointer<QObject>>() }
+    }
+
+    fn from_qobject_pointer(pointer: QPointer<QObject>) -> Self {
+        let pointer = ManuallyDrop::new(pointer);
+        // SAFETY: all QPointers have the same layout, regardless of T
+        unsafe { core::ptr::read((&*pointer as *const QPointer<QObject>).cast::<Self>()) }
+    }
+}
+
+impl<T> Clone for QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Create a new handle tracking the same QObject
+    fn clone(&self) -> Self {
+        Self::from_qobject_pointer(ffi::qpointer_clone(self.as_qobject_pointer()))
+    }
+}
+
+impl<T> Default for QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Create a handle which does not track any QObject
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+impl<T> Drop for QPointer<T> {
+    fn drop(&mut self) {
+        // SAFETY: all QPointers have the same layout, regardless of T
+        let pointer = unsafe { &mut *(self as *mut Self).cast::<QPointer<QObject>>() };
+        ffi::qpointer_drop(pointer);
+    }
+}
+
+impl<T> PartialEq for QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    /// Two handles are equal if they track the same QObject, or both are null
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ptr() == other.as_ptr()
+    }
+}
+
+impl<T> Eq for QPointer<T> where T: Upcast<QObject> {}
+
+impl<T> core::fmt::Debug for QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("QPointer").field(&self.as_ptr()).finish()
+    }
+}
+
+// Safety:
+//
+// Static checks on the C++ side to ensure the size is the same.
+// Every QPointer<T> is represented by a QPointer<QObject> in C++,
+// the tracked object is upcast when the QPointer is created and downcast when it is read.
+unsafe impl<T> ExternType for QPointer<T>
+where
+    T: Upcast<QObject>,
+{
+    type Id = type_id!("rust::cxxqt1::QPointerQObject");
+    type Kind = cxx::kind::Trivial;
+}
@@ -39,6 +39,11 @@ pub use installation::qt_minimal::QtInstallationQtMinimal;
 #[cfg(feature = "qmake")]
 mod parse_cflags;
 
+mod parallel;
+// Shared with cxx-qt-build, this is not part of the public API
+#[doc(hidden)]
+pub use parallel::parallel_map;
+
 mod platform;
 pub use platform::QtPlatformLinker;
 
@@ -386,19 +391,19 @@ impl QtBuild {
                 qmldir_qrc_path: qrc_path.clone(),
             };
             let mut qml_resource_paths = Vec::new();
-            for file in qml_files {
-                let result = QtToolQmlCacheGen::new(self.qt_installation.as_ref())
-                    .compile(qml_cache_args.clone(), file.get_path());
+            let qmlcachegen = QtToolQmlCacheGen::new(self.qt_installation.as_ref());
+            let results = parallel_map(qml_files, |file| {
+                qmlcachegen.compile(qml_cache_args.clone(), file.get_path())
+            });
+            for result in results {
                 qmlcachegen_file_paths.push(result.qml_cache_path);
                 qml_resource_paths.push(result.qml_resource_path);
             }
 
             // If there are no QML files there is nothing for qmlcachegen to run with
             if !qml_files.is_empty() {
-                qmlcachegen_file_paths.push(
-                    QtToolQmlCacheGen::new(self.qt_installation.as_ref())
-                        .compile_loader(qml_cache_args.clone(), &qml_resource_paths),
-                );
+                qmlcachegen_file_paths
+                    .push(qmlcachegen.compile_loader(qml_cache_args.clone(), &qml_resource_paths));
             }
         }
 
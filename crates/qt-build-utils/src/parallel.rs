@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, OnceLock,
+    },
+    thread,
+};
+
+/// The jobserver that Cargo passes to the build script, if any
+fn jobserver() -> Option<&'static jobserver::Client> {
+    static CLIENT: OnceLock<Option<jobserver::Client>> = OnceLock::new();
+    CLIENT
+        // SAFETY: this is only called once, from within a build script,
+        // where the file descriptors of the jobserver are inherited from Cargo.
+        .get_or_init(|| unsafe { jobserver::Client::from_env() })
+        .as_ref()
+}
+
+/// Run the given function over every item in parallel, returning the results in the order of the items.
+///
+/// The build script itself owns one job of the [Cargo jobserver](https://doc.rust-lang.org/cargo/reference/build-scripts.html#jobserver),
+/// additional threads are only started for the jobs that are currently available.
+/// Without a jobserver, the number of threads is limited by [std::thread::available_parallelism].
+pub fn parallel_map<T, R>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+{
+    let max_threads = thread::available_parallelism()
+        .map(usize::from)
+        .unwrap_or(1)
+        .min(items.len());
+    if max_threads <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    // Only take the tokens which are available right now, so we never wait for other crates
+    let tokens: Vec<_> = match jobserver() {
+        Some(client) => std::iter::from_fn(|| client.try_acquire().ok().flatten())
+            .take(max_threads - 1)
+            .collect(),
+        None => vec![],
+    };
+    let extra_threads = if jobserver().is_some() {
+        tokens.len()
+    } else {
+        max_threads - 1
+    };
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<Option<R>>>());
+    let work = || loop {
+        let index = next.fetch_add(1, Ordering::Relaxed);
+        let Some(item) = items.get(index) else {
+            break;
+        };
+        let result = f(item);
+        results.lock().unwrap()[index] = Some(result);
+    };
+
+    thread::scope(|scope| {
+        for _ in 0..extra_threads {
+            scope.spawn(work);
+        }
+        work();
+    });
+    drop(tokens);
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|result| result.expect("Every item should have been processed"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_map_keeps_order() {
+        let items: Vec<usize> = (0..100).collect();
+        let results = parallel_map(&items, |item| item * 2);
+        assert_eq!(results, (0..100).map(|item| item * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn parallel_map_empty() {
+        let items: Vec<usize> = vec![];
+        assert!(parallel_map(&items, |item| *item).is_empty());
+    }
+}
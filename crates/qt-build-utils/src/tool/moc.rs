@@ -88,6 +88,9 @@ impl QtToolMoc {
     /// Run moc on a C++ header file and save the output into [cargo's OUT_DIR](https://doc.rust-lang.org/cargo/reference/environment-variables.html).
     /// The return value contains the path to the generated C++ file, which can then be passed to [cc::Build::files](https://docs.rs/cc/latest/cc/struct.Build.html#method.file),
     /// as well as the path to the generated metatypes.json file, which can be used for QML modules.
+    ///
+    /// If moc was already run with the same arguments on a header with the same contents, the
+    /// previous output is reused, which keeps its modification time unchanged.
     pub fn compile(&self, input_file: impl AsRef<Path>, arguments: MocArguments) -> MocProducts {
         let input_path = input_file.as_ref();
         // Put all the moc files into one place, this can then be added to the include path
@@ -126,6 +129,21 @@ impl QtToolMoc {
             .arg("-o")
             .arg(output_path.to_str().unwrap())
             .arg("--output-json");
+
+        // Skip running moc if neither the command nor the contents of the header changed
+        let stamp_path = PathBuf::from(format!("{}.stamp", output_path.display()));
+        let stamp = Self::stamp(&cmd, input_path);
+        let products = MocProducts {
+            cpp: output_path,
+            metatypes_json: metatypes_json_path,
+        };
+        if products.cpp.exists()
+            && products.metatypes_json.exists()
+            && std::fs::read_to_string(&stamp_path).is_ok_and(|previous| previous == stamp)
+        {
+            return products;
+        }
+
         let cmd = cmd
             // Binaries should work without environment and this prevents
             // LD_LIBRARY_PATH from causing different Qt version clashes
@@ -141,9 +159,74 @@ impl QtToolMoc {
             );
         }
 
-        MocProducts {
-            cpp: output_path,
-            metatypes_json: metatypes_json_path,
+        std::fs::write(&stamp_path, stamp).expect("Could not write moc stamp file");
+
+        products
+    }
+
+    /// A hash of the moc command and the contents of its input file
+    fn stamp(cmd: &Command, input_path: &Path) -> String {
+        let mut hasher = StampHasher::default();
+        hasher.write(cmd.get_program().as_encoded_bytes());
+        for arg in cmd.get_args() {
+            hasher.write(arg.as_encoded_bytes());
+        }
+        hasher.write(&std::fs::read(input_path).unwrap_or_default());
+        format!("{:032x}", hasher.0)
+    }
+}
+
+/// A 128-bit FNV-1a hasher for the moc stamps
+///
+/// Unlike [std::collections::hash_map::DefaultHasher], its output is stable across Rust releases,
+/// so the stamps of a previous build stay valid after a toolchain update.
+struct StampHasher(u128);
+
+impl Default for StampHasher {
+    fn default() -> Self {
+        Self(0x6c62272e07bb014262b821756295c58d)
+    }
+}
+
+impl StampHasher {
+    /// Hash the bytes, prefixed by their length so that consecutive writes cannot be confused
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= u128::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0000000001000000000000000000013b);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stamp_hasher() {
+        assert_eq!(StampHasher::default().0, 0x6c62272e07bb014262b821756295c58d);
+
+        let mut hasher = StampHasher::default();
+        hasher.write(b"ab");
+        let mut split = StampHasher::default();
+        split.write(b"a");
+        split.write(b"b");
+        assert_ne!(hasher.0, split.0);
+    }
+
+    #[test]
+    fn test_stamp() {
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("input.h");
+        std::fs::write(&input, "class A {};").unwrap();
+
+        let mut cmd = Command::new("moc");
+        cmd.arg("-o").arg("output.cpp");
+        let stamp = QtToolMoc::stamp(&cmd, &input);
+        assert_eq!(stamp.len(), 32);
+        assert_eq!(stamp, QtToolMoc::stamp(&cmd, &input));
+
+        std::fs::write(&input, "class B {};").unwrap();
+        assert_ne!(stamp, QtToolMoc::stamp(&cmd, &input));
+    }
+}
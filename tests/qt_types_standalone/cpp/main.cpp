@@ -34,6 +34,7 @@
 #include "qpen.h"
 #include "qpersistentmodelindex.h"
 #include "qpoint.h"
+#include "qpointer.h"
 #include "qpointf.h"
 #include "qpolygon.h"
 #include "qpolygonf.h"
@@ -94,6 +95,7 @@ main(int argc, char* argv[])
   runTest(QScopedPointer<QObject>(new QPersistentModelIndexTest));
   runTest(QScopedPointer<QObject>(new QPointTest));
   runTest(QScopedPointer<QObject>(new QPointFTest));
+  runTest(QScopedPointer<QObject>(new QPointerTest));
   runTest(QScopedPointer<QObject>(new QQmlApplicationEngineTest));
   runTest(QScopedPointer<QObject>(new QQmlEngineTest));
   runTest(QScopedPointer<QObject>(new QRectTest));
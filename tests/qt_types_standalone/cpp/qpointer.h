@@ -0,0 +1,66 @@
+// clang-format off
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+// clang-format on
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+#pragma once
+
+#include <QtCore/QObject>
+#include <QtCore/QThread>
+#include <QtTest/QTest>
+
+#include "qt_types_standalone/src/qpointer.cxx.h"
+
+class QPointerTest : public QObject
+{
+  Q_OBJECT
+
+private Q_SLOTS:
+  void null()
+  {
+    const auto pointer = create_qpointer_null();
+    QVERIFY(qpointer_is_null(*pointer));
+    QCOMPARE(qpointer_as_ptr(*pointer), nullptr);
+    QCOMPARE(qpointer_upgrade(*pointer), nullptr);
+  }
+
+  void upgrade()
+  {
+    QObject object;
+    auto pointer = create_qpointer(object);
+    QVERIFY(!qpointer_is_null(*pointer));
+    QCOMPARE(qpointer_as_ptr(*pointer), &object);
+    QCOMPARE(qpointer_upgrade(*pointer), &object);
+    QCOMPARE(qpointer_upgrade_mut(*pointer), &object);
+  }
+
+  void destroyed()
+  {
+    auto object = new QObject();
+    auto pointer = create_qpointer(*object);
+    auto clone = qpointer_clone(*pointer);
+    QCOMPARE(qpointer_as_ptr(*clone), object);
+
+    delete object;
+
+    // Both the handle and its clone are cleared
+    QVERIFY(qpointer_is_null(*pointer));
+    QVERIFY(qpointer_is_null(*clone));
+    QCOMPARE(qpointer_upgrade(*pointer), nullptr);
+    QCOMPARE(qpointer_upgrade_mut(*pointer), nullptr);
+    QCOMPARE(qpointer_upgrade_mut(*clone), nullptr);
+  }
+
+  void otherThread()
+  {
+    QThread thread;
+    QObject object;
+    object.moveToThread(&thread);
+
+    auto pointer = create_qpointer(object);
+    QCOMPARE(qpointer_as_ptr(*pointer), &object);
+    // The object can not be referenced from a thread it does not live in
+    QCOMPARE(qpointer_upgrade(*pointer), nullptr);
+    QCOMPARE(qpointer_upgrade_mut(*pointer), nullptr);
+  }
+};
@@ -33,6 +33,7 @@ fn main() {
         .file("src/qpen.rs")
         .file("src/qpersistentmodelindex.rs")
         .file("src/qpoint.rs")
+        .file("src/qpointer.rs")
         .file("src/qpointf.rs")
         .file("src/qpolygon.rs")
         .file("src/qpolygonf.rs")
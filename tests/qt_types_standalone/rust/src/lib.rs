@@ -30,6 +30,7 @@ mod qmodelindex;
 mod qpen;
 mod qpersistentmodelindex;
 mod qpoint;
+mod qpointer;
 mod qpointf;
 mod qpolygon;
 mod qpolygonf;
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2026 Klarälvdalens Datakonsult AB, a KDAB Group company <info@kdab.com>
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use core::pin::Pin;
+use cxx_qt::{QObject, QPointer};
+
+#[cxx::bridge]
+mod qpointer_cxx {
+    #[namespace = ""]
+    unsafe extern "C++" {
+        include!(<QtCore/QObject>);
+        type QObject = cxx_qt::QObject;
+    }
+
+    extern "Rust" {
+        type QPointerWrapper;
+
+        fn create_qpointer(object: Pin<&mut QObject>) -> Box<QPointerWrapper>;
+        fn create_qpointer_null() -> Box<QPointerWrapper>;
+        fn qpointer_clone(wrapper: &QPointerWrapper) -> Box<QPointerWrapper>;
+        fn qpointer_is_null(wrapper: &QPointerWrapper) -> bool;
+        fn qpointer_as_ptr(wrapper: &QPointerWrapper) -> *mut QObject;
+        fn qpointer_upgrade(wrapper: &QPointerWrapper) -> *const QObject;
+        fn qpointer_upgrade_mut(wrapper: &mut QPointerWrapper) -> *mut QObject;
+    }
+}
+
+// CXX doesn't support Rust generics so we need to have a new type
+struct QPointerWrapper {
+    pointer: QPointer<QObject>,
+}
+
+fn create_qpointer(object: Pin<&mut QObject>) -> Box<QPointerWrapper> {
+    Box::new(QPointerWrapper {
+        pointer: QPointer::new(object),
+    })
+}
+
+fn create_qpointer_null() -> Box<QPointerWrapper> {
+    Box::new(QPointerWrapper {
+        pointer: QPointer::null(),
+    })
+}
+
+fn qpointer_clone(wrapper: &QPointerWrapper) -> Box<QPointerWrapper> {
+    Box::new(QPointerWrapper {
+        pointer: wrapper.pointer.clone(),
+    })
+}
+
+fn qpointer_is_null(wrapper: &QPointerWrapper) -> bool {
+    wrapper.pointer.is_null()
+}
+
+fn qpointer_as_ptr(wrapper: &QPointerWrapper) -> *mut QObject {
+    wrapper.pointer.as_ptr()
+}
+
+fn qpointer_upgrade(wrapper: &QPointerWrapper) -> *const QObject {
+    // SAFETY: the reference is immediately converted to a pointer, which is only compared
+    unsafe {
+        wrapper
+            .pointer
+            .upgrade()
+            .map_or(core::ptr::null(), |object| object as *const QObject)
+    }
+}
+
+fn qpointer_upgrade_mut(wrapper: &mut QPointerWrapper) -> *mut QObject {
+    // SAFETY: the reference is immediately converted to a pointer, which is only compared
+    unsafe {
+        wrapper
+            .pointer
+            .upgrade_mut()
+            .map_or(core::ptr::null_mut(), |object| {
+                object.get_unchecked_mut() as *mut QObject
+            })
+    }
+}